@@ -1,4 +1,4 @@
-//! Derive macro for the `Topicable` trait.
+//! Derive macros for the `Topicable` and `CdrBounds` traits.
 
 use darling::{FromDeriveInput, FromField};
 use proc_macro::TokenStream;
@@ -12,6 +12,60 @@ struct Field {
 
     #[darling(default)]
     key: bool,
+
+    bound: Option<usize>,
+}
+
+impl Field {
+    /// The expressions for the maximum serialized CDR size and alignment of
+    /// this field, taking a `#[dds(bound = N)]` into account.
+    fn cdr_bounds(&self) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+        let ty = &self.ty;
+        let size = match self.bound {
+            Some(bound) => quote! {
+                <#ty as ::cyclonedds::cdr_bounds::CdrSequenceBounds>::max_bounded_serialized_cdr_size(#bound)
+            },
+            None => quote! {
+                <#ty as ::cyclonedds::cdr_bounds::CdrBounds>::max_serialized_cdr_size()
+            },
+        };
+        let alignment = quote! {
+            <#ty as ::cyclonedds::cdr_bounds::CdrBounds>::alignment()
+        };
+        (size, alignment)
+    }
+
+    /// The name of the function that serializes this field of the generated
+    /// key while enforcing its `#[dds(bound = N)]`.
+    fn serialize_bounded_ident(&self) -> syn::Ident {
+        let ident = self.ident.as_ref().expect("key fields are named");
+        format_ident!("serialize_bounded_{}", ident)
+    }
+
+    /// The `#[serde(serialize_with)]` attribute of this field in the
+    /// generated key, if it has a bound to enforce.
+    fn key_serialize_with(&self) -> Option<proc_macro2::TokenStream> {
+        self.bound.map(|_| {
+            let serialize_bounded = self.serialize_bounded_ident().to_string();
+            quote! { #[serde(serialize_with = #serialize_bounded)] }
+        })
+    }
+
+    /// The function named in [`Field::key_serialize_with`], which fails to
+    /// serialize a value exceeding the bound.
+    fn key_serialize_bounded(&self) -> Option<proc_macro2::TokenStream> {
+        let bound = self.bound?;
+        let ty = &self.ty;
+        let serialize_bounded = self.serialize_bounded_ident();
+        Some(quote! {
+            fn #serialize_bounded<S>(value: &#ty, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                ::cyclonedds::cdr_bounds::serialize_bounded(value, #bound, serializer)
+            }
+        })
+    }
 }
 
 #[derive(Debug, FromDeriveInput)]
@@ -22,6 +76,9 @@ struct TopicableAttributes {
     data: darling::ast::Data<(), Field>,
 
     type_name: Option<String>,
+
+    #[darling(default)]
+    optional_members: bool,
 }
 
 impl ToTokens for TopicableAttributes {
@@ -30,6 +87,7 @@ impl ToTokens for TopicableAttributes {
             ref ident,
             ref data,
             ref type_name,
+            optional_members,
         } = *self;
 
         let keys = data
@@ -58,8 +116,12 @@ impl ToTokens for TopicableAttributes {
             let key_field_defs = keys.iter().map(|f| {
                 let n = &f.ident;
                 let t = &f.ty;
-                quote! { pub #n: #t }
+                let serialize_with = f.key_serialize_with();
+                quote! { #serialize_with pub #n: #t }
             });
+            // The bounds of the key fields are enforced when the key is
+            // serialized, which fails a write whose key exceeds them.
+            let key_field_serialize_bounded = keys.iter().filter_map(|f| f.key_serialize_bounded());
             let key_field_inits = keys.iter().map(|f| {
                 let n = &f.ident;
                 quote! { #n: self.#n.clone() }
@@ -68,18 +130,8 @@ impl ToTokens for TopicableAttributes {
                 let n = &f.ident;
                 quote! { #n: key.#n.clone() }
             });
-            let key_size_sum = keys.iter().map(|f| {
-                let t = &f.ty;
-                quote! {
-                    <#t as ::cyclonedds::cdr_bounds::CdrBounds>::max_serialized_cdr_size()
-                }
-            });
-            let key_alignment_max = keys.iter().map(|f| {
-                let t = &f.ty;
-                quote! {
-                    <#t as ::cyclonedds::cdr_bounds::CdrBounds>::alignment()
-                }
-            });
+            let (key_sizes, key_alignments): (Vec<_>, Vec<_>) =
+                keys.iter().map(|f| f.cdr_bounds()).unzip();
 
             let key_name = format!("Key<{ident}>");
             let key_field_names = keys.iter().map(|f| &f.ident);
@@ -93,6 +145,8 @@ impl ToTokens for TopicableAttributes {
                         #(#key_field_defs),*
                     }
 
+                    #(#key_field_serialize_bounded)*
+
                     impl std::fmt::Debug for Key {
                         fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
                             let mut debug = f.debug_struct(#key_name);
@@ -105,10 +159,12 @@ impl ToTokens for TopicableAttributes {
 
                     impl ::cyclonedds::cdr_bounds::CdrBounds for Key {
                         fn max_serialized_cdr_size() -> ::cyclonedds::cdr_bounds::CdrSize {
-                            #(#key_size_sum)+*
+                            ::cyclonedds::cdr_bounds::struct_max_serialized_cdr_size(&[
+                                #((#key_sizes, #key_alignments)),*
+                            ])
                         }
                         fn alignment() -> usize {
-                            0 #(.max(#key_alignment_max))*
+                            1 #(.max(#key_alignments))*
                         }
                     }
                 }
@@ -141,10 +197,18 @@ impl ToTokens for TopicableAttributes {
             }
         });
 
+        let optional_members = optional_members.then(|| {
+            quote!(
+                const OPTIONAL_MEMBERS: bool = true;
+            )
+        });
+
         tokens.extend(quote! {
             impl ::cyclonedds::Topicable for #ident {
                 type Key = #key_type;
 
+                #optional_members
+
                 #from_key
 
                 #as_key
@@ -162,9 +226,19 @@ impl ToTokens for TopicableAttributes {
 /// `#[dds(key)]` fields use [`()`](primitive@unit) as their key type and must
 /// implement [`Default`].
 ///
+/// `String` and `Vec<T>` key fields can be annotated with `#[dds(bound = N)]`
+/// so that the key's bounds (see `CdrBounds`) allow for a non-MD5 keyhash. The
+/// bound is enforced when the key is serialized, so writing a sample whose key
+/// field exceeds it fails.
+///
 /// An optional `#[dds(type_name = "...")]` attribute overrides the DDS type
 /// name used for topic matching. Without it, the Rust type name is used.
 ///
+/// A `#[dds(optional_members)]` attribute sets `Topicable::OPTIONAL_MEMBERS`,
+/// so that the `Option<T>` members of the struct, and of the structs nested in
+/// it, are encoded in XCDR2 like IDL `@optional` members. Without it, an
+/// `Option<T>` is encoded the way `cdr_encoding` does it.
+///
 /// # Examples
 ///
 /// ```ignore
@@ -192,6 +266,114 @@ pub fn derive_topicable(input: TokenStream) -> TokenStream {
         .unwrap_or_else(|e| e.write_errors().into())
 }
 
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(dds), supports(struct_any))]
+struct CdrBoundsAttributes {
+    ident: syn::Ident,
+
+    generics: syn::Generics,
+
+    data: darling::ast::Data<(), Field>,
+
+    // Accepted (and ignored) so that a type can derive both `Topicable` and
+    // `CdrBounds`.
+    #[allow(dead_code)]
+    type_name: Option<String>,
+    #[allow(dead_code)]
+    #[darling(default)]
+    optional_members: bool,
+}
+
+impl ToTokens for CdrBoundsAttributes {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let CdrBoundsAttributes {
+            ref ident,
+            ref generics,
+            ref data,
+            ..
+        } = *self;
+
+        let (sizes, alignments): (Vec<_>, Vec<_>) = data
+            .as_ref()
+            .take_struct()
+            .expect("the cdr bounds attribute only accepts structs")
+            .fields
+            .into_iter()
+            .map(Field::cdr_bounds)
+            .unzip();
+
+        let mut generics = generics.clone();
+        for param in generics.type_params_mut() {
+            param
+                .bounds
+                .push(syn::parse_quote!(::cyclonedds::cdr_bounds::CdrBounds));
+        }
+        let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+        tokens.extend(quote! {
+            impl #impl_generics ::cyclonedds::cdr_bounds::CdrBounds for #ident #type_generics #where_clause {
+                fn max_serialized_cdr_size() -> ::cyclonedds::cdr_bounds::CdrSize {
+                    ::cyclonedds::cdr_bounds::struct_max_serialized_cdr_size(&[
+                        #((#sizes, #alignments)),*
+                    ])
+                }
+                fn alignment() -> usize {
+                    1 #(.max(#alignments))*
+                }
+            }
+        });
+    }
+}
+
+/// Derives `CdrBounds` for a struct from the bounds of its fields.
+///
+/// The maximum serialized size is the sum of the field sizes with every field
+/// padded to its alignment and the total padded to the alignment of the
+/// struct, matching the key type generated by `Topicable`. The alignment is
+/// the largest field alignment. `String` and `Vec<T>` fields are unbounded unless annotated with
+/// `#[dds(bound = N)]`, which limits them to `N` characters or elements like
+/// IDL's `string<N>` and `sequence<T, N>`. Nested structs need to implement
+/// `CdrBounds` themselves, e.g. by deriving it as well.
+///
+/// Any type parameters of the struct are required to implement `CdrBounds`,
+/// and a `#[dds(bound)]` on a field whose type does not implement
+/// `CdrSequenceBounds` fails to compile.
+///
+/// # Examples
+///
+/// ```ignore
+/// use cyclonedds::cdr_bounds::{CdrBounds, CdrSize};
+///
+/// #[derive(CdrBounds, serde::Serialize, serde::Deserialize, Default, Clone, Debug, PartialEq, Hash)]
+/// pub struct Position {
+///     pub x: i32,
+///     pub y: i32,
+/// }
+///
+/// #[derive(CdrBounds, serde::Serialize, serde::Deserialize, Default, Clone, Debug, PartialEq, Hash)]
+/// pub struct Id {
+///     #[dds(bound = 8)]
+///     pub name: String,
+///     pub position: Position,
+/// }
+///
+/// // 4 byte length + 8 characters + null terminator, padded to 4 bytes for the
+/// // position, + 2 * 4 bytes.
+/// assert_eq!(Id::max_serialized_cdr_size(), CdrSize::Bounded(24));
+/// ```
+///
+/// # Panics
+///
+/// Panics at compile time if applied to an enum or a union, or if
+/// `#[dds(bound)]` is not an integer literal.
+#[proc_macro_derive(CdrBounds, attributes(dds))]
+pub fn derive_cdr_bounds(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    CdrBoundsAttributes::from_derive_input(&input)
+        .map(|cdr_bounds| cdr_bounds.to_token_stream().into())
+        .unwrap_or_else(|e| e.write_errors().into())
+}
+
 #[cfg(test)]
 mod tests {
     use syn::parse_quote;
@@ -217,6 +399,63 @@ mod tests {
         assert_eq!(keys[1].ident.as_ref().unwrap().to_string(), "id2");
     }
 
+    #[test]
+    fn test_derive_parses_bounds() {
+        let input = parse_quote! {
+            struct Sensor {
+                #[dds(key, bound = 16)]
+                pub name: String,
+                #[dds(bound = 4)]
+                pub readings: Vec<f32>,
+                pub value: f32,
+            }
+        };
+        let attributes = CdrBoundsAttributes::from_derive_input(&input).unwrap();
+        let fields = attributes.data.take_struct().unwrap();
+        let bounds: Vec<_> = fields.fields.iter().map(|f| f.bound).collect();
+        assert_eq!(bounds, [Some(16), Some(4), None]);
+    }
+
+    #[test]
+    fn test_derive_cdr_bounds_on_tuple_struct() {
+        let input = parse_quote! {
+            struct Id([u8; 16]);
+        };
+        let attributes = CdrBoundsAttributes::from_derive_input(&input).unwrap();
+        let fields = attributes.data.take_struct().unwrap();
+        assert_eq!(fields.fields.len(), 1);
+    }
+
+    #[test]
+    fn test_derive_cdr_bounds_reject_enum() {
+        let input = parse_quote! {
+            enum Id { A, B }
+        };
+        assert!(CdrBoundsAttributes::from_derive_input(&input).is_err());
+    }
+
+    #[test]
+    fn test_derive_parses_optional_members() {
+        let input = parse_quote! {
+            struct Sensor {
+                #[dds(key)]
+                pub id: Option<u32>,
+                pub value: Option<f32>,
+            }
+        };
+        let attributes = TopicableAttributes::from_derive_input(&input).unwrap();
+        assert!(!attributes.optional_members);
+
+        let input = parse_quote! {
+            #[dds(optional_members)]
+            struct Sensor {
+                pub id: u32,
+            }
+        };
+        let attributes = TopicableAttributes::from_derive_input(&input).unwrap();
+        assert!(attributes.optional_members);
+    }
+
     #[test]
     fn test_derive_parses_type_name() {
         let input = parse_quote! {
@@ -229,6 +229,9 @@ pub fn generate_bindings(
         .define("BUILD_DDSPERF", "OFF")
         .define("ENABLE_SSL", "NO")
         .define("ENABLE_SECURITY", "NO")
+        // NOTE: this is the default, but the DcpsTopic built-in topic relies on
+        // it so it is pinned here.
+        .define("ENABLE_TOPIC_DISCOVERY", "ON")
         .define("CMAKE_INSTALL_LIBDIR", "lib")
         // NOTE: this is to keep the symbols when building with `--release`.
         .define("ENABLE_LTO", "NO");
@@ -290,6 +293,8 @@ fn main() -> Result<(), BuildError> {
         .allowlist_var("DOMAIN_DEFAULT")
         .allowlist_var("DURATION_INFINITE")
         .allowlist_var("TIME_NEVER")
+        .allowlist_var("BUILTIN_TOPIC_.*")
+        .allowlist_var("DATA_REPRESENTATION_.*")
         .formatter(bindgen::Formatter::Prettyplease)
         .layout_tests(true);
 
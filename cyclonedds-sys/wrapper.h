@@ -33,6 +33,19 @@ static void ddsi_serdata_unref_bindgen_wrapper(struct ddsi_serdata* serdata) {
   ddsi_serdata_unref(serdata);
 }
 
+/// Converts a `ddsi_serdata` object into a sample of its type.
+static bool ddsi_serdata_to_sample_bindgen_wrapper(
+    const struct ddsi_serdata* serdata, void* sample, void** bufptr,
+    void* buflim) {
+  return ddsi_serdata_to_sample(serdata, sample, bufptr, buflim);
+}
+
+/// Frees a sample (or its contents) using the operations of a `ddsi_sertype`.
+static void ddsi_sertype_free_sample_bindgen_wrapper(
+    const struct ddsi_sertype* sertype, void* sample, dds_free_op_t op) {
+  ddsi_sertype_free_sample(sertype, sample, op);
+}
+
 /// The value that represents the default domain ID.
 static const dds_domainid_t DOMAIN_DEFAULT = DDS_DOMAIN_DEFAULT;
 /// The value that represents an infinite duration.
@@ -54,5 +67,14 @@ static const dds_entity_t BUILTIN_TOPIC_DCPS_PUBLICATION =
 /// Pseudo topic for the DcpsSubscription builtin topic.
 static const dds_entity_t BUILTIN_TOPIC_DCPS_SUBSCRIPTION =
     DDS_BUILTIN_TOPIC_DCPSSUBSCRIPTION;
+/// The identifier for the XCDR1 data representation.
+static const dds_data_representation_id_t DATA_REPRESENTATION_XCDR1 =
+    DDS_DATA_REPRESENTATION_XCDR1;
+/// The identifier for the XML data representation.
+static const dds_data_representation_id_t DATA_REPRESENTATION_XML =
+    DDS_DATA_REPRESENTATION_XML;
+/// The identifier for the XCDR2 data representation.
+static const dds_data_representation_id_t DATA_REPRESENTATION_XCDR2 =
+    DDS_DATA_REPRESENTATION_XCDR2;
 
 #endif  // WRAPPER_H_
@@ -0,0 +1,352 @@
+//! Strings and sequences with a maximum length.
+//!
+//! [`BoundedString<N>`] and [`BoundedVec<T, N>`] correspond to IDL's
+//! `string<N>` and `sequence<T, N>`. They serialize exactly like [`String`] and
+//! [`Vec<T>`], but their [`CdrBounds`] are bounded, so a key made up of them
+//! can still have a keyhash based on its serialized form rather than an MD5
+//! hash.
+//!
+//! Both types dereference to their unbounded counterparts and can be modified
+//! freely. The bound is checked when a sample is written, so
+//! [`Writer::write`](crate::Writer::write) fails with an
+//! [`Error::Serialization`](crate::Error::Serialization) for a sample in which
+//! it is exceeded, and no reader receives that sample. Likewise a
+//! received sample that exceeds the bound fails to deserialize and is dropped
+//! (see [`Reader::type_undeserializable_status`](crate::Reader::type_undeserializable_status)).
+//!
+//! # Examples
+//!
+//! ```
+//! use cyclonedds::bounded::{BoundedString, BoundedVec};
+//! use cyclonedds::cdr_bounds::{CdrBounds, CdrSize};
+//!
+//! let mut name = BoundedString::<8>::from("sensor");
+//! assert!(!name.exceeds_bound());
+//! name.push_str("-overflow");
+//! assert!(name.exceeds_bound());
+//!
+//! let readings = BoundedVec::<f32, 4>::from(vec![0.5, 1.5]);
+//! assert_eq!(readings.len(), 2);
+//!
+//! // 4 byte length + 8 characters + null terminator.
+//! assert_eq!(BoundedString::<8>::max_serialized_cdr_size(), CdrSize::Bounded(13));
+//! // 4 byte length + 4 * 4 bytes.
+//! assert_eq!(BoundedVec::<f32, 4>::max_serialized_cdr_size(), CdrSize::Bounded(20));
+//! ```
+
+use crate::cdr_bounds::{CdrBounds, CdrSequenceBounds, CdrSize};
+
+/// A [`String`] of at most `N` bytes, the equivalent of IDL's `string<N>`.
+///
+/// See the [module documentation](self) for how the bound is enforced.
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BoundedString<const N: usize>(String);
+
+impl<const N: usize> BoundedString<N> {
+    /// The maximum length of the string in bytes.
+    pub const BOUND: usize = N;
+
+    /// Creates a new empty [`BoundedString`].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(String::new())
+    }
+
+    /// Returns whether the string is longer than `N` bytes, in which case it
+    /// will fail to serialize.
+    #[must_use]
+    pub const fn exceeds_bound(&self) -> bool {
+        self.0.len() > N
+    }
+
+    /// Unwraps the underlying [`String`].
+    #[must_use]
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl<const N: usize> From<String> for BoundedString<N> {
+    fn from(string: String) -> Self {
+        Self(string)
+    }
+}
+
+impl<const N: usize> From<&str> for BoundedString<N> {
+    fn from(string: &str) -> Self {
+        Self(string.to_string())
+    }
+}
+
+impl<const N: usize> std::ops::Deref for BoundedString<N> {
+    type Target = String;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const N: usize> std::ops::DerefMut for BoundedString<N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<const N: usize> std::fmt::Debug for BoundedString<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl<const N: usize> std::fmt::Display for BoundedString<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<const N: usize> serde::Serialize for BoundedString<N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if self.exceeds_bound() {
+            return Err(serde::ser::Error::custom(format_args!(
+                "string of {} bytes exceeds its bound of {N}",
+                self.0.len()
+            )));
+        }
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, const N: usize> serde::Deserialize<'de> for BoundedString<N> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let string = String::deserialize(deserializer)?;
+        if string.len() > N {
+            return Err(serde::de::Error::invalid_length(
+                string.len(),
+                &format!("a string of at most {N} bytes").as_str(),
+            ));
+        }
+        Ok(Self(string))
+    }
+}
+
+impl<const N: usize> CdrBounds for BoundedString<N> {
+    fn max_serialized_cdr_size() -> CdrSize {
+        String::max_bounded_serialized_cdr_size(N)
+    }
+
+    fn alignment() -> usize {
+        String::alignment()
+    }
+}
+
+/// A [`Vec<T>`] of at most `N` elements, the equivalent of IDL's
+/// `sequence<T, N>`.
+///
+/// See the [module documentation](self) for how the bound is enforced.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BoundedVec<T, const N: usize>(Vec<T>);
+
+impl<T, const N: usize> BoundedVec<T, N> {
+    /// The maximum number of elements in the sequence.
+    pub const BOUND: usize = N;
+
+    /// Creates a new empty [`BoundedVec`].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Returns whether the sequence holds more than `N` elements, in which
+    /// case it will fail to serialize.
+    #[must_use]
+    pub const fn exceeds_bound(&self) -> bool {
+        self.0.len() > N
+    }
+
+    /// Unwraps the underlying [`Vec`].
+    #[must_use]
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T, const N: usize> Default for BoundedVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> From<Vec<T>> for BoundedVec<T, N> {
+    fn from(vec: Vec<T>) -> Self {
+        Self(vec)
+    }
+}
+
+impl<T, const N: usize> std::ops::Deref for BoundedVec<T, N> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, const N: usize> std::ops::DerefMut for BoundedVec<T, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T, const N: usize> std::fmt::Debug for BoundedVec<T, N>
+where
+    T: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl<T, const N: usize> serde::Serialize for BoundedVec<T, N>
+where
+    T: serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if self.exceeds_bound() {
+            return Err(serde::ser::Error::custom(format_args!(
+                "sequence of {} elements exceeds its bound of {N}",
+                self.0.len()
+            )));
+        }
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, T, const N: usize> serde::Deserialize<'de> for BoundedVec<T, N>
+where
+    T: serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let vec = Vec::deserialize(deserializer)?;
+        if vec.len() > N {
+            return Err(serde::de::Error::invalid_length(
+                vec.len(),
+                &format!("a sequence of at most {N} elements").as_str(),
+            ));
+        }
+        Ok(Self(vec))
+    }
+}
+
+impl<T, const N: usize> CdrBounds for BoundedVec<T, N>
+where
+    T: CdrBounds,
+{
+    fn max_serialized_cdr_size() -> CdrSize {
+        Vec::<T>::max_bounded_serialized_cdr_size(N)
+    }
+
+    fn alignment() -> usize {
+        Vec::<T>::alignment()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bounded_string_serialization() {
+        let string = BoundedString::<5>::from("hello");
+        let serialized = cdr_encoding::to_vec::<_, byteorder::BigEndian>(&string).unwrap();
+        assert_eq!(
+            serialized,
+            cdr_encoding::to_vec::<_, byteorder::BigEndian>(&"hello".to_string()).unwrap()
+        );
+        assert_eq!(
+            CdrSize::Bounded(serialized.len()),
+            BoundedString::<5>::max_serialized_cdr_size()
+        );
+
+        let (deserialized, _) =
+            cdr_encoding::from_bytes::<BoundedString<5>, byteorder::BigEndian>(&serialized)
+                .unwrap();
+        assert_eq!(deserialized, string);
+
+        // The same bytes do not fit a smaller bound.
+        assert!(
+            cdr_encoding::from_bytes::<BoundedString<4>, byteorder::BigEndian>(&serialized)
+                .is_err()
+        );
+
+        let mut string = string;
+        string.push('!');
+        assert!(string.exceeds_bound());
+        let error = cdr_encoding::to_vec::<_, byteorder::BigEndian>(&string).unwrap_err();
+        assert!(error.to_string().contains("exceeds its bound of 5"));
+    }
+
+    #[test]
+    fn test_bounded_vec_serialization() {
+        let vec = BoundedVec::<u16, 3>::from(vec![1, 2, 3]);
+        let serialized = cdr_encoding::to_vec::<_, byteorder::BigEndian>(&vec).unwrap();
+        assert_eq!(
+            serialized,
+            cdr_encoding::to_vec::<_, byteorder::BigEndian>(&vec![1_u16, 2, 3]).unwrap()
+        );
+        assert_eq!(
+            CdrSize::Bounded(serialized.len()),
+            BoundedVec::<u16, 3>::max_serialized_cdr_size()
+        );
+
+        let (deserialized, _) =
+            cdr_encoding::from_bytes::<BoundedVec<u16, 3>, byteorder::BigEndian>(&serialized)
+                .unwrap();
+        assert_eq!(deserialized, vec);
+
+        assert!(
+            cdr_encoding::from_bytes::<BoundedVec<u16, 2>, byteorder::BigEndian>(&serialized)
+                .is_err()
+        );
+
+        let mut vec = vec;
+        vec.push(4);
+        assert!(vec.exceeds_bound());
+        let error = cdr_encoding::to_vec::<_, byteorder::BigEndian>(&vec).unwrap_err();
+        assert!(error.to_string().contains("exceeds its bound of 3"));
+    }
+
+    #[test]
+    fn test_bounded_cdr_bounds() {
+        assert_eq!(
+            BoundedString::<16>::max_serialized_cdr_size(),
+            CdrSize::Bounded(4 + 16 + 1)
+        );
+        assert_eq!(BoundedString::<16>::alignment(), 4);
+
+        assert_eq!(
+            BoundedVec::<u64, 2>::max_serialized_cdr_size(),
+            CdrSize::Bounded(8 + 2 * 8)
+        );
+        assert_eq!(BoundedVec::<u64, 2>::alignment(), 8);
+
+        assert_eq!(
+            BoundedVec::<BoundedString<3>, 2>::max_serialized_cdr_size(),
+            CdrSize::Bounded(4 + 2 * 8)
+        );
+        assert_eq!(
+            BoundedVec::<String, 2>::max_serialized_cdr_size(),
+            CdrSize::Unbounded
+        );
+    }
+}
@@ -0,0 +1,127 @@
+//! Components for moving samples between DDS and other systems.
+//!
+//! - [`record`] records samples to a compact binary log and replays them.
+//!
+//! Bridges that need additional dependencies are gated behind a cargo feature
+//! of the same name so that applications only pay for the formats they use:
+//!
+//! - `json`: newline-delimited JSON for logging pipelines and web dashboards.
+
+use std::io::Write;
+
+use crate::{Reader, Topicable};
+
+#[cfg(feature = "json")]
+pub mod json;
+pub mod record;
+
+/// An error produced while moving samples between DDS and another format.
+///
+/// Each bridge instantiates it with the errors specific to its format, such
+/// as [`record::RecordError`].
+#[derive(Debug)]
+pub enum BridgeError<E> {
+    /// A DDS operation failed.
+    Dds(crate::Error),
+    /// Reading or writing the other format failed.
+    Io(std::io::Error),
+    /// The data was not valid in the other format.
+    Format(E),
+}
+
+impl<E> std::fmt::Display for BridgeError<E>
+where
+    E: std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BridgeError::Dds(error) => write!(f, "DDS error: {error}"),
+            BridgeError::Io(error) => write!(f, "I/O error: {error}"),
+            BridgeError::Format(error) => error.fmt(f),
+        }
+    }
+}
+
+impl<E> std::error::Error for BridgeError<E>
+where
+    E: std::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BridgeError::Dds(error) => Some(error),
+            BridgeError::Io(error) => Some(error),
+            BridgeError::Format(error) => error.source(),
+        }
+    }
+}
+
+impl<E> From<crate::Error> for BridgeError<E> {
+    fn from(error: crate::Error) -> Self {
+        BridgeError::Dds(error)
+    }
+}
+
+impl<E> From<std::io::Error> for BridgeError<E> {
+    fn from(error: std::io::Error) -> Self {
+        BridgeError::Io(error)
+    }
+}
+
+/// A format that the samples of a [`ReaderSource`] are written in.
+trait Format {
+    type Error;
+
+    /// Takes all available samples from `source` and writes them to `output`,
+    /// returning how many were written.
+    fn write_samples<T>(
+        &self,
+        source: &ReaderSource<'_, '_, '_, '_, T>,
+        output: &mut dyn Write,
+    ) -> Result<usize, Self::Error>
+    where
+        T: Topicable;
+}
+
+/// A reader whose samples are bridged, with the type erased so that readers of
+/// different topics can be bridged together.
+trait Source<F>: std::fmt::Debug
+where
+    F: Format,
+{
+    /// Takes all available samples and writes them to `output` in `format`,
+    /// returning how many were written.
+    fn write_samples(&self, format: &F, output: &mut dyn Write) -> Result<usize, F::Error>;
+}
+
+#[derive(Debug)]
+struct ReaderSource<'r, 'd, 'p, 't, T>
+where
+    T: Topicable,
+{
+    reader: &'r Reader<'d, 'p, 't, T>,
+    topic_name: String,
+    type_name: String,
+}
+
+impl<'r, 'd, 'p, 't, T> ReaderSource<'r, 'd, 'p, 't, T>
+where
+    T: Topicable,
+{
+    fn new(reader: &'r Reader<'d, 'p, 't, T>) -> crate::Result<Self> {
+        Ok(Self {
+            reader,
+            topic_name: reader.topic_name()?,
+            type_name: reader.type_name()?,
+        })
+    }
+}
+
+impl<T, F> Source<F> for ReaderSource<'_, '_, '_, '_, T>
+where
+    T: Topicable,
+    F: Format,
+{
+    fn write_samples(&self, format: &F, output: &mut dyn Write) -> Result<usize, F::Error> {
+        format.write_samples(self, output)
+    }
+}
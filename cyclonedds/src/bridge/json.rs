@@ -0,0 +1,481 @@
+//! Newline-delimited JSON export and import of samples.
+//!
+//! Every [`Topicable`] type implements [`serde::Serialize`] and
+//! [`serde::Deserialize`], so samples of any topic can be converted to and from
+//! JSON without extra code. A [`JsonExporter`] takes samples from a set of
+//! [`Readers`](Reader) and writes one JSON object per line, and a
+//! [`JsonImporter`] writes lines in the same format into a topic through a
+//! [`Writer`].
+//!
+//! Each line is a record of the form:
+//!
+//! ```json
+//! {
+//!   "topic": "Readings",
+//!   "type_name": "Reading",
+//!   "kind": "sample",
+//!   "info": {
+//!     "sample_state": "fresh",
+//!     "view_state": "new",
+//!     "instance_state": "alive",
+//!     "source_timestamp": 1700000000000000000,
+//!     "instance_handle": 7263548329573412001,
+//!     "publication_handle": 1029384756473829101,
+//!     "disposed_generation_count": 0,
+//!     "no_writers_generation_count": 0,
+//!     "sample_rank": 0,
+//!     "generation_rank": 0,
+//!     "absolute_generation_rank": 0
+//!   },
+//!   "data": { "id": 1, "value": 21.5 }
+//! }
+//! ```
+//!
+//! `kind` is `"key"` for the key-only samples produced when an instance is
+//! disposed or unregistered, in which case `data` holds only the key. The
+//! `source_timestamp` is in nanoseconds since the Unix epoch.
+//!
+//! This module requires the `json` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use cyclonedds::bridge::json::{JsonExporter, JsonImporter};
+//! use cyclonedds::{Domain, Participant, Reader, Topic, Writer};
+//!
+//! #[derive(cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug)]
+//! struct Reading {
+//!     #[dds(key)]
+//!     id: i32,
+//!     value: f64,
+//! }
+//!
+//! let domain = Domain::default();
+//! let participant = Participant::new(&domain)?;
+//! let topic = Topic::<Reading>::new(&participant, "Readings")?;
+//! let reader = Reader::new(&topic)?;
+//! let writer = Writer::new(&topic)?;
+//!
+//! let mut exporter = JsonExporter::new(Vec::new());
+//! exporter.add_reader(&reader)?;
+//!
+//! JsonImporter::new(&writer).write_line(r#"{"data":{"id":1,"value":21.5}}"#)?;
+//! assert_eq!(exporter.export()?, 1);
+//!
+//! let output = String::from_utf8(exporter.into_inner())?;
+//! assert!(output.contains(r#""data":{"id":1,"value":21.5}"#));
+//! # Ok::<_, Box<dyn std::error::Error>>(())
+//! ```
+
+use std::io::{BufRead, Write};
+
+use serde_json::{Map, Value, json};
+
+use super::{BridgeError, Format, ReaderSource, Source};
+use crate::sample::{Info, SampleOrKey, View};
+use crate::{Reader, State, Topicable, Writer};
+
+/// An error produced while exporting or importing JSON records.
+pub type JsonError = BridgeError<FormatError>;
+
+/// An error specific to JSON records.
+#[derive(Debug)]
+pub enum FormatError {
+    /// A sample could not be converted to or from JSON.
+    Json(serde_json::Error),
+    /// A line was valid JSON but not a valid record.
+    InvalidRecord(String),
+}
+
+impl std::fmt::Display for FormatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FormatError::Json(error) => write!(f, "JSON error: {error}"),
+            FormatError::InvalidRecord(message) => write!(f, "invalid record: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for FormatError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FormatError::Json(error) => Some(error),
+            FormatError::InvalidRecord(_) => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for JsonError {
+    fn from(error: serde_json::Error) -> Self {
+        BridgeError::Format(FormatError::Json(error))
+    }
+}
+
+fn invalid_record(message: impl Into<String>) -> JsonError {
+    BridgeError::Format(FormatError::InvalidRecord(message.into()))
+}
+
+/// Newline-delimited JSON records, as described in the [module
+/// documentation](self).
+#[derive(Debug)]
+struct Lines;
+
+impl Format for Lines {
+    type Error = JsonError;
+
+    fn write_samples<T>(
+        &self,
+        source: &ReaderSource<'_, '_, '_, '_, T>,
+        output: &mut dyn Write,
+    ) -> Result<usize, JsonError>
+    where
+        T: Topicable,
+    {
+        let samples = source.reader.take()?;
+        for sample in &samples {
+            let record = to_record(&source.topic_name, &source.type_name, sample)?;
+            serde_json::to_writer(&mut *output, &record)?;
+            output.write_all(b"\n")?;
+        }
+        Ok(samples.len())
+    }
+}
+
+/// Converts a sample into a record as described in the [module
+/// documentation](self).
+fn to_record<T>(
+    topic_name: &str,
+    type_name: &str,
+    sample: &SampleOrKey<T>,
+) -> Result<Value, JsonError>
+where
+    T: Topicable,
+{
+    let (kind, data) = match sample.view() {
+        View::Sample(sample) => ("sample", serde_json::to_value(sample)?),
+        View::Key(key) => ("key", serde_json::to_value(key)?),
+    };
+
+    Ok(json!({
+        "topic": topic_name,
+        "type_name": type_name,
+        "kind": kind,
+        "info": info_to_json(sample.info()),
+        "data": data,
+    }))
+}
+
+fn info_to_json(info: &Info) -> Value {
+    let sample_state = if info.state.contains(State::SampleStale) {
+        "stale"
+    } else {
+        "fresh"
+    };
+    let view_state = if info.state.contains(State::ViewNew) {
+        "new"
+    } else {
+        "old"
+    };
+    let instance_state = if info.state.contains(State::InstanceDisposed) {
+        "disposed"
+    } else if info.state.contains(State::InstanceUnregistered) {
+        "unregistered"
+    } else {
+        "alive"
+    };
+
+    json!({
+        "sample_state": sample_state,
+        "view_state": view_state,
+        "instance_state": instance_state,
+        "source_timestamp": info.source_timestamp.as_nanos(),
+        "instance_handle": info.instance_handle.inner,
+        "publication_handle": info.publication_handle.inner,
+        "disposed_generation_count": info.disposed_generation_count,
+        "no_writers_generation_count": info.no_writers_generation_count,
+        "sample_rank": info.sample_rank,
+        "generation_rank": info.generation_rank,
+        "absolute_generation_rank": info.absolute_generation_rank,
+    })
+}
+
+/// Exports the samples of a set of [`Readers`](Reader) as newline-delimited
+/// JSON.
+///
+/// Samples are only taken when [`export`](JsonExporter::export) is called, so
+/// the exporter can be driven from a polling loop, a
+/// [`WaitSet`](crate::WaitSet), or a listener that signals another thread.
+#[derive(Debug)]
+pub struct JsonExporter<'r, W>
+where
+    W: Write,
+{
+    output: W,
+    sources: Vec<Box<dyn Source<Lines> + 'r>>,
+}
+
+impl<'r, W> JsonExporter<'r, W>
+where
+    W: Write,
+{
+    /// Creates a new exporter that writes records to `output`.
+    #[must_use]
+    pub const fn new(output: W) -> Self {
+        Self {
+            output,
+            sources: Vec::new(),
+        }
+    }
+
+    /// Adds a reader whose samples should be exported.
+    ///
+    /// Readers of different topic types can be added to the same exporter.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the topic or type name of the
+    /// reader could not be retrieved.
+    pub fn add_reader<T>(&mut self, reader: &'r Reader<'_, '_, '_, T>) -> crate::Result<()>
+    where
+        T: Topicable,
+    {
+        self.sources.push(Box::new(ReaderSource::new(reader)?));
+        Ok(())
+    }
+
+    /// Takes all available samples from every reader, in the order the readers
+    /// were added, and writes a record for each of them.
+    ///
+    /// Returns the number of records written. The output is flushed before
+    /// returning.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`JsonError`] if a reader fails to take samples, a sample
+    /// cannot be represented as JSON, or writing to the output fails. Samples
+    /// taken before the error are not returned to the readers.
+    pub fn export(&mut self) -> Result<usize, JsonError> {
+        let mut exported = 0;
+        for source in &self.sources {
+            exported += source.write_samples(&Lines, &mut self.output)?;
+        }
+        self.output.flush()?;
+        Ok(exported)
+    }
+
+    /// Returns a reference to the output.
+    #[must_use]
+    pub const fn get_ref(&self) -> &W {
+        &self.output
+    }
+
+    /// Consumes the exporter and returns the output.
+    #[must_use]
+    pub fn into_inner(self) -> W {
+        self.output
+    }
+}
+
+/// Writes newline-delimited JSON records into a topic through a [`Writer`].
+///
+/// Records use the format described in the [module documentation](self). Only
+/// `data` is required: a record without a `kind` is written as a sample, so
+/// `{"data": {...}}` is enough to publish one. Key-only records dispose or
+/// unregister the instance according to the `instance_state` in their `info`.
+/// All other fields, including the `topic`, are ignored, which allows records
+/// exported from one topic to be replayed into another of the same type.
+#[derive(Debug)]
+pub struct JsonImporter<'w, 'd, 'p, 't, T>
+where
+    T: Topicable,
+{
+    writer: &'w Writer<'d, 'p, 't, T>,
+}
+
+impl<'w, 'd, 'p, 't, T> JsonImporter<'w, 'd, 'p, 't, T>
+where
+    T: Topicable,
+{
+    /// Creates a new importer that writes records through `writer`.
+    #[must_use]
+    pub const fn new(writer: &'w Writer<'d, 'p, 't, T>) -> Self {
+        Self { writer }
+    }
+
+    /// Parses a single record and writes it into the topic.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`JsonError`] if the line is not a valid record for `T` or
+    /// the writer fails to write it.
+    pub fn write_line(&self, line: &str) -> Result<(), JsonError> {
+        let Value::Object(mut record) = serde_json::from_str(line)? else {
+            return Err(invalid_record("expected a JSON object"));
+        };
+        let data = record
+            .remove("data")
+            .ok_or_else(|| invalid_record("missing \"data\""))?;
+
+        match record
+            .get("kind")
+            .and_then(Value::as_str)
+            .unwrap_or("sample")
+        {
+            "sample" => self.writer.write(&serde_json::from_value(data)?)?,
+            "key" => {
+                let key = serde_json::from_value(data)?;
+                match instance_state(&record) {
+                    Some("disposed") => self.writer.dispose(&key)?,
+                    Some("unregistered") => self.writer.unregister_instance(&key)?,
+                    _ => {
+                        return Err(invalid_record(
+                            "key records need an instance_state of \"disposed\" or \
+                             \"unregistered\"",
+                        ));
+                    }
+                }
+            }
+            kind => return Err(invalid_record(format!("unknown kind {kind:?}"))),
+        }
+        Ok(())
+    }
+
+    /// Writes every record from `input` into the topic, skipping blank lines.
+    ///
+    /// Returns the number of records written.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`JsonError`] for the first line that could not be read or
+    /// written. Records before it will already have been written.
+    pub fn write_lines<R>(&self, input: R) -> Result<usize, JsonError>
+    where
+        R: BufRead,
+    {
+        let mut written = 0;
+        for line in input.lines() {
+            let line = line?;
+            if !line.trim().is_empty() {
+                self.write_line(&line)?;
+                written += 1;
+            }
+        }
+        Ok(written)
+    }
+}
+
+fn instance_state(record: &Map<String, Value>) -> Option<&str> {
+    record
+        .get("info")
+        .and_then(|info| info.get("instance_state"))
+        .and_then(Value::as_str)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::topic::Data;
+
+    #[test]
+    fn test_json_export_import_round_trip() -> Result<(), JsonError> {
+        let domain_id = crate::tests::domain::unique_id();
+        let domain = crate::Domain::new(domain_id)?;
+        let qos = crate::QoS::new().with_history(crate::qos::policy::History::KeepAll);
+        let participant = crate::Participant::builder(&domain)
+            .with_qos(&qos)
+            .build()?;
+
+        let source_name = crate::tests::topic::unique_name();
+        let source_topic = crate::Topic::<Data>::new(&participant, &source_name)?;
+        let source_reader = Reader::new(&source_topic)?;
+        let source_writer = Writer::new(&source_topic)?;
+
+        let target_name = crate::tests::topic::unique_name();
+        let target_topic = crate::Topic::<Data>::new(&participant, &target_name)?;
+        let target_reader = Reader::new(&target_topic)?;
+        let target_writer = Writer::new(&target_topic)?;
+
+        let sample = Data {
+            x: 1,
+            y: -1,
+            message: "hello".into(),
+        };
+        source_writer.write(&sample)?;
+        source_writer.dispose(&(1, -1))?;
+
+        let mut exporter = JsonExporter::new(Vec::new());
+        exporter.add_reader(&source_reader)?;
+        assert_eq!(exporter.export()?, 2);
+        assert_eq!(exporter.export()?, 0);
+
+        let output = exporter.into_inner();
+        let records = output
+            .split(|byte| *byte == b'\n')
+            .filter(|line| !line.is_empty())
+            .map(serde_json::from_slice::<Value>)
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(records.len(), 2);
+
+        assert_eq!(records[0]["topic"], json!(source_name));
+        assert_eq!(records[0]["kind"], json!("sample"));
+        assert_eq!(records[0]["info"]["instance_state"], json!("alive"));
+        assert_eq!(
+            records[0]["data"],
+            json!({"x": 1, "y": -1, "message": "hello"})
+        );
+        assert_eq!(records[1]["kind"], json!("key"));
+        assert_eq!(records[1]["info"]["instance_state"], json!("disposed"));
+        assert_eq!(records[1]["data"], json!([1, -1]));
+
+        let importer = JsonImporter::new(&target_writer);
+        assert_eq!(importer.write_lines(output.as_slice())?, 2);
+
+        let samples = target_reader.take()?;
+        assert_eq!(samples.len(), 2);
+        assert_eq!(samples[0].sample(), Some(&sample));
+        assert_eq!(samples[1].key(), Some(&(1, -1)));
+        assert!(samples[1].info().state.contains(State::InstanceDisposed));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_import_invalid_records() -> Result<(), JsonError> {
+        let domain_id = crate::tests::domain::unique_id();
+        let domain = crate::Domain::new(domain_id)?;
+        let participant = crate::Participant::new(&domain)?;
+        let topic_name = crate::tests::topic::unique_name();
+        let topic = crate::Topic::<Data>::new(&participant, &topic_name)?;
+        let writer = Writer::new(&topic)?;
+        let importer = JsonImporter::new(&writer);
+
+        importer.write_line(r#"{"data": {"x": 1, "y": 2, "message": ""}}"#)?;
+
+        assert!(matches!(
+            importer.write_line("[1, 2]"),
+            Err(JsonError::Format(FormatError::InvalidRecord(_)))
+        ));
+        assert!(matches!(
+            importer.write_line(r#"{"kind": "sample"}"#),
+            Err(JsonError::Format(FormatError::InvalidRecord(_)))
+        ));
+        assert!(matches!(
+            importer.write_line(r#"{"kind": "other", "data": {}}"#),
+            Err(JsonError::Format(FormatError::InvalidRecord(_)))
+        ));
+        assert!(matches!(
+            importer.write_line(r#"{"kind": "key", "data": [1, 2]}"#),
+            Err(JsonError::Format(FormatError::InvalidRecord(_)))
+        ));
+        assert!(matches!(
+            importer.write_line(r#"{"data": {"x": "not a number"}}"#),
+            Err(JsonError::Format(FormatError::Json(_)))
+        ));
+        assert!(matches!(
+            importer.write_line("{"),
+            Err(JsonError::Format(FormatError::Json(_)))
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,680 @@
+//! Recording samples to a file and replaying them later.
+//!
+//! A [`Recorder`] takes the samples of a set of [`Readers`](Reader) and
+//! appends them, still CDR-encoded, to a recording together with their source
+//! timestamp, publishing writer, and instance state. A [`Player`] publishes
+//! the samples of a recording again through [`Writers`](Writer) of the same
+//! topics, either paced like the original traffic or at a scaled rate. A
+//! [`Recording`] iterates over the recorded samples for offline inspection.
+//!
+//! # Format
+//!
+//! Recordings use a compact binary format with all integers in little-endian
+//! byte order. After the 8-byte [`MAGIC`] a recording is a sequence of
+//! entries, each starting with a one-byte tag:
+//!
+//! - A topic entry (tag `1`) assigns a `u32` identifier to a topic name and
+//!   type name, each stored as a `u32` length followed by UTF-8 bytes. It
+//!   precedes the first sample of its topic.
+//! - A sample entry (tag `2`) holds the `u32` topic identifier, a flag byte
+//!   (`1` for a full sample and `0` for a key-only sample), the `u32`
+//!   [`State`] bits, the `i64` source timestamp in nanoseconds, the `u64`
+//!   publication handle, and the `u32` length and bytes of the CDR-encoded
+//!   sample (including its encapsulation header).
+//!
+//! # Examples
+//!
+//! ```
+//! use cyclonedds::bridge::record::{Player, Recorder, Timing};
+//! use cyclonedds::{Domain, Participant, Reader, Topic, Writer};
+//! # #[derive(
+//! #     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+//! # )]
+//! # struct Data {
+//! #     x: i32,
+//! # }
+//!
+//! let domain = Domain::default();
+//! let participant = Participant::new(&domain)?;
+//! let topic = Topic::<Data>::new(&participant, "MyTopic")?;
+//! let reader = Reader::new(&topic)?;
+//! let writer = Writer::new(&topic)?;
+//!
+//! let mut recorder = Recorder::new(Vec::new())?;
+//! recorder.add_reader(&reader)?;
+//! writer.write(&Data { x: 1 })?;
+//! assert_eq!(recorder.record()?, 1);
+//! let recording = recorder.finish()?;
+//!
+//! let mut player = Player::new(recording.as_slice())?.with_timing(Timing::Unpaced)?;
+//! player.add_writer(&writer)?;
+//! assert_eq!(player.play()?, 1);
+//! assert_eq!(reader.take()?[0].x, 1);
+//! # Ok::<_, Box<dyn std::error::Error>>(())
+//! ```
+
+use std::collections::HashMap;
+use std::io::{Read, Write};
+
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+
+use super::{BridgeError, Format, ReaderSource, Source};
+use crate::entity::InstanceHandle;
+use crate::internal::ffi;
+use crate::{Reader, State, Time, Topicable, Writer};
+
+/// The bytes every recording starts with. The last byte is the version of the
+/// format.
+pub const MAGIC: [u8; 8] = *b"DDSREC\0\x01";
+
+const TAG_TOPIC: u8 = 1;
+const TAG_SAMPLE: u8 = 2;
+
+/// An error produced while recording or replaying samples.
+pub type RecordError = BridgeError<FormatError>;
+
+/// An error specific to recordings.
+#[derive(Debug)]
+pub enum FormatError {
+    /// The recording is malformed or uses an unsupported version of the format.
+    InvalidRecording(String),
+    /// A topic was recorded with a different type than the writer it is
+    /// replayed through.
+    TypeMismatch {
+        /// The name of the topic.
+        topic_name: String,
+        /// The type name in the recording.
+        recorded: String,
+        /// The type name of the writer.
+        expected: String,
+    },
+    /// The factor of a [`Timing::Scaled`] is not a finite, positive number.
+    InvalidTiming(f64),
+}
+
+impl std::fmt::Display for FormatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FormatError::InvalidRecording(message) => write!(f, "invalid recording: {message}"),
+            FormatError::TypeMismatch {
+                topic_name,
+                recorded,
+                expected,
+            } => write!(
+                f,
+                "topic {topic_name} was recorded with type {recorded} but replayed as {expected}"
+            ),
+            FormatError::InvalidTiming(factor) => {
+                write!(
+                    f,
+                    "invalid timing: scale factor {factor} is not finite and positive"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for FormatError {}
+
+fn invalid_recording(message: impl Into<String>) -> RecordError {
+    BridgeError::Format(FormatError::InvalidRecording(message.into()))
+}
+
+/// A sample read from a [`Recording`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordedSample {
+    /// The name of the topic the sample was recorded from.
+    pub topic_name: String,
+    /// The name of the type of the topic.
+    pub type_name: String,
+    /// The CDR-encoded sample, or only its key if `is_key` is set.
+    pub serialized: Vec<u8>,
+    /// Whether this is a key-only sample, as produced when an instance is
+    /// disposed or unregistered.
+    pub is_key: bool,
+    /// The [`sample`](crate::state::sample), [`view`](crate::state::view), and
+    /// [`instance`](crate::state::instance) state of the sample when it was
+    /// recorded.
+    pub state: State,
+    /// The timestamp at which the sample was originally written.
+    pub source_timestamp: Time,
+    /// The handle of the writer that originally published the sample.
+    pub publication_handle: InstanceHandle,
+}
+
+fn write_str(output: &mut dyn Write, value: &str) -> std::io::Result<()> {
+    write_bytes(output, value.as_bytes())
+}
+
+fn write_bytes(output: &mut dyn Write, value: &[u8]) -> std::io::Result<()> {
+    let len = u32::try_from(value.len())
+        .map_err(|error| std::io::Error::new(std::io::ErrorKind::InvalidInput, error))?;
+    output.write_u32::<LittleEndian>(len)?;
+    output.write_all(value)
+}
+
+fn read_bytes(input: &mut dyn Read) -> std::io::Result<Vec<u8>> {
+    let len = input.read_u32::<LittleEndian>()?;
+    let mut value = Vec::new();
+    input.take(u64::from(len)).read_to_end(&mut value)?;
+    if value.len() == usize::try_from(len).unwrap_or(usize::MAX) {
+        Ok(value)
+    } else {
+        Err(std::io::ErrorKind::UnexpectedEof.into())
+    }
+}
+
+/// Sample entries for the topic with the given identifier.
+#[derive(Debug)]
+struct SampleEntries {
+    id: u32,
+}
+
+impl Format for SampleEntries {
+    type Error = RecordError;
+
+    fn write_samples<T>(
+        &self,
+        source: &ReaderSource<'_, '_, '_, '_, T>,
+        output: &mut dyn Write,
+    ) -> Result<usize, RecordError>
+    where
+        T: Topicable,
+    {
+        let samples = source.reader.take_serialized()?;
+        for sample in &samples {
+            output.write_u8(TAG_SAMPLE)?;
+            output.write_u32::<LittleEndian>(self.id)?;
+            output.write_u8(sample.valid_data.into())?;
+            output.write_u32::<LittleEndian>(sample.info.state.bits())?;
+            output.write_i64::<LittleEndian>(sample.info.source_timestamp.as_nanos())?;
+            output.write_u64::<LittleEndian>(sample.info.publication_handle.inner)?;
+            write_bytes(output, &sample.serialized)?;
+        }
+        Ok(samples.len())
+    }
+}
+
+/// Records the samples of a set of [`Readers`](Reader).
+///
+/// Samples are only taken when [`record`](Recorder::record) is called, so the
+/// recorder can be driven from a polling loop or a
+/// [`WaitSet`](crate::WaitSet). Samples are appended in the
+/// order they are taken, which for readers of different topics may differ
+/// slightly from the order of their timestamps.
+#[derive(Debug)]
+pub struct Recorder<'r, W>
+where
+    W: Write,
+{
+    output: W,
+    sources: Vec<Box<dyn Source<SampleEntries> + 'r>>,
+}
+
+impl<'r, W> Recorder<'r, W>
+where
+    W: Write,
+{
+    /// Creates a new recorder that appends to `output`, starting with the
+    /// [`MAGIC`] bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RecordError`] if writing to `output` fails.
+    pub fn new(mut output: W) -> Result<Self, RecordError> {
+        output.write_all(&MAGIC)?;
+        Ok(Self {
+            output,
+            sources: Vec::new(),
+        })
+    }
+
+    /// Adds a reader whose samples should be recorded.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RecordError`] if the topic or type name of the reader could
+    /// not be retrieved or writing to the output fails.
+    pub fn add_reader<T>(&mut self, reader: &'r Reader<'_, '_, '_, T>) -> Result<(), RecordError>
+    where
+        T: Topicable,
+    {
+        let id = u32::try_from(self.sources.len())
+            .map_err(|_err| invalid_recording("too many topics"))?;
+        let source = ReaderSource::new(reader)?;
+
+        self.output.write_u8(TAG_TOPIC)?;
+        self.output.write_u32::<LittleEndian>(id)?;
+        write_str(&mut self.output, &source.topic_name)?;
+        write_str(&mut self.output, &source.type_name)?;
+
+        self.sources.push(Box::new(source));
+        Ok(())
+    }
+
+    /// Takes all available samples from every reader and appends them to the
+    /// recording.
+    ///
+    /// Returns the number of samples recorded.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RecordError`] if a reader fails to take samples or writing
+    /// to the output fails. Samples taken before the error are not returned to
+    /// the readers.
+    pub fn record(&mut self) -> Result<usize, RecordError> {
+        let mut recorded = 0;
+        for (id, source) in (0..).zip(&self.sources) {
+            recorded += source.write_samples(&SampleEntries { id }, &mut self.output)?;
+        }
+        Ok(recorded)
+    }
+
+    /// Flushes the recording and returns the output.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RecordError`] if flushing the output fails.
+    pub fn finish(mut self) -> Result<W, RecordError> {
+        self.output.flush()?;
+        Ok(self.output)
+    }
+}
+
+/// An iterator over the samples of a recording.
+#[derive(Debug)]
+pub struct Recording<R>
+where
+    R: Read,
+{
+    input: R,
+    topics: HashMap<u32, (String, String)>,
+}
+
+impl<R> Recording<R>
+where
+    R: Read,
+{
+    /// Opens a recording, checking that it starts with the [`MAGIC`] bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RecordError`] if `input` could not be read or is not a
+    /// recording in a supported format.
+    pub fn new(mut input: R) -> Result<Self, RecordError> {
+        let mut magic = [0; MAGIC.len()];
+        input.read_exact(&mut magic)?;
+        if magic != MAGIC {
+            return Err(invalid_recording(format!("unrecognized header {magic:?}")));
+        }
+        Ok(Self {
+            input,
+            topics: HashMap::new(),
+        })
+    }
+
+    /// Reads the next sample, or `None` at the end of the recording.
+    fn next_sample(&mut self) -> Result<Option<RecordedSample>, RecordError> {
+        loop {
+            let tag = match self.input.read_u8() {
+                Ok(tag) => tag,
+                Err(error) if error.kind() == std::io::ErrorKind::UnexpectedEof => {
+                    return Ok(None);
+                }
+                Err(error) => return Err(error.into()),
+            };
+
+            let id = self.input.read_u32::<LittleEndian>()?;
+            match tag {
+                TAG_TOPIC => {
+                    let topic_name = read_string(&mut self.input)?;
+                    let type_name = read_string(&mut self.input)?;
+                    self.topics.insert(id, (topic_name, type_name));
+                }
+                TAG_SAMPLE => {
+                    let is_key = self.input.read_u8()? == 0;
+                    let state = State::from_bits_truncate(self.input.read_u32::<LittleEndian>()?);
+                    let source_timestamp = Time::from_nanos(self.input.read_i64::<LittleEndian>()?);
+                    let publication_handle = InstanceHandle {
+                        inner: self.input.read_u64::<LittleEndian>()?,
+                    };
+                    let serialized = read_bytes(&mut self.input)?;
+                    let (topic_name, type_name) =
+                        self.topics.get(&id).cloned().ok_or_else(|| {
+                            invalid_recording(format!("sample for unknown topic {id}"))
+                        })?;
+
+                    return Ok(Some(RecordedSample {
+                        topic_name,
+                        type_name,
+                        serialized,
+                        is_key,
+                        state,
+                        source_timestamp,
+                        publication_handle,
+                    }));
+                }
+                tag => {
+                    return Err(invalid_recording(format!("unknown entry tag {tag}")));
+                }
+            }
+        }
+    }
+}
+
+fn read_string(input: &mut dyn Read) -> Result<String, RecordError> {
+    String::from_utf8(read_bytes(input)?).map_err(|error| invalid_recording(error.to_string()))
+}
+
+impl<R> Iterator for Recording<R>
+where
+    R: Read,
+{
+    type Item = Result<RecordedSample, RecordError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_sample().transpose()
+    }
+}
+
+/// How a [`Player`] paces the samples it publishes.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Timing {
+    /// Keep the intervals between the source timestamps of the samples.
+    #[default]
+    Original,
+    /// Divide the intervals between the source timestamps of the samples by
+    /// the given factor, so `Scaled(2.0)` replays twice as fast. The factor
+    /// must be finite and positive.
+    Scaled(f64),
+    /// Publish samples as fast as possible.
+    Unpaced,
+}
+
+/// A writer that recorded samples are replayed through, with the type erased
+/// so that writers of different topics can be used together.
+trait Target: std::fmt::Debug {
+    fn type_name(&self) -> &str;
+
+    fn replay(&self, sample: &RecordedSample) -> crate::Result<()>;
+}
+
+#[derive(Debug)]
+struct WriterTarget<'w, 'd, 'p, 't, T>
+where
+    T: Topicable,
+{
+    writer: &'w Writer<'d, 'p, 't, T>,
+    type_name: String,
+}
+
+impl<T> Target for WriterTarget<'_, '_, '_, '_, T>
+where
+    T: Topicable,
+{
+    fn type_name(&self) -> &str {
+        &self.type_name
+    }
+
+    fn replay(&self, sample: &RecordedSample) -> crate::Result<()> {
+        if !sample.is_key {
+            return self.writer.write_serialized(&sample.serialized);
+        }
+
+        let (key, _) = ffi::serdata_ops::deserialize::<T::Key>(&sample.serialized)
+            .map_err(|_err| crate::Error::BadParameter)?;
+        if sample.state.contains(State::InstanceDisposed) {
+            self.writer.dispose(&key)
+        } else if sample.state.contains(State::InstanceUnregistered) {
+            self.writer.unregister_instance(&key)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Replays a recording through a set of [`Writers`](Writer).
+///
+/// Each recorded sample is published by the writer added for its topic.
+/// Key-only samples dispose or unregister their instance according to their
+/// recorded instance state. Samples of topics without a writer are skipped.
+#[derive(Debug)]
+pub struct Player<'w, R>
+where
+    R: Read,
+{
+    recording: Recording<R>,
+    targets: HashMap<String, Box<dyn Target + 'w>>,
+    timing: Timing,
+}
+
+impl<'w, R> Player<'w, R>
+where
+    R: Read,
+{
+    /// Creates a new player for the recording in `input` that paces samples
+    /// with [`Timing::Original`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RecordError`] if `input` is not a recording in a supported
+    /// format.
+    pub fn new(input: R) -> Result<Self, RecordError> {
+        Ok(Self {
+            recording: Recording::new(input)?,
+            targets: HashMap::new(),
+            timing: Timing::default(),
+        })
+    }
+
+    /// Sets how samples are paced.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RecordError`] if the timing is [`Timing::Scaled`] with a
+    /// factor that is not finite and positive.
+    pub fn with_timing(mut self, timing: Timing) -> Result<Self, RecordError> {
+        match timing {
+            Timing::Scaled(factor) if !factor.is_finite() || factor <= 0.0 => {
+                Err(BridgeError::Format(FormatError::InvalidTiming(factor)))
+            }
+            timing => {
+                self.timing = timing;
+                Ok(self)
+            }
+        }
+    }
+
+    /// Adds a writer to replay the samples of its topic through, replacing
+    /// any writer previously added for the same topic.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the topic or type name of the
+    /// writer could not be retrieved.
+    pub fn add_writer<T>(&mut self, writer: &'w Writer<'_, '_, '_, T>) -> crate::Result<()>
+    where
+        T: Topicable,
+    {
+        self.targets.insert(
+            writer.topic_name()?,
+            Box::new(WriterTarget {
+                writer,
+                type_name: writer.type_name()?,
+            }),
+        );
+        Ok(())
+    }
+
+    /// Replays the rest of the recording, blocking for as long as its
+    /// [`Timing`] requires.
+    ///
+    /// Returns the number of samples published.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RecordError`] if the recording is malformed, a topic was
+    /// recorded with a different type than its writer, or a writer fails to
+    /// publish a sample.
+    pub fn play(&mut self) -> Result<usize, RecordError> {
+        let start = std::time::Instant::now();
+        let mut first_timestamp = None;
+        let mut played = 0;
+
+        for sample in &mut self.recording {
+            let sample = sample?;
+            let Some(target) = self.targets.get(&sample.topic_name) else {
+                continue;
+            };
+            if target.type_name() != sample.type_name {
+                return Err(BridgeError::Format(FormatError::TypeMismatch {
+                    topic_name: sample.topic_name,
+                    recorded: sample.type_name,
+                    expected: target.type_name().to_string(),
+                }));
+            }
+
+            let first_timestamp = *first_timestamp.get_or_insert(sample.source_timestamp);
+            let offset = std::time::Duration::from_nanos(
+                u64::try_from(
+                    sample
+                        .source_timestamp
+                        .as_nanos()
+                        .saturating_sub(first_timestamp.as_nanos()),
+                )
+                .unwrap_or_default(),
+            );
+            let due = match self.timing {
+                Timing::Original => Some(offset),
+                Timing::Scaled(factor) => Some(offset.div_f64(factor)),
+                Timing::Unpaced => None,
+            };
+            if let Some(due) = due {
+                std::thread::sleep(due.saturating_sub(start.elapsed()));
+            }
+
+            target.replay(&sample)?;
+            played += 1;
+        }
+
+        Ok(played)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::Entity;
+    use crate::tests::topic::Data;
+
+    #[test]
+    fn test_record_replay() -> Result<(), RecordError> {
+        let domain_id = crate::tests::domain::unique_id();
+        let domain = crate::Domain::new(domain_id)?;
+        let qos = crate::QoS::new().with_history(crate::qos::policy::History::KeepAll);
+        let participant = crate::Participant::builder(&domain)
+            .with_qos(&qos)
+            .build()?;
+
+        let topic_name = crate::tests::topic::unique_name();
+        let topic = crate::Topic::<Data>::new(&participant, &topic_name)?;
+        let reader = Reader::new(&topic)?;
+        let writer = Writer::new(&topic)?;
+
+        let sample = Data {
+            x: 1,
+            y: -1,
+            message: "hello".into(),
+        };
+        writer.write_with_timestamp(&sample, Time::from_secs(10))?;
+        writer.dispose_with_timestamp(&(1, -1), Time::from_secs(11))?;
+
+        let mut recorder = Recorder::new(Vec::new())?;
+        recorder.add_reader(&reader)?;
+        assert_eq!(recorder.record()?, 2);
+        assert_eq!(recorder.record()?, 0);
+        let recording = recorder.finish()?;
+
+        let samples = Recording::new(recording.as_slice())?.collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(samples.len(), 2);
+        assert_eq!(samples[0].topic_name, topic_name);
+        assert!(!samples[0].is_key);
+        assert_eq!(samples[0].source_timestamp, Time::from_secs(10));
+        assert_eq!(samples[0].publication_handle, writer.instance_handle()?);
+        assert!(samples[1].is_key);
+        assert!(samples[1].state.contains(State::InstanceDisposed));
+        assert_eq!(samples[1].source_timestamp, Time::from_secs(11));
+
+        let mut player = Player::new(recording.as_slice())?.with_timing(Timing::Scaled(10.0))?;
+        player.add_writer(&writer)?;
+        let start = std::time::Instant::now();
+        assert_eq!(player.play()?, 2);
+        assert!(
+            start.elapsed() >= std::time::Duration::from_millis(100),
+            "the one second between the samples should take a tenth of a second to replay"
+        );
+
+        let replayed = reader.take()?;
+        assert_eq!(replayed.len(), 2);
+        assert_eq!(replayed[0].sample(), Some(&sample));
+        assert_eq!(replayed[1].key(), Some(&(1, -1)));
+        assert!(replayed[1].info().state.contains(State::InstanceDisposed));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_record_replay_mismatches() -> Result<(), RecordError> {
+        assert!(matches!(
+            Recording::new(b"not a recording".as_slice()),
+            Err(RecordError::Format(FormatError::InvalidRecording(_)))
+        ));
+
+        let mut truncated = MAGIC.to_vec();
+        truncated.extend([TAG_SAMPLE, 0, 0]);
+        assert!(matches!(
+            Recording::new(truncated.as_slice())?.next(),
+            Some(Err(RecordError::Io(_)))
+        ));
+
+        let mut unknown_topic = MAGIC.to_vec();
+        unknown_topic.extend([TAG_SAMPLE, 0, 0, 0, 0, 1]);
+        unknown_topic.extend([0; 4 + 8 + 8 + 4]);
+        assert!(matches!(
+            Recording::new(unknown_topic.as_slice())?.next(),
+            Some(Err(RecordError::Format(FormatError::InvalidRecording(_))))
+        ));
+
+        let domain_id = crate::tests::domain::unique_id();
+        let domain = crate::Domain::new(domain_id)?;
+        let participant = crate::Participant::new(&domain)?;
+        let topic_name = crate::tests::topic::unique_name();
+        let topic = crate::Topic::<Data>::new(&participant, &topic_name)?;
+        let writer = Writer::new(&topic)?;
+
+        let mut recording = MAGIC.to_vec();
+        recording.push(TAG_TOPIC);
+        recording.extend(0u32.to_le_bytes());
+        write_str(&mut recording, &topic_name)?;
+        write_str(&mut recording, "OtherType")?;
+        recording.extend([TAG_SAMPLE, 0, 0, 0, 0, 1]);
+        recording.extend([0; 4 + 8 + 8]);
+        write_bytes(&mut recording, &[0, 1, 0, 0])?;
+
+        for factor in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert!(matches!(
+                Player::new(recording.as_slice())?.with_timing(Timing::Scaled(factor)),
+                Err(RecordError::Format(FormatError::InvalidTiming(_)))
+            ));
+        }
+
+        let mut player = Player::new(recording.as_slice())?.with_timing(Timing::Unpaced)?;
+        player.add_writer(&writer)?;
+        assert!(matches!(
+            player.play(),
+            Err(RecordError::Format(FormatError::TypeMismatch { .. }))
+        ));
+
+        Ok(())
+    }
+}
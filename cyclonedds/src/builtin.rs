@@ -0,0 +1,537 @@
+//! Readers for the DCPS built-in topics.
+//!
+//! Every participant publishes information about itself and the entities it
+//! contains on a set of built-in topics. Reading these topics is how
+//! applications observe discovery: which participants are present in the
+//! domain, which topics exist, and which readers and writers have been
+//! created, local and remote alike.
+//!
+//! | Data type                            | Describes                          |
+//! |--------------------------------------|------------------------------------|
+//! | [`ParticipantBuiltinTopicData`]      | discovered participants            |
+//! | [`TopicBuiltinTopicData`]            | discovered topics                  |
+//! | [`PublicationBuiltinTopicData`]      | discovered [`Writers`](crate::Writer) |
+//! | [`SubscriptionBuiltinTopicData`]     | discovered [`Readers`](crate::Reader) |
+//!
+//! Each of these is read through a [`BuiltinReader`] parameterized by the data
+//! type.
+//!
+//! ```
+//! use cyclonedds::builtin::{BuiltinReader, ParticipantBuiltinTopicData};
+//! use cyclonedds::{Domain, Participant};
+//!
+//! let domain = Domain::default();
+//! let participant = Participant::new(&domain)?;
+//! let reader = BuiltinReader::<ParticipantBuiltinTopicData>::new(&participant)?;
+//!
+//! for sample in reader.read()? {
+//!     println!("participant: {:?}", sample.key());
+//! }
+//! # Ok::<_, cyclonedds::Error>(())
+//! ```
+
+use crate::entity::{Guid, InstanceHandle};
+use crate::internal::ffi;
+use crate::{Participant, QoS, Result};
+
+pub(crate) mod private {
+    /// Private trait for sealing downstream implementation of the
+    /// [`BuiltinTopic`](super::BuiltinTopic) trait and for carrying the
+    /// details of the underlying C representation.
+    pub trait Sealed: Sized {
+        /// The C representation of a sample of the built-in topic.
+        type Raw: Default;
+
+        /// The pseudo topic handle of the built-in topic.
+        const TOPIC: cyclonedds_sys::dds_entity_t;
+
+        /// Converts the C representation of a sample.
+        fn from_raw(raw: &Self::Raw) -> Self;
+
+        /// Extracts the key of the C representation of a sample.
+        fn key_from_raw(raw: &Self::Raw) -> crate::entity::Guid;
+    }
+}
+
+/// Data types that can be read via a [`BuiltinReader`].
+///
+/// This trait is sealed and is implemented for
+/// [`ParticipantBuiltinTopicData`], [`TopicBuiltinTopicData`],
+/// [`PublicationBuiltinTopicData`] and [`SubscriptionBuiltinTopicData`].
+pub trait BuiltinTopic: private::Sealed + std::fmt::Debug {}
+
+/// Information about a discovered participant.
+#[derive(Debug)]
+pub struct ParticipantBuiltinTopicData {
+    /// The GUID of the participant.
+    pub key: Guid,
+    /// The [`QoS`] of the participant.
+    pub qos: QoS,
+}
+
+impl ParticipantBuiltinTopicData {
+    /// Returns the [`UserData`](crate::qos::policy::UserData) of the
+    /// participant if it has been set.
+    #[must_use]
+    pub fn user_data(&self) -> Option<&[u8]> {
+        self.qos
+            .user_data()
+            .map(|user_data| user_data.value.as_slice())
+    }
+}
+
+/// Information about a discovered topic.
+///
+/// NOTE: this is only published when Cyclone is built with
+/// `ENABLE_TOPIC_DISCOVERY` enabled (which the vendored build does) and the
+/// domain is configured with `Discovery/EnableTopicDiscoveryEndpoints` set to
+/// `true`.
+#[derive(Debug)]
+pub struct TopicBuiltinTopicData {
+    /// The key identifying the topic.
+    ///
+    /// Topics are not entities in the DDSI sense and so this is an identifier
+    /// derived from the topic definition rather than an entity GUID.
+    pub key: Guid,
+    /// The name of the topic.
+    pub topic_name: String,
+    /// The name of the type associated with the topic.
+    pub type_name: String,
+    /// The [`QoS`] of the topic.
+    pub qos: QoS,
+}
+
+/// Information about a discovered [`Writer`](crate::Writer).
+#[derive(Debug)]
+pub struct PublicationBuiltinTopicData {
+    /// The GUID of the writer.
+    pub key: Guid,
+    /// The GUID of the participant that contains the writer.
+    pub participant_key: Guid,
+    /// The local instance handle of the participant that contains the writer.
+    pub participant_instance_handle: InstanceHandle,
+    /// The name of the topic the writer publishes on.
+    pub topic_name: String,
+    /// The name of the type the writer publishes.
+    pub type_name: String,
+    /// The [`QoS`] of the writer.
+    pub qos: QoS,
+}
+
+impl PublicationBuiltinTopicData {
+    /// Returns the [`UserData`](crate::qos::policy::UserData) of the writer if
+    /// it has been set.
+    #[must_use]
+    pub fn user_data(&self) -> Option<&[u8]> {
+        self.qos
+            .user_data()
+            .map(|user_data| user_data.value.as_slice())
+    }
+}
+
+/// Information about a discovered [`Reader`](crate::Reader).
+#[derive(Debug)]
+pub struct SubscriptionBuiltinTopicData {
+    /// The GUID of the reader.
+    pub key: Guid,
+    /// The GUID of the participant that contains the reader.
+    pub participant_key: Guid,
+    /// The local instance handle of the participant that contains the reader.
+    pub participant_instance_handle: InstanceHandle,
+    /// The name of the topic the reader subscribes to.
+    pub topic_name: String,
+    /// The name of the type the reader subscribes to.
+    pub type_name: String,
+    /// The [`QoS`] of the reader.
+    pub qos: QoS,
+}
+
+impl SubscriptionBuiltinTopicData {
+    /// Returns the [`UserData`](crate::qos::policy::UserData) of the reader if
+    /// it has been set.
+    #[must_use]
+    pub fn user_data(&self) -> Option<&[u8]> {
+        self.qos
+            .user_data()
+            .map(|user_data| user_data.value.as_slice())
+    }
+}
+
+impl private::Sealed for ParticipantBuiltinTopicData {
+    type Raw = cyclonedds_sys::dds_builtintopic_participant_t;
+    const TOPIC: cyclonedds_sys::dds_entity_t = cyclonedds_sys::BUILTIN_TOPIC_DCPS_PARTICIPANT;
+
+    fn from_raw(raw: &Self::Raw) -> Self {
+        ffi::dds_builtintopic_participant_data(raw)
+    }
+
+    fn key_from_raw(raw: &Self::Raw) -> Guid {
+        Guid { inner: raw.key.v }
+    }
+}
+
+impl private::Sealed for TopicBuiltinTopicData {
+    type Raw = cyclonedds_sys::dds_builtintopic_topic_t;
+    const TOPIC: cyclonedds_sys::dds_entity_t = cyclonedds_sys::BUILTIN_TOPIC_DCPS_TOPIC;
+
+    fn from_raw(raw: &Self::Raw) -> Self {
+        ffi::dds_builtintopic_topic_data(raw)
+    }
+
+    fn key_from_raw(raw: &Self::Raw) -> Guid {
+        Guid { inner: raw.key.d }
+    }
+}
+
+impl private::Sealed for PublicationBuiltinTopicData {
+    type Raw = cyclonedds_sys::dds_builtintopic_endpoint_t;
+    const TOPIC: cyclonedds_sys::dds_entity_t = cyclonedds_sys::BUILTIN_TOPIC_DCPS_PUBLICATION;
+
+    fn from_raw(raw: &Self::Raw) -> Self {
+        ffi::dds_builtintopic_publication_data(raw)
+    }
+
+    fn key_from_raw(raw: &Self::Raw) -> Guid {
+        Guid { inner: raw.key.v }
+    }
+}
+
+impl private::Sealed for SubscriptionBuiltinTopicData {
+    type Raw = cyclonedds_sys::dds_builtintopic_endpoint_t;
+    const TOPIC: cyclonedds_sys::dds_entity_t = cyclonedds_sys::BUILTIN_TOPIC_DCPS_SUBSCRIPTION;
+
+    fn from_raw(raw: &Self::Raw) -> Self {
+        ffi::dds_builtintopic_subscription_data(raw)
+    }
+
+    fn key_from_raw(raw: &Self::Raw) -> Guid {
+        Guid { inner: raw.key.v }
+    }
+}
+
+impl BuiltinTopic for ParticipantBuiltinTopicData {}
+impl BuiltinTopic for TopicBuiltinTopicData {}
+impl BuiltinTopic for PublicationBuiltinTopicData {}
+impl BuiltinTopic for SubscriptionBuiltinTopicData {}
+
+/// A sample received from a [`BuiltinReader`].
+///
+/// When a discovered entity is deleted or lost the reader receives a key-only
+/// sample: [`key`](BuiltinSample::key) identifies the entity but
+/// [`sample`](BuiltinSample::sample) is `None`. The
+/// [`Info`](crate::sample::Info) state distinguishes between the two.
+#[derive(Debug)]
+pub struct BuiltinSample<T>
+where
+    T: BuiltinTopic,
+{
+    pub(crate) key: Guid,
+    pub(crate) sample: Option<T>,
+    pub(crate) info: crate::sample::Info,
+}
+
+impl<T> BuiltinSample<T>
+where
+    T: BuiltinTopic,
+{
+    /// Returns the key of the entity this sample describes.
+    #[must_use]
+    pub const fn key(&self) -> Guid {
+        self.key
+    }
+
+    /// Returns a reference to the sample payload, or `None` if this is a
+    /// key-only sample.
+    #[must_use]
+    pub const fn sample(&self) -> Option<&T> {
+        self.sample.as_ref()
+    }
+
+    /// Consumes `self` and returns the sample payload, or `None` if this is a
+    /// key-only sample.
+    #[must_use]
+    pub fn into_sample(self) -> Option<T> {
+        self.sample
+    }
+
+    /// Returns the metadata associated with this sample.
+    #[must_use]
+    pub const fn info(&self) -> &crate::sample::Info {
+        &self.info
+    }
+}
+
+/// A reader for one of the DCPS built-in topics.
+///
+/// The type parameter selects the built-in topic that is read (see the
+/// [implementors of `BuiltinTopic`](BuiltinTopic#implementors)). Built-in
+/// readers behave like any other [`Reader`](crate::Reader) and so can be used
+/// with [`ReadConditions`](crate::ReadCondition) and
+/// [`WaitSets`](crate::WaitSet) via the [`Entity`](crate::entity::Entity)
+/// trait.
+#[derive(Debug)]
+pub struct BuiltinReader<'domain, 'participant, T>
+where
+    T: BuiltinTopic,
+{
+    pub(crate) inner: cyclonedds_sys::dds_entity_t,
+    phantom: std::marker::PhantomData<(&'participant Participant<'domain>, fn() -> T)>,
+}
+
+impl<'d, 'p, T> BuiltinReader<'d, 'p, T>
+where
+    T: BuiltinTopic,
+{
+    /// Creates a new `BuiltinReader` under the given
+    /// [`Participant`](crate::Participant).
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the reader fails to create.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclonedds::builtin::{BuiltinReader, PublicationBuiltinTopicData};
+    /// # use cyclonedds::{Domain, Participant};
+    /// # let domain = Domain::default();
+    /// # let participant = Participant::new(&domain)?;
+    ///
+    /// let reader = BuiltinReader::<PublicationBuiltinTopicData>::new(&participant)?;
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn new(participant: &'p Participant<'d>) -> Result<Self> {
+        Ok(Self {
+            inner: ffi::dds_create_reader(participant.inner, T::TOPIC, None, None)?,
+            phantom: std::marker::PhantomData,
+        })
+    }
+
+    /// Removes and returns all available samples from the reader cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the reader fails to take samples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclonedds::builtin::{BuiltinReader, SubscriptionBuiltinTopicData};
+    /// # use cyclonedds::{Domain, Participant, Reader, Topic};
+    /// # let domain = Domain::default();
+    /// # let participant = Participant::new(&domain)?;
+    /// # #[derive(
+    /// #     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+    /// # )]
+    /// # struct Data {
+    /// #     x: i32,
+    /// # }
+    ///
+    /// let builtin_reader = BuiltinReader::<SubscriptionBuiltinTopicData>::new(&participant)?;
+    /// let topic = Topic::<Data>::new(&participant, "Example")?;
+    /// let reader = Reader::new(&topic)?;
+    ///
+    /// let samples = builtin_reader.take()?;
+    /// assert!(samples.iter().any(|sample| {
+    ///     sample
+    ///         .sample()
+    ///         .is_some_and(|subscription| subscription.topic_name == "Example")
+    /// }));
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn take(&self) -> Result<Vec<BuiltinSample<T>>> {
+        ffi::dds_peek_read_take_builtin::<T, ffi::read_operation::Take>(self.inner)
+    }
+
+    /// Returns all available samples from the reader cache without removing
+    /// them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the reader fails to read samples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclonedds::builtin::{BuiltinReader, ParticipantBuiltinTopicData};
+    /// # use cyclonedds::{Domain, Participant};
+    /// # let domain = Domain::default();
+    /// # let participant = Participant::new(&domain)?;
+    ///
+    /// let reader = BuiltinReader::<ParticipantBuiltinTopicData>::new(&participant)?;
+    /// // The local participant is always discovered.
+    /// assert!(!reader.read()?.is_empty());
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn read(&self) -> Result<Vec<BuiltinSample<T>>> {
+        ffi::dds_peek_read_take_builtin::<T, ffi::read_operation::Read>(self.inner)
+    }
+
+    /// Returns all available samples without marking them as read or removing
+    /// them from the cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the reader fails to peek.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclonedds::builtin::{BuiltinReader, ParticipantBuiltinTopicData};
+    /// # use cyclonedds::{Domain, Participant};
+    /// # let domain = Domain::default();
+    /// # let participant = Participant::new(&domain)?;
+    ///
+    /// let reader = BuiltinReader::<ParticipantBuiltinTopicData>::new(&participant)?;
+    /// let count = reader.peek()?.len();
+    /// assert_eq!(reader.take()?.len(), count);
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn peek(&self) -> Result<Vec<BuiltinSample<T>>> {
+        ffi::dds_peek_read_take_builtin::<T, ffi::read_operation::Peek>(self.inner)
+    }
+}
+
+impl<T> Drop for BuiltinReader<'_, '_, T>
+where
+    T: BuiltinTopic,
+{
+    fn drop(&mut self) {
+        let result = ffi::dds_delete(self.inner);
+        debug_assert!(
+            result.is_ok(),
+            "unable to delete {self:?}: failed with {result:?}"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_reader_participant() {
+        let domain_id = crate::tests::domain::unique_id();
+        let domain = crate::Domain::new(domain_id).unwrap();
+        let qos = QoS::new().with_user_data(crate::qos::policy::UserData {
+            value: b"builtin".to_vec(),
+        });
+        let participant = Participant::builder(&domain)
+            .with_qos(&qos)
+            .build()
+            .unwrap();
+
+        let reader = BuiltinReader::<ParticipantBuiltinTopicData>::new(&participant).unwrap();
+        let samples = reader.take().unwrap();
+        assert!(
+            samples
+                .iter()
+                .filter_map(BuiltinSample::sample)
+                .any(|participant| participant.user_data() == Some(b"builtin".as_slice())),
+            "the local participant should be discovered"
+        );
+        assert!(reader.take().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_builtin_reader_publication() {
+        let domain_id = crate::tests::domain::unique_id();
+        let domain = crate::Domain::new(domain_id).unwrap();
+        let topic_name = crate::tests::topic::unique_name();
+        let participant = Participant::new(&domain).unwrap();
+        let topic =
+            crate::Topic::<crate::tests::topic::Data>::new(&participant, &topic_name).unwrap();
+        let qos = QoS::new()
+            .with_durability(crate::qos::policy::Durability::TransientLocal)
+            .with_user_data(crate::qos::policy::UserData {
+                value: b"writer".to_vec(),
+            });
+        let _writer = crate::Writer::builder(&topic)
+            .with_qos(&qos)
+            .build()
+            .unwrap();
+
+        let reader = BuiltinReader::<PublicationBuiltinTopicData>::new(&participant).unwrap();
+        let samples = reader.read().unwrap();
+        let publication = samples
+            .iter()
+            .filter_map(BuiltinSample::sample)
+            .find(|publication| publication.topic_name == topic_name)
+            .expect("the local writer should be discovered");
+
+        assert!(!publication.type_name.is_empty());
+        assert_eq!(publication.user_data(), Some(b"writer".as_slice()));
+        assert_eq!(
+            publication.qos.durability(),
+            Some(crate::qos::policy::Durability::TransientLocal)
+        );
+        assert_eq!(reader.peek().unwrap().len(), samples.len());
+    }
+
+    #[test]
+    fn test_builtin_reader_topic() {
+        let domain_id = crate::tests::domain::unique_id();
+        let domain = crate::Domain::new_with_xml_config(
+            domain_id,
+            "<Domain><Discovery><EnableTopicDiscoveryEndpoints>true\
+             </EnableTopicDiscoveryEndpoints></Discovery></Domain>",
+        )
+        .unwrap();
+        let topic_name = crate::tests::topic::unique_name();
+        let participant = Participant::new(&domain).unwrap();
+        let qos = QoS::new().with_topic_data(crate::qos::policy::TopicData {
+            value: b"topic".to_vec(),
+        });
+        let _topic = crate::builder::TopicBuilder::<crate::tests::topic::Data>::new(
+            &participant,
+            &topic_name,
+        )
+        .with_qos(&qos)
+        .build()
+        .unwrap();
+
+        let reader = BuiltinReader::<TopicBuiltinTopicData>::new(&participant).unwrap();
+        let samples = reader.take().unwrap();
+        let topic = samples
+            .iter()
+            .filter_map(BuiltinSample::sample)
+            .find(|topic| topic.topic_name == topic_name)
+            .expect("the local topic should be discovered");
+
+        assert!(!topic.type_name.is_empty());
+        assert_eq!(
+            topic
+                .qos
+                .topic_data()
+                .map(|topic_data| topic_data.value.as_slice()),
+            Some(b"topic".as_slice())
+        );
+    }
+
+    #[test]
+    fn test_builtin_reader_subscription() {
+        let domain_id = crate::tests::domain::unique_id();
+        let domain = crate::Domain::new(domain_id).unwrap();
+        let topic_name = crate::tests::topic::unique_name();
+        let participant = Participant::new(&domain).unwrap();
+        let topic =
+            crate::Topic::<crate::tests::topic::Data>::new(&participant, &topic_name).unwrap();
+
+        let reader = BuiltinReader::<SubscriptionBuiltinTopicData>::new(&participant).unwrap();
+        let subscription_reader = crate::Reader::new(&topic).unwrap();
+        let samples = reader.take().unwrap();
+        let subscription = samples
+            .into_iter()
+            .filter_map(BuiltinSample::into_sample)
+            .find(|subscription| subscription.topic_name == topic_name)
+            .expect("the local reader should be discovered");
+        assert_eq!(subscription.user_data(), None);
+
+        drop(subscription_reader);
+        let samples = reader.take().unwrap();
+        assert!(samples.iter().any(|sample| {
+            sample.key() == subscription.key
+                && !sample.info().state.contains(crate::state::instance::Alive)
+        }));
+    }
+}
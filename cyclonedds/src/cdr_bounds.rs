@@ -4,6 +4,16 @@
 //! computing keyhashes. Implement [`CdrBounds`] on your key type to provide
 //! this information.
 //!
+//! [`CdrBounds`] is implemented for the primitive types, arrays, tuples of up
+//! to 16 elements, [`Option`], [`Box`], [`String`], [`Vec`], [`HashMap`],
+//! [`BTreeMap`], [`std::time::Duration`], the [`bounded`](crate::bounded)
+//! types, and, with the `uuid` feature, `Uuid`, which wraps `uuid::Uuid` so
+//! that it can be encoded in CDR. Structs, including newtypes such as
+//! `struct Id([u8; 16])`, can derive it.
+//!
+//! [`HashMap`]: std::collections::HashMap
+//! [`BTreeMap`]: std::collections::BTreeMap
+//!
 //! # Examples
 //!
 //! ## Implementing bounds for bounded data
@@ -63,6 +73,35 @@
 //! assert_eq!(UnboundedData::max_serialized_cdr_size(), CdrSize::Unbounded);
 //! assert_eq!(UnboundedData::alignment(), 4);
 //! ```
+//!
+//! ## Deriving bounds
+//!
+//! The [`CdrBounds`](derive@CdrBounds) derive macro does the above for
+//! structs, using `#[dds(bound = N)]` to limit strings and sequences the way
+//! IDL's `string<N>` and `sequence<T, N>` do:
+//!
+//! ```
+//! use cyclonedds::cdr_bounds::{CdrBounds, CdrSize};
+//!
+//! #[derive(CdrBounds)]
+//! struct Position {
+//!     x: i32,
+//!     y: i32,
+//! }
+//!
+//! #[derive(CdrBounds)]
+//! struct Id {
+//!     #[dds(bound = 8)]
+//!     name: String,
+//!     position: Position,
+//! }
+//!
+//! // (4 byte length + 8 characters + null terminator) + (4 + 4)
+//! assert_eq!(Id::max_serialized_cdr_size(), CdrSize::Bounded(21));
+//! assert_eq!(Id::alignment(), 4);
+//! ```
+
+pub use cyclonedds_macros::CdrBounds;
 
 /// The maximum serialized CDR size of a type.
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
@@ -171,7 +210,9 @@ impl_cdr_size_op_assign!(SubAssign, sub_assign, -);
 /// size of a topic type is 16 bytes or fewer the keyhash is based on the
 /// big-endian serialized form of the key. Otherwise, it is based on the md5
 /// hash of the data. If the key type contains unbounded fields (e.g. `Vec`,
-/// `String`), return [`CdrSize::Unbounded`].
+/// `String`), return [`CdrSize::Unbounded`]. The types in
+/// [`bounded`](crate::bounded) can be used instead where a maximum length is
+/// known.
 pub trait CdrBounds {
     /// Returns the maximum serialized CDR size of this type.
     fn max_serialized_cdr_size() -> CdrSize;
@@ -267,6 +308,255 @@ impl CdrBounds for String {
     }
 }
 
+impl<T> CdrBounds for Option<T>
+where
+    T: CdrBounds,
+{
+    fn max_serialized_cdr_size() -> CdrSize {
+        // A `u32` discriminant followed by the value when present, which is how
+        // keys encode an `Option`. Optional members are never part of the key,
+        // since keys are always encoded in XCDR1, even for types with
+        // `OPTIONAL_MEMBERS`.
+        u32::alignment().with_padding(T::alignment()) + T::max_serialized_cdr_size()
+    }
+
+    fn alignment() -> usize {
+        u32::alignment().max(T::alignment())
+    }
+}
+
+impl<T> CdrBounds for Box<T>
+where
+    T: CdrBounds,
+{
+    fn max_serialized_cdr_size() -> CdrSize {
+        T::max_serialized_cdr_size()
+    }
+
+    fn alignment() -> usize {
+        T::alignment()
+    }
+}
+
+impl<K, V, S> CdrBounds for std::collections::HashMap<K, V, S>
+where
+    K: CdrBounds,
+    V: CdrBounds,
+{
+    fn max_serialized_cdr_size() -> CdrSize {
+        CdrSize::Unbounded
+    }
+
+    fn alignment() -> usize {
+        // max between alignment of length, key, and value.
+        u32::alignment().max(K::alignment()).max(V::alignment())
+    }
+}
+
+impl<K, V> CdrBounds for std::collections::BTreeMap<K, V>
+where
+    K: CdrBounds,
+    V: CdrBounds,
+{
+    fn max_serialized_cdr_size() -> CdrSize {
+        CdrSize::Unbounded
+    }
+
+    fn alignment() -> usize {
+        // max between alignment of length, key, and value.
+        u32::alignment().max(K::alignment()).max(V::alignment())
+    }
+}
+
+impl CdrBounds for std::time::Duration {
+    fn max_serialized_cdr_size() -> CdrSize {
+        // serde serializes a `Duration` as its seconds and nanoseconds.
+        <(u64, u32)>::max_serialized_cdr_size()
+    }
+
+    fn alignment() -> usize {
+        <(u64, u32)>::alignment()
+    }
+}
+
+/// A [`uuid::Uuid`] that is encoded as the IDL `octet[16]` holding its bytes.
+///
+/// `uuid::Uuid` itself cannot be encoded in CDR: serde hands its bytes to the
+/// encoder without the length prefix that decoding them expects, so it never
+/// round-trips. Use this wrapper for UUID members and keys instead. Formats
+/// that are human-readable, such as the JSON bridge, still get the hyphenated
+/// string.
+///
+/// # Examples
+///
+/// ```
+/// use cyclonedds::cdr_bounds::{CdrBounds, CdrSize, Uuid};
+///
+/// #[derive(
+///     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+/// )]
+/// struct Device {
+///     #[dds(key)]
+///     id: Uuid,
+///     name: String,
+/// }
+///
+/// assert_eq!(Uuid::max_serialized_cdr_size(), CdrSize::Bounded(16));
+///
+/// let device = Device {
+///     id: uuid::Uuid::nil().into(),
+///     name: "sensor".to_string(),
+/// };
+/// ```
+#[cfg(feature = "uuid")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Uuid(pub uuid::Uuid);
+
+#[cfg(feature = "uuid")]
+impl From<uuid::Uuid> for Uuid {
+    fn from(uuid: uuid::Uuid) -> Self {
+        Self(uuid)
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl From<Uuid> for uuid::Uuid {
+    fn from(uuid: Uuid) -> Self {
+        uuid.0
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl serde::Serialize for Uuid {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            serde::Serialize::serialize(&self.0, serializer)
+        } else {
+            serde::Serialize::serialize(self.0.as_bytes(), serializer)
+        }
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl<'de> serde::Deserialize<'de> for Uuid {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            <uuid::Uuid as serde::Deserialize>::deserialize(deserializer).map(Self)
+        } else {
+            <[u8; 16] as serde::Deserialize>::deserialize(deserializer)
+                .map(|bytes| Self(uuid::Uuid::from_bytes(bytes)))
+        }
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl CdrBounds for Uuid {
+    fn max_serialized_cdr_size() -> CdrSize {
+        <[u8; 16]>::max_serialized_cdr_size()
+    }
+
+    fn alignment() -> usize {
+        <[u8; 16]>::alignment()
+    }
+}
+
+/// Describes the CDR serialization bounds of a string or sequence type when
+/// it is limited to a maximum number of elements.
+///
+/// This is what `#[dds(bound = N)]` relies on in the
+/// [`CdrBounds`](derive@CdrBounds) derive macro.
+pub trait CdrSequenceBounds: CdrBounds {
+    /// Returns the maximum serialized CDR size of this type when it holds at
+    /// most `bound` elements.
+    fn max_bounded_serialized_cdr_size(bound: usize) -> CdrSize;
+
+    /// Returns the number of elements counted against the bound, i.e. the
+    /// number of elements of a sequence or the number of bytes of a string.
+    fn bounded_len(&self) -> usize;
+}
+
+/// Serializes `value`, failing if it holds more than `bound` elements.
+///
+/// This is what the [`Topicable`](derive@crate::Topicable) derive macro uses
+/// to enforce `#[dds(bound = N)]` on key fields, so that writing a sample
+/// whose key exceeds its bounds fails.
+///
+/// # Errors
+///
+/// Returns an error if `value` exceeds `bound` or fails to serialize.
+#[doc(hidden)]
+pub fn serialize_bounded<T, S>(value: &T, bound: usize, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: CdrSequenceBounds + serde::Serialize,
+    S: serde::Serializer,
+{
+    let len = value.bounded_len();
+    if len > bound {
+        return Err(serde::ser::Error::custom(format_args!(
+            "length of {len} exceeds the bound of {bound}"
+        )));
+    }
+    value.serialize(serializer)
+}
+
+/// Returns the maximum serialized CDR size of a struct whose fields have the
+/// given maximum sizes and alignments, in declaration order.
+///
+/// Like the tuple implementations of [`CdrBounds`], every field is padded to
+/// its alignment and the total to the largest alignment. This is what the
+/// [`CdrBounds`](derive@CdrBounds) and [`Topicable`](derive@crate::Topicable)
+/// derive macros use for structs and their generated keys.
+#[doc(hidden)]
+#[must_use]
+pub fn struct_max_serialized_cdr_size(fields: &[(CdrSize, usize)]) -> CdrSize {
+    // NOTE: the alignment of a zero-sized field (e.g. `()`) is 0, which adds no
+    // padding.
+    let alignment = fields
+        .iter()
+        .map(|&(_, alignment)| alignment)
+        .fold(1, usize::max);
+    fields
+        .iter()
+        .fold(
+            CdrSize::Bounded(0),
+            |size, &(field_size, field_alignment)| {
+                size.with_padding(field_alignment.max(1)) + field_size
+            },
+        )
+        .with_padding(alignment)
+}
+
+impl<T> CdrSequenceBounds for Vec<T>
+where
+    T: CdrBounds,
+{
+    fn max_bounded_serialized_cdr_size(bound: usize) -> CdrSize {
+        u32::alignment().with_padding(T::alignment())
+            + T::max_serialized_cdr_size().with_padding(T::alignment()) * bound
+    }
+
+    fn bounded_len(&self) -> usize {
+        self.len()
+    }
+}
+
+impl CdrSequenceBounds for String {
+    fn max_bounded_serialized_cdr_size(bound: usize) -> CdrSize {
+        // Length prefix, characters, and the NUL terminator.
+        CdrSize::Bounded(u32::alignment() + bound + 1)
+    }
+
+    fn bounded_len(&self) -> usize {
+        self.len()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -420,4 +710,150 @@ mod tests {
             <(u32, u32, u32, u32, u8)>::max_serialized_cdr_size()
         );
     }
+
+    #[test]
+    fn test_cdr_size_bounds_struct() {
+        // `{ u8, u64, u8 }`: 1 + 7 padding + 8 + 1 + 7 trailing padding.
+        assert_eq!(
+            struct_max_serialized_cdr_size(&[
+                (u8::max_serialized_cdr_size(), u8::alignment()),
+                (u64::max_serialized_cdr_size(), u64::alignment()),
+                (u8::max_serialized_cdr_size(), u8::alignment()),
+            ]),
+            CdrSize::Bounded(24)
+        );
+        // `{ string<8>, { i32, i32 } }`: 13 + 3 padding + 8.
+        assert_eq!(
+            struct_max_serialized_cdr_size(&[
+                (
+                    String::max_bounded_serialized_cdr_size(8),
+                    String::alignment()
+                ),
+                (
+                    <(i32, i32)>::max_serialized_cdr_size(),
+                    <(i32, i32)>::alignment()
+                ),
+            ]),
+            CdrSize::Bounded(24)
+        );
+        // `{ u16, u8, u32 }`: 2 + 1 + 1 padding + 4.
+        assert_eq!(
+            struct_max_serialized_cdr_size(&[
+                (u16::max_serialized_cdr_size(), u16::alignment()),
+                (u8::max_serialized_cdr_size(), u8::alignment()),
+                (u32::max_serialized_cdr_size(), u32::alignment()),
+            ]),
+            CdrSize::Bounded(8)
+        );
+        assert_eq!(
+            struct_max_serialized_cdr_size(&[
+                (<()>::max_serialized_cdr_size(), <()>::alignment()),
+                (u8::max_serialized_cdr_size(), u8::alignment()),
+            ]),
+            CdrSize::Bounded(1)
+        );
+        assert_eq!(
+            struct_max_serialized_cdr_size(&[
+                (u8::max_serialized_cdr_size(), u8::alignment()),
+                (String::max_serialized_cdr_size(), String::alignment()),
+            ]),
+            CdrSize::Unbounded
+        );
+        assert_eq!(struct_max_serialized_cdr_size(&[]), CdrSize::Bounded(0));
+    }
+
+    #[test]
+    fn test_cdr_size_bounds_standard_types() {
+        assert_eq!(
+            CdrSize::Bounded(4 + 1),
+            <Option<u8>>::max_serialized_cdr_size()
+        );
+        assert_eq!(4, <Option<u8>>::alignment());
+        assert_eq!(
+            CdrSize::Bounded(8 + 8),
+            <Option<f64>>::max_serialized_cdr_size()
+        );
+        assert_eq!(8, <Option<f64>>::alignment());
+        assert_eq!(
+            CdrSize::Unbounded,
+            <Option<String>>::max_serialized_cdr_size()
+        );
+
+        assert_eq!(CdrSize::Bounded(8), <Box<u64>>::max_serialized_cdr_size());
+        assert_eq!(8, <Box<u64>>::alignment());
+        assert_eq!(
+            <Box<[u16; 3]>>::max_serialized_cdr_size(),
+            <[u16; 3]>::max_serialized_cdr_size()
+        );
+
+        assert_eq!(
+            CdrSize::Unbounded,
+            <std::collections::HashMap<u8, u8>>::max_serialized_cdr_size()
+        );
+        assert_eq!(4, <std::collections::HashMap<u8, u8>>::alignment());
+        assert_eq!(
+            CdrSize::Unbounded,
+            <std::collections::BTreeMap<u32, f64>>::max_serialized_cdr_size()
+        );
+        assert_eq!(8, <std::collections::BTreeMap<u32, f64>>::alignment());
+
+        assert_eq!(
+            CdrSize::Bounded(16),
+            <std::time::Duration>::max_serialized_cdr_size()
+        );
+        assert_eq!(8, <std::time::Duration>::alignment());
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn test_cdr_size_bounds_uuid() {
+        let uuid = Uuid(uuid::Uuid::from_u128(
+            0x0123_4567_89ab_cdef_0123_4567_89ab_cdef,
+        ));
+        let serialized = cdr_encoding::to_vec::<_, byteorder::LittleEndian>(&uuid).unwrap();
+        assert_eq!(serialized, uuid.0.as_bytes());
+        assert_eq!(CdrSize::Bounded(16), Uuid::max_serialized_cdr_size());
+        assert_eq!(1, Uuid::alignment());
+
+        let (deserialized, _) =
+            cdr_encoding::from_bytes::<Uuid, byteorder::LittleEndian>(&serialized).unwrap();
+        assert_eq!(deserialized, uuid);
+
+        // The alignment of a following member is unaffected by the UUID.
+        let serialized =
+            cdr_encoding::to_vec::<_, byteorder::LittleEndian>(&(7_u8, uuid, 1_u32)).unwrap();
+        assert_eq!(serialized.len(), 1 + 16 + 3 + 4);
+        let (deserialized, _) =
+            cdr_encoding::from_bytes::<(u8, Uuid, u32), byteorder::LittleEndian>(&serialized)
+                .unwrap();
+        assert_eq!(deserialized, (7, uuid, 1));
+    }
+
+    #[test]
+    fn test_cdr_size_bounds_sequences_with_bound() {
+        assert_eq!(
+            CdrSize::Bounded(4 + 16 + 1),
+            String::max_bounded_serialized_cdr_size(16)
+        );
+        assert_eq!(
+            CdrSize::Bounded(4 + 3),
+            <Vec<u8>>::max_bounded_serialized_cdr_size(3)
+        );
+        assert_eq!(
+            CdrSize::Bounded(8 + 2 * 8),
+            <Vec<i64>>::max_bounded_serialized_cdr_size(2)
+        );
+        assert_eq!(
+            CdrSize::Bounded(4 + 2 * 4),
+            <Vec<(u16, u8)>>::max_bounded_serialized_cdr_size(2)
+        );
+        assert_eq!(
+            CdrSize::Unbounded,
+            <Vec<String>>::max_bounded_serialized_cdr_size(2)
+        );
+        assert_eq!(
+            CdrSize::Bounded(4),
+            <Vec<u32>>::max_bounded_serialized_cdr_size(0)
+        );
+    }
 }
@@ -2,8 +2,10 @@
 //! domain ID). Only entities within the same domain can publish or subscribe to
 //! each other's data.
 
+pub(crate) mod config;
+
 use crate::internal::ffi;
-use crate::{Error, Result};
+use crate::{DomainConfig, Error, Result};
 
 /// A communication boundary for DDS publish-subscribe traffic.
 #[derive(Debug)]
@@ -79,6 +81,42 @@ impl Domain {
             inner,
         })
     }
+
+    /// Creates a new domain with the given `domain_id` from a typed
+    /// [`DomainConfig`].
+    ///
+    /// The configuration is validated (see [`DomainConfig::validate`]) before
+    /// it is rendered to XML and passed to Cyclone DDS.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::InvalidOption`](crate::config::ConfigError::InvalidOption)
+    /// naming the offending option if the configuration cannot be rendered, and
+    /// [`ConfigError::Dds`](crate::config::ConfigError::Dds) if Cyclone DDS
+    /// rejects the configuration or cannot create the domain with the provided
+    /// domain ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclonedds::config::Verbosity;
+    /// use cyclonedds::{Domain, DomainConfig};
+    ///
+    /// let config = DomainConfig::new()
+    ///     .with_max_message_size(1400)
+    ///     .with_tracing_verbosity(Verbosity::Warning);
+    ///
+    /// // Create a new domain with the configuration and a domain ID of 1.
+    /// let domain = Domain::new_with_config(1, &config)?;
+    /// # Ok::<_, cyclonedds::config::ConfigError>(())
+    /// ```
+    pub fn new_with_config(
+        domain_id: u32,
+        config: &DomainConfig,
+    ) -> Result<Self, config::ConfigError> {
+        let config = config.to_xml()?;
+        Ok(Self::new_with_xml_config(domain_id, &config)?)
+    }
 }
 
 impl Drop for Domain {
@@ -162,4 +200,43 @@ mod tests {
         let xml_config = "<Domain><General><MaxMessageSize>1400B</MaxMessageSize></General>";
         Domain::new_with_xml_config(domain_id, xml_config).unwrap();
     }
+
+    #[test]
+    fn test_domain_create_with_config() {
+        let domain_id = crate::tests::domain::unique_id();
+        let config = DomainConfig::new()
+            .with_peer("localhost")
+            .with_spdp_interval(crate::Duration::from_secs(1))
+            .with_max_message_size(1400)
+            .with_tracing_verbosity(config::Verbosity::Warning)
+            .with_tracing_output(config::TracingOutput::Stderr);
+        Domain::new_with_config(domain_id, &config).unwrap();
+    }
+
+    #[test]
+    fn test_domain_create_with_invalid_config() {
+        let domain_id = crate::tests::domain::unique_id();
+        let config = DomainConfig::new().with_peer("local host");
+        let err = Domain::new_with_config(domain_id, &config).unwrap_err();
+        assert!(matches!(
+            err,
+            config::ConfigError::InvalidOption {
+                option: "Discovery/Peers/Peer",
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_domain_create_with_config_with_explicit_default_id() {
+        let domain_id = cyclonedds_sys::DOMAIN_DEFAULT;
+        let config = DomainConfig::new();
+        let err = Domain::new_with_config(domain_id, &config).unwrap_err();
+        assert_eq!(err, config::ConfigError::Dds(Error::BadParameter));
+
+        // The port mapping is left to Cyclone DDS to check.
+        let config = DomainConfig::new().with_port_base(7400);
+        let err = Domain::new_with_config(domain_id, &config).unwrap_err();
+        assert_eq!(err, config::ConfigError::Dds(Error::BadParameter));
+    }
 }
@@ -0,0 +1,767 @@
+//! A typed builder for the Cyclone DDS XML configuration of a [`Domain`].
+//!
+//! [`DomainConfig`] covers the most commonly used settings. Anything it does
+//! not cover can still be supplied as raw XML through
+//! [`Domain::new_with_xml_config`].
+//!
+//! [`Domain`]: crate::Domain
+//! [`Domain::new_with_xml_config`]: crate::Domain::new_with_xml_config
+
+use std::fmt::Write;
+
+use crate::Duration;
+
+/// Errors that can occur when validating a [`DomainConfig`] or creating a
+/// [`Domain`](crate::Domain) from it.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConfigError {
+    /// A configuration option holds a value that is not accepted.
+    InvalidOption {
+        /// The path of the offending option in the Cyclone DDS XML
+        /// configuration, e.g. `General/MaxMessageSize`.
+        option: &'static str,
+        /// A description of why the value was rejected.
+        reason: String,
+    },
+    /// The configuration was valid but Cyclone DDS failed to create the
+    /// domain.
+    Dds(crate::Error),
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::InvalidOption { option, reason } => {
+                write!(
+                    f,
+                    "invalid value for configuration option {option}: {reason}"
+                )
+            }
+            ConfigError::Dds(err) => write!(f, "unable to create domain: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ConfigError::InvalidOption { .. } => None,
+            ConfigError::Dds(err) => Some(err),
+        }
+    }
+}
+
+impl From<crate::Error> for ConfigError {
+    #[inline]
+    fn from(err: crate::Error) -> Self {
+        ConfigError::Dds(err)
+    }
+}
+
+/// A network interface that Cyclone DDS should use.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NetworkInterface {
+    /// Selects the interface by its name, e.g. `eth0`.
+    Name(String),
+    /// Selects the interface by one of its addresses.
+    Address(std::net::IpAddr),
+}
+
+/// Controls which kinds of traffic may use multicast.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Multicast {
+    /// Multicast is not used at all.
+    Disabled,
+    /// Multicast is used for all traffic.
+    Enabled,
+    /// Multicast is only used for participant discovery.
+    Spdp,
+    /// Any-source multicast is used.
+    Asm,
+    /// Source-specific multicast is used.
+    Ssm,
+}
+
+impl Multicast {
+    const fn as_xml(self) -> &'static str {
+        match self {
+            Multicast::Disabled => "false",
+            Multicast::Enabled => "true",
+            Multicast::Spdp => "spdp",
+            Multicast::Asm => "asm",
+            Multicast::Ssm => "ssm",
+        }
+    }
+}
+
+/// The verbosity of the Cyclone DDS trace output.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    /// Nothing is traced.
+    None,
+    /// Only severe errors are traced.
+    Severe,
+    /// Errors are traced.
+    Error,
+    /// Warnings and errors are traced.
+    Warning,
+    /// Informational messages are traced.
+    Info,
+    /// Configuration details are traced.
+    Config,
+    /// Fine-grained diagnostics are traced.
+    Fine,
+    /// Finer-grained diagnostics are traced.
+    Finer,
+    /// Everything is traced.
+    Finest,
+}
+
+impl Verbosity {
+    const fn as_xml(self) -> &'static str {
+        match self {
+            Verbosity::None => "none",
+            Verbosity::Severe => "severe",
+            Verbosity::Error => "error",
+            Verbosity::Warning => "warning",
+            Verbosity::Info => "info",
+            Verbosity::Config => "config",
+            Verbosity::Fine => "fine",
+            Verbosity::Finer => "finer",
+            Verbosity::Finest => "finest",
+        }
+    }
+}
+
+/// The destination of the Cyclone DDS trace output.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TracingOutput {
+    /// Traces are written to the standard output.
+    Stdout,
+    /// Traces are written to the standard error.
+    Stderr,
+    /// Traces are written to the given file.
+    File(std::path::PathBuf),
+}
+
+/// A shared-memory transport provided through a PSMX (publish-subscribe
+/// message exchange) plugin.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SharedMemory {
+    /// The type of the PSMX plugin, e.g. `iox` or `cdds`.
+    pub kind: String,
+    /// The library implementing the plugin. If unset, Cyclone DDS derives it
+    /// from the `kind`.
+    pub library: Option<String>,
+    /// Plugin specific configuration passed through verbatim.
+    pub config: Option<String>,
+}
+
+/// A typed Cyclone DDS domain configuration.
+///
+/// Unset options keep their Cyclone DDS defaults. The configuration is
+/// validated and rendered to XML when a [`Domain`](crate::Domain) is created
+/// from it via [`Domain::new_with_config`](crate::Domain::new_with_config).
+///
+/// # Examples
+///
+/// ```
+/// use cyclonedds::config::{TracingOutput, Verbosity};
+/// use cyclonedds::{Domain, DomainConfig, Duration};
+///
+/// let config = DomainConfig::new()
+///     .with_peer("localhost")
+///     .with_spdp_interval(Duration::from_secs(1))
+///     .with_max_message_size(1400)
+///     .with_tracing_verbosity(Verbosity::Warning)
+///     .with_tracing_output(TracingOutput::Stderr);
+///
+/// let domain = Domain::new_with_config(1, &config)?;
+/// # Ok::<_, cyclonedds::config::ConfigError>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DomainConfig {
+    network_interfaces: Vec<NetworkInterface>,
+    allow_multicast: Option<Multicast>,
+    spdp_multicast_address: Option<std::net::IpAddr>,
+    peers: Vec<String>,
+    spdp_interval: Option<Duration>,
+    port_base: Option<u16>,
+    max_message_size: Option<u32>,
+    tracing_verbosity: Option<Verbosity>,
+    tracing_output: Option<TracingOutput>,
+    shared_memory: Option<SharedMemory>,
+}
+
+impl DomainConfig {
+    /// Creates a new [`DomainConfig`] with no options set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclonedds::DomainConfig;
+    ///
+    /// let config = DomainConfig::new();
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a network interface to use (`General/Interfaces`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclonedds::DomainConfig;
+    /// use cyclonedds::config::NetworkInterface;
+    ///
+    /// let config = DomainConfig::new().with_network_interface(NetworkInterface::Name("lo".into()));
+    /// ```
+    #[must_use]
+    pub fn with_network_interface(mut self, network_interface: NetworkInterface) -> Self {
+        self.network_interfaces.push(network_interface);
+        self
+    }
+
+    /// Sets which traffic may use multicast (`General/AllowMulticast`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclonedds::DomainConfig;
+    /// use cyclonedds::config::Multicast;
+    ///
+    /// let config = DomainConfig::new().with_allow_multicast(Multicast::Spdp);
+    /// ```
+    #[must_use]
+    pub const fn with_allow_multicast(mut self, allow_multicast: Multicast) -> Self {
+        self.allow_multicast = Some(allow_multicast);
+        self
+    }
+
+    /// Sets the multicast address used for participant discovery
+    /// (`Discovery/SPDPMulticastAddress`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclonedds::DomainConfig;
+    ///
+    /// let config = DomainConfig::new().with_spdp_multicast_address([239, 255, 0, 1].into());
+    /// ```
+    #[must_use]
+    pub const fn with_spdp_multicast_address(mut self, address: std::net::IpAddr) -> Self {
+        self.spdp_multicast_address = Some(address);
+        self
+    }
+
+    /// Adds a unicast discovery peer (`Discovery/Peers`).
+    ///
+    /// The `address` is a host name or IP address with an optional port.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclonedds::DomainConfig;
+    ///
+    /// let config = DomainConfig::new()
+    ///     .with_peer("192.168.1.10")
+    ///     .with_peer("localhost:7410");
+    /// ```
+    #[must_use]
+    pub fn with_peer(mut self, address: impl Into<String>) -> Self {
+        self.peers.push(address.into());
+        self
+    }
+
+    /// Sets the interval between participant announcements
+    /// (`Discovery/SPDPInterval`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclonedds::{DomainConfig, Duration};
+    ///
+    /// let config = DomainConfig::new().with_spdp_interval(Duration::from_secs(5));
+    /// ```
+    #[must_use]
+    pub const fn with_spdp_interval(mut self, interval: Duration) -> Self {
+        self.spdp_interval = Some(interval);
+        self
+    }
+
+    /// Sets the base of the DDSI port mapping (`Discovery/Ports/Base`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclonedds::DomainConfig;
+    ///
+    /// let config = DomainConfig::new().with_port_base(7400);
+    /// ```
+    #[must_use]
+    pub const fn with_port_base(mut self, port_base: u16) -> Self {
+        self.port_base = Some(port_base);
+        self
+    }
+
+    /// Sets the maximum size in bytes of an RTPS message
+    /// (`General/MaxMessageSize`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclonedds::DomainConfig;
+    ///
+    /// let config = DomainConfig::new().with_max_message_size(1400);
+    /// ```
+    #[must_use]
+    pub const fn with_max_message_size(mut self, max_message_size: u32) -> Self {
+        self.max_message_size = Some(max_message_size);
+        self
+    }
+
+    /// Sets the verbosity of the trace output (`Tracing/Verbosity`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclonedds::DomainConfig;
+    /// use cyclonedds::config::Verbosity;
+    ///
+    /// let config = DomainConfig::new().with_tracing_verbosity(Verbosity::Config);
+    /// ```
+    #[must_use]
+    pub const fn with_tracing_verbosity(mut self, verbosity: Verbosity) -> Self {
+        self.tracing_verbosity = Some(verbosity);
+        self
+    }
+
+    /// Sets the destination of the trace output (`Tracing/OutputFile`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclonedds::DomainConfig;
+    /// use cyclonedds::config::TracingOutput;
+    ///
+    /// let config = DomainConfig::new().with_tracing_output(TracingOutput::File("cdds.log".into()));
+    /// ```
+    #[must_use]
+    pub fn with_tracing_output(mut self, output: TracingOutput) -> Self {
+        self.tracing_output = Some(output);
+        self
+    }
+
+    /// Enables a shared-memory transport
+    /// (`General/Interfaces/PubSubMessageExchange`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclonedds::DomainConfig;
+    /// use cyclonedds::config::SharedMemory;
+    ///
+    /// let config = DomainConfig::new().with_shared_memory(SharedMemory {
+    ///     kind: "iox".to_string(),
+    ///     library: None,
+    ///     config: None,
+    /// });
+    /// ```
+    #[must_use]
+    pub fn with_shared_memory(mut self, shared_memory: SharedMemory) -> Self {
+        self.shared_memory = Some(shared_memory);
+        self
+    }
+
+    /// Checks that every option set can be rendered as XML, e.g. that names
+    /// are not empty and addresses are of the right kind.
+    ///
+    /// Numeric ranges (such as the message size or the port mapping) are left
+    /// to Cyclone DDS, which rejects them when the domain is created.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::InvalidOption`] naming the first offending
+    /// option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclonedds::DomainConfig;
+    /// use cyclonedds::config::ConfigError;
+    ///
+    /// let err = DomainConfig::new().with_peer("local host").validate().unwrap_err();
+    /// assert!(matches!(
+    ///     err,
+    ///     ConfigError::InvalidOption {
+    ///         option: "Discovery/Peers/Peer",
+    ///         ..
+    ///     }
+    /// ));
+    /// ```
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        fn invalid(option: &'static str, reason: impl Into<String>) -> ConfigError {
+            ConfigError::InvalidOption {
+                option,
+                reason: reason.into(),
+            }
+        }
+
+        fn check_text(option: &'static str, text: &str) -> Result<(), ConfigError> {
+            if text.is_empty() {
+                Err(invalid(option, "value must not be empty"))
+            } else if text.contains('\0') {
+                Err(invalid(
+                    option,
+                    format!("value contains a null byte: {text:?}"),
+                ))
+            } else {
+                Ok(())
+            }
+        }
+
+        for network_interface in &self.network_interfaces {
+            if let NetworkInterface::Name(name) = network_interface {
+                check_text("General/Interfaces/NetworkInterface", name)?;
+            }
+        }
+
+        if let Some(address) = self.spdp_multicast_address {
+            if !address.is_multicast() {
+                return Err(invalid(
+                    "Discovery/SPDPMulticastAddress",
+                    format!("{address} is not a multicast address"),
+                ));
+            }
+        }
+
+        for peer in &self.peers {
+            check_text("Discovery/Peers/Peer", peer)?;
+            if peer.chars().any(char::is_whitespace) {
+                return Err(invalid(
+                    "Discovery/Peers/Peer",
+                    format!("address contains whitespace: {peer:?}"),
+                ));
+            }
+        }
+
+        if let Some(interval) = self.spdp_interval {
+            if interval.as_nanos() <= 0 {
+                return Err(invalid(
+                    "Discovery/SPDPInterval",
+                    format!("interval must be positive, got {interval:?}"),
+                ));
+            }
+        }
+
+        if let Some(TracingOutput::File(path)) = &self.tracing_output {
+            let path = path.to_str().ok_or_else(|| {
+                invalid(
+                    "Tracing/OutputFile",
+                    format!("path is not valid UTF-8: {path:?}"),
+                )
+            })?;
+            check_text("Tracing/OutputFile", path)?;
+        }
+
+        if let Some(shared_memory) = &self.shared_memory {
+            check_text(
+                "General/Interfaces/PubSubMessageExchange[@type]",
+                &shared_memory.kind,
+            )?;
+            if let Some(library) = &shared_memory.library {
+                check_text(
+                    "General/Interfaces/PubSubMessageExchange[@library]",
+                    library,
+                )?;
+            }
+            if let Some(config) = &shared_memory.config {
+                check_text("General/Interfaces/PubSubMessageExchange[@config]", config)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validates the configuration and renders it as a Cyclone DDS XML
+    /// configuration fragment.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::InvalidOption`] naming the first offending
+    /// option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclonedds::DomainConfig;
+    ///
+    /// let xml = DomainConfig::new().with_max_message_size(1400).to_xml()?;
+    /// assert_eq!(
+    ///     xml,
+    ///     "<Domain><General><MaxMessageSize>1400B</MaxMessageSize></General></Domain>"
+    /// );
+    /// # Ok::<_, cyclonedds::config::ConfigError>(())
+    /// ```
+    pub fn to_xml(&self) -> Result<String, ConfigError> {
+        self.validate()?;
+
+        // NOTE: writing into a `String` cannot fail so the results of `write!` are
+        // ignored throughout.
+        let mut xml = String::from("<Domain>");
+
+        let has_interfaces = !self.network_interfaces.is_empty() || self.shared_memory.is_some();
+        if has_interfaces || self.allow_multicast.is_some() || self.max_message_size.is_some() {
+            xml.push_str("<General>");
+            if has_interfaces {
+                xml.push_str("<Interfaces>");
+                for network_interface in &self.network_interfaces {
+                    let _ = match network_interface {
+                        NetworkInterface::Name(name) => {
+                            write!(xml, "<NetworkInterface name=\"{}\"/>", escape(name))
+                        }
+                        NetworkInterface::Address(address) => {
+                            write!(xml, "<NetworkInterface address=\"{address}\"/>")
+                        }
+                    };
+                }
+                if let Some(shared_memory) = &self.shared_memory {
+                    let _ = write!(
+                        xml,
+                        "<PubSubMessageExchange type=\"{}\"",
+                        escape(&shared_memory.kind)
+                    );
+                    if let Some(library) = &shared_memory.library {
+                        let _ = write!(xml, " library=\"{}\"", escape(library));
+                    }
+                    if let Some(config) = &shared_memory.config {
+                        let _ = write!(xml, " config=\"{}\"", escape(config));
+                    }
+                    xml.push_str("/>");
+                }
+                xml.push_str("</Interfaces>");
+            }
+            if let Some(allow_multicast) = self.allow_multicast {
+                let _ = write!(
+                    xml,
+                    "<AllowMulticast>{}</AllowMulticast>",
+                    allow_multicast.as_xml()
+                );
+            }
+            if let Some(max_message_size) = self.max_message_size {
+                let _ = write!(xml, "<MaxMessageSize>{max_message_size}B</MaxMessageSize>");
+            }
+            xml.push_str("</General>");
+        }
+
+        if self.spdp_multicast_address.is_some()
+            || !self.peers.is_empty()
+            || self.spdp_interval.is_some()
+            || self.port_base.is_some()
+        {
+            xml.push_str("<Discovery>");
+            if let Some(address) = self.spdp_multicast_address {
+                let _ = write!(
+                    xml,
+                    "<SPDPMulticastAddress>{address}</SPDPMulticastAddress>"
+                );
+            }
+            if !self.peers.is_empty() {
+                xml.push_str("<Peers>");
+                for peer in &self.peers {
+                    let _ = write!(xml, "<Peer address=\"{}\"/>", escape(peer));
+                }
+                xml.push_str("</Peers>");
+            }
+            if let Some(interval) = self.spdp_interval {
+                if interval.is_infinite() {
+                    xml.push_str("<SPDPInterval>inf</SPDPInterval>");
+                } else {
+                    let _ = write!(
+                        xml,
+                        "<SPDPInterval>{}ns</SPDPInterval>",
+                        interval.as_nanos()
+                    );
+                }
+            }
+            if let Some(port_base) = self.port_base {
+                let _ = write!(xml, "<Ports><Base>{port_base}</Base></Ports>");
+            }
+            xml.push_str("</Discovery>");
+        }
+
+        if self.tracing_verbosity.is_some() || self.tracing_output.is_some() {
+            xml.push_str("<Tracing>");
+            if let Some(verbosity) = self.tracing_verbosity {
+                let _ = write!(xml, "<Verbosity>{}</Verbosity>", verbosity.as_xml());
+            }
+            if let Some(output) = &self.tracing_output {
+                let output = match output {
+                    TracingOutput::Stdout => "stdout".into(),
+                    TracingOutput::Stderr => "stderr".into(),
+                    // NOTE: the path is guaranteed to be valid UTF-8 by the validation above.
+                    TracingOutput::File(path) => escape(&path.to_string_lossy()).into_owned(),
+                };
+                let _ = write!(xml, "<OutputFile>{output}</OutputFile>");
+            }
+            xml.push_str("</Tracing>");
+        }
+
+        xml.push_str("</Domain>");
+        Ok(xml)
+    }
+}
+
+/// Escapes the characters that are not allowed verbatim in XML text and
+/// attribute values.
+fn escape(text: &str) -> std::borrow::Cow<'_, str> {
+    if text.contains(['&', '<', '>', '"', '\'']) {
+        let mut escaped = String::with_capacity(text.len());
+        for c in text.chars() {
+            match c {
+                '&' => escaped.push_str("&amp;"),
+                '<' => escaped.push_str("&lt;"),
+                '>' => escaped.push_str("&gt;"),
+                '"' => escaped.push_str("&quot;"),
+                '\'' => escaped.push_str("&apos;"),
+                c => escaped.push(c),
+            }
+        }
+        std::borrow::Cow::Owned(escaped)
+    } else {
+        std::borrow::Cow::Borrowed(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_domain_config_empty() {
+        let xml = DomainConfig::new().to_xml().unwrap();
+        assert_eq!(xml, "<Domain></Domain>");
+    }
+
+    #[test]
+    fn test_domain_config_render() {
+        let config = DomainConfig::new()
+            .with_network_interface(NetworkInterface::Name("eth0".to_string()))
+            .with_network_interface(NetworkInterface::Address([127, 0, 0, 1].into()))
+            .with_shared_memory(SharedMemory {
+                kind: "iox".to_string(),
+                library: Some("psmx_iox".to_string()),
+                config: Some("SERVICE_NAME=\"a&b\";".to_string()),
+            })
+            .with_allow_multicast(Multicast::Spdp)
+            .with_max_message_size(1400)
+            .with_spdp_multicast_address([239, 255, 0, 1].into())
+            .with_peer("localhost")
+            .with_peer("10.0.0.1:7410")
+            .with_spdp_interval(Duration::from_millis(500))
+            .with_port_base(7400)
+            .with_tracing_verbosity(Verbosity::Finest)
+            .with_tracing_output(TracingOutput::Stderr);
+
+        let expected = "<Domain><General><Interfaces><NetworkInterface name=\"eth0\"/>\
+                        <NetworkInterface address=\"127.0.0.1\"/><PubSubMessageExchange \
+                        type=\"iox\" library=\"psmx_iox\" \
+                        config=\"SERVICE_NAME=&quot;a&amp;b&quot;;\"/></Interfaces>\
+                        <AllowMulticast>spdp</AllowMulticast><MaxMessageSize>1400B\
+                        </MaxMessageSize></General><Discovery><SPDPMulticastAddress>239.255.0.1\
+                        </SPDPMulticastAddress><Peers><Peer address=\"localhost\"/><Peer \
+                        address=\"10.0.0.1:7410\"/></Peers><SPDPInterval>500000000ns\
+                        </SPDPInterval><Ports><Base>7400</Base></Ports></Discovery><Tracing>\
+                        <Verbosity>finest</Verbosity><OutputFile>stderr</OutputFile></Tracing>\
+                        </Domain>";
+        assert_eq!(config.to_xml().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_domain_config_render_infinite_spdp_interval() {
+        let xml = DomainConfig::new()
+            .with_spdp_interval(Duration::INFINITE)
+            .to_xml()
+            .unwrap();
+        assert_eq!(
+            xml,
+            "<Domain><Discovery><SPDPInterval>inf</SPDPInterval></Discovery></Domain>"
+        );
+    }
+
+    #[test]
+    fn test_domain_config_invalid_options() {
+        fn invalid_option(config: &DomainConfig) -> &'static str {
+            match config.validate().unwrap_err() {
+                ConfigError::InvalidOption { option, .. } => option,
+                err @ ConfigError::Dds(_) => panic!("unexpected error: {err}"),
+            }
+        }
+
+        let config =
+            DomainConfig::new().with_network_interface(NetworkInterface::Name(String::new()));
+        assert_eq!(
+            invalid_option(&config),
+            "General/Interfaces/NetworkInterface"
+        );
+
+        let config = DomainConfig::new().with_spdp_multicast_address([10, 0, 0, 1].into());
+        assert_eq!(invalid_option(&config), "Discovery/SPDPMulticastAddress");
+
+        let config = DomainConfig::new().with_peer("local host");
+        assert_eq!(invalid_option(&config), "Discovery/Peers/Peer");
+
+        let config = DomainConfig::new().with_peer("\0");
+        assert_eq!(invalid_option(&config), "Discovery/Peers/Peer");
+
+        let config = DomainConfig::new().with_spdp_interval(Duration::from_nanos(0));
+        assert_eq!(invalid_option(&config), "Discovery/SPDPInterval");
+
+        let config = DomainConfig::new().with_tracing_output(TracingOutput::File("".into()));
+        assert_eq!(invalid_option(&config), "Tracing/OutputFile");
+
+        let config = DomainConfig::new().with_shared_memory(SharedMemory {
+            kind: String::new(),
+            library: None,
+            config: None,
+        });
+        assert_eq!(
+            invalid_option(&config),
+            "General/Interfaces/PubSubMessageExchange[@type]"
+        );
+    }
+
+    #[test]
+    fn test_domain_config_leaves_ranges_to_cyclone() {
+        DomainConfig::new()
+            .with_max_message_size(0)
+            .validate()
+            .unwrap();
+        DomainConfig::new()
+            .with_max_message_size(u32::MAX)
+            .with_port_base(u16::MAX)
+            .validate()
+            .unwrap();
+    }
+
+    #[test]
+    fn test_domain_config_error_display() {
+        let err = ConfigError::InvalidOption {
+            option: "General/MaxMessageSize",
+            reason: "too large".to_string(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "invalid value for configuration option General/MaxMessageSize: too large"
+        );
+
+        let err = ConfigError::from(crate::Error::BadParameter);
+        assert_eq!(
+            err.to_string(),
+            "unable to create domain: bad parameter provided"
+        );
+    }
+}
@@ -0,0 +1,63 @@
+//! Runtime-typed topics, readers, and writers.
+//!
+//! Bridges, recorders, and debugging tools often need to handle data types
+//! that are not known when they are compiled. The types in this module
+//! describe a data type at runtime with a [`StructType`] and represent its
+//! samples as a [`DynamicData`] tree that can be inspected and constructed
+//! field by field.
+//!
+//! A [`StructType`] can be built by hand or parsed from IDL with
+//! [`StructType::from_idl`]. Samples are encoded exactly as the equivalent
+//! [`Topicable`](crate::Topicable) type would be, so a [`DynamicWriter`] and a
+//! typed [`Reader`](crate::Reader) (or vice versa) interoperate as long as the
+//! type names and layouts agree.
+//!
+//! NOTE: Cyclone's XTypes type discovery is not integrated with this crate,
+//! so the type of a remote topic can't be looked up at runtime and has to be
+//! provided by the application.
+//!
+//! ```
+//! use cyclonedds::dynamic::{
+//!     DynamicData, DynamicReader, DynamicStruct, DynamicTopic, DynamicWriter, StructType,
+//! };
+//! use cyclonedds::{Domain, Participant};
+//!
+//! let struct_type = StructType::from_idl(
+//!     "module sensors { struct Reading { @key long id; double value; }; };",
+//!     "sensors::Reading",
+//! )?;
+//!
+//! let domain = Domain::default();
+//! let participant = Participant::new(&domain)?;
+//! let topic = DynamicTopic::new(&participant, "Readings", struct_type)?;
+//! let reader = DynamicReader::new(&topic)?;
+//! let writer = DynamicWriter::new(&topic)?;
+//!
+//! writer.write(&DynamicData::from(
+//!     DynamicStruct::new()
+//!         .with_field("id", 1i32)
+//!         .with_field("value", 21.5f64),
+//! ))?;
+//!
+//! for sample in reader.take()? {
+//!     if let Some(value) = sample.sample().and_then(|sample| sample.field("value")) {
+//!         println!("reading: {value:?}");
+//!     }
+//! }
+//! # Ok::<_, Box<dyn std::error::Error>>(())
+//! ```
+
+pub(crate) mod cdr;
+mod data;
+mod idl;
+mod reader;
+mod topic;
+mod types;
+mod writer;
+
+pub use data::{DynamicData, DynamicStruct};
+pub use idl::IdlError;
+pub use reader::{DynamicReader, DynamicReaderBuilder, DynamicSample};
+pub use topic::{DynamicTopic, DynamicTopicBuilder};
+pub use types::{DynamicType, EnumType, Member, StructType};
+pub use writer::{DynamicWriter, DynamicWriterBuilder};
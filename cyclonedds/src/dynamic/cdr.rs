@@ -0,0 +1,936 @@
+//! A (de)serializer for [`DynamicData`] driven by a [`StructType`], along
+//! with the CDR [`Encoder`] and [`Decoder`] it shares with the serde codec for
+//! [`OPTIONAL_MEMBERS`](crate::Topicable::OPTIONAL_MEMBERS) types.
+//!
+//! The layout matches what `cdr_encoding` produces for the equivalent Rust
+//! types so that dynamic and [`Topicable`](crate::Topicable) endpoints are
+//! interoperable: primitives are aligned to their size relative to the start
+//! of the payload, strings are a `u32` length (including the NUL terminator)
+//! followed by the bytes, sequences are a `u32` element count followed by the
+//! elements, arrays are just the elements, enumerations are a `u32`, and
+//! structures are their members in order.
+//!
+//! That is XCDR1, which is what is written. XCDR2 payloads, as written for IDL
+//! types with `@optional` members, are accepted too. These differ in that
+//! primitives are aligned to at most 4 bytes, and that sequences and arrays of
+//! non-primitive elements are preceded by a `u32` DHEADER holding the length
+//! of the rest of their serialization.
+
+use crate::cdr_bounds::Padding;
+use crate::dynamic::{DynamicData, DynamicStruct, DynamicType, StructType};
+use crate::internal::ffi::serdata_ops::DDSI_RTPS_HEADER_SIZE;
+use crate::{Error, Result};
+
+/// The byte order of a CDR payload.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Endianness {
+    Big,
+    Little,
+}
+
+impl Endianness {
+    /// The byte order of the current platform.
+    pub(crate) const NATIVE: Self = if cfg!(target_endian = "big") {
+        Endianness::Big
+    } else {
+        Endianness::Little
+    };
+
+    /// The encapsulation header of a payload in this byte order and `version`.
+    pub(crate) const fn cdr_header(self, version: Version) -> [u8; DDSI_RTPS_HEADER_SIZE] {
+        let identifier = match (version, self) {
+            (Version::Xcdr1, Endianness::Big) => 0x0,
+            (Version::Xcdr1, Endianness::Little) => 0x1,
+            (Version::Xcdr2, Endianness::Big) => 0x6,
+            (Version::Xcdr2, Endianness::Little) => 0x7,
+        };
+        [0x0, identifier, 0x0, 0x0]
+    }
+
+    pub(crate) const fn from_cdr_header(header: &[u8]) -> Option<Self> {
+        match header {
+            [0x0, 0x0 | 0x6, ..] => Some(Endianness::Big),
+            [0x0, 0x1 | 0x7, ..] => Some(Endianness::Little),
+            _ => None,
+        }
+    }
+}
+
+/// The version of the CDR encoding of a payload.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Version {
+    /// Plain CDR, as written by `cdr_encoding`.
+    Xcdr1,
+    /// Plain CDR2, as written for final IDL types with `@optional` members.
+    Xcdr2,
+}
+
+impl Version {
+    pub(crate) const fn from_cdr_header(header: &[u8]) -> Option<Self> {
+        match header {
+            [0x0, 0x0 | 0x1, ..] => Some(Version::Xcdr1),
+            [0x0, 0x6 | 0x7, ..] => Some(Version::Xcdr2),
+            _ => None,
+        }
+    }
+
+    /// The largest alignment of a primitive.
+    const fn max_alignment(self) -> usize {
+        match self {
+            Version::Xcdr1 => 8,
+            Version::Xcdr2 => 4,
+        }
+    }
+}
+
+/// An error encoding or decoding CDR.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum CodecError {
+    /// The input ended before the value did.
+    UnexpectedEnd,
+    /// The output buffer is too small for the value.
+    BufferFull,
+    /// A length does not fit in a `u32` or exceeds its bound.
+    Length(usize),
+    /// A boolean that is neither 0 nor 1.
+    Bool(u8),
+    /// A string that is not NUL-terminated UTF-8.
+    String,
+}
+
+impl std::fmt::Display for CodecError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CodecError::UnexpectedEnd => write!(f, "unexpected end of input"),
+            CodecError::BufferFull => write!(f, "the serialized value does not fit the buffer"),
+            CodecError::Length(length) => write!(f, "length {length} out of bounds"),
+            CodecError::Bool(value) => write!(f, "invalid boolean value {value}"),
+            CodecError::String => write!(f, "string is not NUL-terminated UTF-8"),
+        }
+    }
+}
+
+impl From<CodecError> for Error {
+    fn from(_error: CodecError) -> Self {
+        Error::BadParameter
+    }
+}
+
+/// The output of an [`Encoder`].
+pub(crate) trait Buffer {
+    /// The number of bytes written so far.
+    fn position(&self) -> usize;
+
+    /// Appends `bytes`.
+    fn write(&mut self, bytes: &[u8]) -> std::result::Result<(), CodecError>;
+
+    /// Overwrites the bytes already written at `position`.
+    fn overwrite(&mut self, position: usize, bytes: &[u8]) -> std::result::Result<(), CodecError>;
+}
+
+impl Buffer for Vec<u8> {
+    fn position(&self) -> usize {
+        self.len()
+    }
+
+    fn write(&mut self, bytes: &[u8]) -> std::result::Result<(), CodecError> {
+        self.extend_from_slice(bytes);
+        Ok(())
+    }
+
+    fn overwrite(&mut self, position: usize, bytes: &[u8]) -> std::result::Result<(), CodecError> {
+        self.get_mut(position..position + bytes.len())
+            .ok_or(CodecError::BufferFull)?
+            .copy_from_slice(bytes);
+        Ok(())
+    }
+}
+
+impl<B> Buffer for &mut B
+where
+    B: Buffer + ?Sized,
+{
+    fn position(&self) -> usize {
+        (**self).position()
+    }
+
+    fn write(&mut self, bytes: &[u8]) -> std::result::Result<(), CodecError> {
+        (**self).write(bytes)
+    }
+
+    fn overwrite(&mut self, position: usize, bytes: &[u8]) -> std::result::Result<(), CodecError> {
+        (**self).overwrite(position, bytes)
+    }
+}
+
+/// A [`Buffer`] that fills a preallocated slice.
+pub(crate) struct SliceBuffer<'a> {
+    bytes: &'a mut [u8],
+    position: usize,
+}
+
+impl<'a> SliceBuffer<'a> {
+    pub(crate) const fn new(bytes: &'a mut [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+}
+
+impl Buffer for SliceBuffer<'_> {
+    fn position(&self) -> usize {
+        self.position
+    }
+
+    fn write(&mut self, bytes: &[u8]) -> std::result::Result<(), CodecError> {
+        self.overwrite(self.position, bytes)?;
+        self.position += bytes.len();
+        Ok(())
+    }
+
+    fn overwrite(&mut self, position: usize, bytes: &[u8]) -> std::result::Result<(), CodecError> {
+        self.bytes
+            .get_mut(position..position + bytes.len())
+            .ok_or(CodecError::BufferFull)?
+            .copy_from_slice(bytes);
+        Ok(())
+    }
+}
+
+/// A [`Buffer`] that only counts the bytes written to it, used to check that a
+/// value can be encoded without keeping its encoding.
+#[derive(Default)]
+pub(crate) struct SizeBuffer {
+    position: usize,
+}
+
+impl Buffer for SizeBuffer {
+    fn position(&self) -> usize {
+        self.position
+    }
+
+    fn write(&mut self, bytes: &[u8]) -> std::result::Result<(), CodecError> {
+        self.position += bytes.len();
+        Ok(())
+    }
+
+    fn overwrite(&mut self, position: usize, bytes: &[u8]) -> std::result::Result<(), CodecError> {
+        if position + bytes.len() <= self.position {
+            Ok(())
+        } else {
+            Err(CodecError::BufferFull)
+        }
+    }
+}
+
+/// Serializes `data` as `struct_type` behind a CDR header.
+pub(crate) fn serialize(
+    struct_type: &StructType,
+    data: &DynamicData,
+    endianness: Endianness,
+) -> Result<Vec<u8>> {
+    let header = endianness.cdr_header(Version::Xcdr1).to_vec();
+    let mut encoder = Encoder::new(header, endianness, Version::Xcdr1);
+    encoder.encode_struct(struct_type, data)?;
+    Ok(encoder.into_buffer())
+}
+
+/// Serializes `data` as `struct_type` without a CDR header.
+pub(crate) fn serialize_payload(
+    struct_type: &StructType,
+    data: &DynamicData,
+    endianness: Endianness,
+) -> Result<Vec<u8>> {
+    let mut encoder = Encoder::new(Vec::new(), endianness, Version::Xcdr1);
+    encoder.encode_struct(struct_type, data)?;
+    Ok(encoder.into_buffer())
+}
+
+/// Deserializes a `struct_type` from `buffer`, using the CDR header at its
+/// start to determine the byte order and version.
+pub(crate) fn deserialize(struct_type: &StructType, buffer: &[u8]) -> Result<DynamicData> {
+    let (header, payload) = buffer
+        .split_at_checked(DDSI_RTPS_HEADER_SIZE)
+        .ok_or(Error::BadParameter)?;
+    let endianness = Endianness::from_cdr_header(header).ok_or(Error::BadParameter)?;
+    let version = Version::from_cdr_header(header).ok_or(Error::BadParameter)?;
+    Decoder::new(payload, endianness, version).decode_struct(struct_type)
+}
+
+/// Deserializes a `struct_type` from an XCDR1 `payload` without a CDR header.
+pub(crate) fn deserialize_payload(
+    struct_type: &StructType,
+    payload: &[u8],
+    endianness: Endianness,
+) -> Result<DynamicData> {
+    Decoder::new(payload, endianness, Version::Xcdr1).decode_struct(struct_type)
+}
+
+/// Encodes CDR into a [`Buffer`].
+pub(crate) struct Encoder<B> {
+    buffer: B,
+    /// The position in `buffer` that alignment is relative to.
+    base: usize,
+    endianness: Endianness,
+    version: Version,
+}
+
+macro_rules! encode_primitive {
+    ($($name:ident: $t:ty),* $(,)?) => {
+        $(
+            pub(crate) fn $name(&mut self, value: $t) -> std::result::Result<(), CodecError> {
+                let bytes = match self.endianness {
+                    Endianness::Big => value.to_be_bytes(),
+                    Endianness::Little => value.to_le_bytes(),
+                };
+                self.align(bytes.len())?;
+                self.buffer.write(&bytes)
+            }
+        )*
+    };
+}
+
+impl<B> Encoder<B>
+where
+    B: Buffer,
+{
+    /// Creates an encoder that appends to `buffer`, aligning relative to its
+    /// current end.
+    pub(crate) fn new(buffer: B, endianness: Endianness, version: Version) -> Self {
+        Self {
+            base: buffer.position(),
+            buffer,
+            endianness,
+            version,
+        }
+    }
+
+    pub(crate) fn into_buffer(self) -> B {
+        self.buffer
+    }
+
+    /// The number of bytes encoded so far.
+    pub(crate) fn position(&self) -> usize {
+        self.buffer.position() - self.base
+    }
+
+    pub(crate) const fn version(&self) -> Version {
+        self.version
+    }
+
+    encode_primitive! {
+        encode_i8: i8,
+        encode_u8: u8,
+        encode_i16: i16,
+        encode_u16: u16,
+        encode_i32: i32,
+        encode_u32: u32,
+        encode_i64: i64,
+        encode_u64: u64,
+        encode_f32: f32,
+        encode_f64: f64,
+    }
+
+    fn align(&mut self, alignment: usize) -> std::result::Result<(), CodecError> {
+        let position = self.position();
+        let padding = position.with_padding(alignment.min(self.version.max_alignment())) - position;
+        for _ in 0..padding {
+            self.buffer.write(&[0])?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn encode_length(&mut self, length: usize) -> std::result::Result<(), CodecError> {
+        let length = u32::try_from(length).map_err(|_err| CodecError::Length(length))?;
+        self.encode_u32(length)
+    }
+
+    /// Encodes the bytes of a string or sequence of octets that follow its
+    /// length.
+    pub(crate) fn encode_bytes(&mut self, bytes: &[u8]) -> std::result::Result<(), CodecError> {
+        self.buffer.write(bytes)
+    }
+
+    pub(crate) fn encode_str(&mut self, value: &str) -> std::result::Result<(), CodecError> {
+        self.encode_length(value.len() + 1)?;
+        self.encode_bytes(value.as_bytes())?;
+        self.encode_bytes(&[0])
+    }
+
+    /// Starts a sequence or array, writing a placeholder for its DHEADER if it
+    /// has one, and returns what to pass to [`Encoder::end_dheader`] once it
+    /// has been encoded.
+    pub(crate) fn begin_dheader(
+        &mut self,
+        primitive_elements: bool,
+    ) -> std::result::Result<Option<usize>, CodecError> {
+        if primitive_elements || self.version == Version::Xcdr1 {
+            return Ok(None);
+        }
+        self.align(4)?;
+        let position = self.buffer.position();
+        self.buffer.write(&[0; 4])?;
+        Ok(Some(position))
+    }
+
+    /// Fills in the DHEADER started by [`Encoder::begin_dheader`].
+    pub(crate) fn end_dheader(
+        &mut self,
+        dheader: Option<usize>,
+    ) -> std::result::Result<(), CodecError> {
+        let Some(position) = dheader else {
+            return Ok(());
+        };
+        let length = self.buffer.position() - position - 4;
+        let length = u32::try_from(length).map_err(|_err| CodecError::Length(length))?;
+        let bytes = match self.endianness {
+            Endianness::Big => length.to_be_bytes(),
+            Endianness::Little => length.to_le_bytes(),
+        };
+        self.buffer.overwrite(position, &bytes)
+    }
+
+    fn encode(&mut self, type_: &DynamicType, data: &DynamicData) -> Result<()> {
+        match (type_, data) {
+            (DynamicType::Bool, DynamicData::Bool(value)) => self.encode_u8(u8::from(*value))?,
+            (DynamicType::Int8, DynamicData::Int8(value)) => self.encode_i8(*value)?,
+            (DynamicType::UInt8, DynamicData::UInt8(value)) => self.encode_u8(*value)?,
+            (DynamicType::Int16, DynamicData::Int16(value)) => self.encode_i16(*value)?,
+            (DynamicType::UInt16, DynamicData::UInt16(value)) => self.encode_u16(*value)?,
+            (DynamicType::Int32, DynamicData::Int32(value)) => self.encode_i32(*value)?,
+            (DynamicType::UInt32, DynamicData::UInt32(value)) => self.encode_u32(*value)?,
+            (DynamicType::Int64, DynamicData::Int64(value)) => self.encode_i64(*value)?,
+            (DynamicType::UInt64, DynamicData::UInt64(value)) => self.encode_u64(*value)?,
+            (DynamicType::Float32, DynamicData::Float32(value)) => self.encode_f32(*value)?,
+            (DynamicType::Float64, DynamicData::Float64(value)) => self.encode_f64(*value)?,
+            (DynamicType::String { bound }, DynamicData::String(value)) => {
+                if bound.is_some_and(|bound| value.len() > bound) || value.contains('\0') {
+                    return Err(Error::BadParameter);
+                }
+                self.encode_str(value)?;
+            }
+            (
+                DynamicType::Sequence { element, bound },
+                DynamicData::Sequence(elements) | DynamicData::Array(elements),
+            ) => {
+                if bound.is_some_and(|bound| elements.len() > bound) {
+                    return Err(Error::BadParameter);
+                }
+                let dheader = self.begin_dheader(element.is_primitive())?;
+                self.encode_length(elements.len())?;
+                for value in elements {
+                    self.encode(element, value)?;
+                }
+                self.end_dheader(dheader)?;
+            }
+            (
+                DynamicType::Array { element, length },
+                DynamicData::Array(elements) | DynamicData::Sequence(elements),
+            ) => {
+                if elements.len() != *length {
+                    return Err(Error::BadParameter);
+                }
+                let dheader = self.begin_dheader(element.is_primitive())?;
+                for value in elements {
+                    self.encode(element, value)?;
+                }
+                self.end_dheader(dheader)?;
+            }
+            (DynamicType::Enum(enum_type), DynamicData::Enum(value)) => {
+                if !usize::try_from(*value).is_ok_and(|value| value < enum_type.enumerators.len()) {
+                    return Err(Error::BadParameter);
+                }
+                self.encode_u32(*value)?;
+            }
+            (DynamicType::Struct(struct_type), DynamicData::Struct(_)) => {
+                self.encode_struct(struct_type, data)?;
+            }
+            _ => return Err(Error::BadParameter),
+        }
+        Ok(())
+    }
+
+    fn encode_struct(&mut self, struct_type: &StructType, data: &DynamicData) -> Result<()> {
+        let fields = data.as_struct().ok_or(Error::BadParameter)?;
+
+        // Every member must be present and no unknown fields may be given.
+        if fields.len() != struct_type.members.len() {
+            return Err(Error::BadParameter);
+        }
+
+        for member in &struct_type.members {
+            let value = fields.get(&member.name).ok_or(Error::BadParameter)?;
+            self.encode(&member.type_, value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Decodes CDR from a payload.
+pub(crate) struct Decoder<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    endianness: Endianness,
+    version: Version,
+}
+
+macro_rules! decode_primitive {
+    ($($name:ident: $t:ty),* $(,)?) => {
+        $(
+            pub(crate) fn $name(&mut self) -> std::result::Result<$t, CodecError> {
+                const SIZE: usize = std::mem::size_of::<$t>();
+                self.position = self
+                    .position
+                    .with_padding(SIZE.min(self.version.max_alignment()));
+                let bytes: [u8; SIZE] = self
+                    .take(SIZE)?
+                    .try_into()
+                    .map_err(|_err| CodecError::UnexpectedEnd)?;
+                Ok(match self.endianness {
+                    Endianness::Big => <$t>::from_be_bytes(bytes),
+                    Endianness::Little => <$t>::from_le_bytes(bytes),
+                })
+            }
+        )*
+    };
+}
+
+impl<'a> Decoder<'a> {
+    pub(crate) const fn new(bytes: &'a [u8], endianness: Endianness, version: Version) -> Self {
+        Self {
+            bytes,
+            position: 0,
+            endianness,
+            version,
+        }
+    }
+
+    /// The number of bytes decoded so far.
+    pub(crate) const fn position(&self) -> usize {
+        self.position
+    }
+
+    pub(crate) const fn version(&self) -> Version {
+        self.version
+    }
+
+    decode_primitive! {
+        decode_i8: i8,
+        decode_u8: u8,
+        decode_i16: i16,
+        decode_u16: u16,
+        decode_i32: i32,
+        decode_u32: u32,
+        decode_i64: i64,
+        decode_u64: u64,
+        decode_f32: f32,
+        decode_f64: f64,
+    }
+
+    /// Takes the next `length` bytes, as for the contents of a string or
+    /// sequence of octets.
+    pub(crate) fn take(&mut self, length: usize) -> std::result::Result<&'a [u8], CodecError> {
+        let end = self
+            .position
+            .checked_add(length)
+            .ok_or(CodecError::Length(length))?;
+        let bytes = self
+            .bytes
+            .get(self.position..end)
+            .ok_or(CodecError::UnexpectedEnd)?;
+        self.position = end;
+        Ok(bytes)
+    }
+
+    pub(crate) fn decode_length(
+        &mut self,
+        bound: Option<usize>,
+    ) -> std::result::Result<usize, CodecError> {
+        let length = self.decode_u32()?;
+        let length = usize::try_from(length).map_err(|_err| CodecError::UnexpectedEnd)?;
+        if bound.is_some_and(|bound| length > bound) {
+            return Err(CodecError::Length(length));
+        }
+        Ok(length)
+    }
+
+    pub(crate) fn decode_bool(&mut self) -> std::result::Result<bool, CodecError> {
+        match self.decode_u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            value => Err(CodecError::Bool(value)),
+        }
+    }
+
+    /// Decodes a string of at most `bound` characters.
+    pub(crate) fn decode_str(
+        &mut self,
+        bound: Option<usize>,
+    ) -> std::result::Result<&'a str, CodecError> {
+        // The bound excludes the NUL terminator.
+        let length = self.decode_length(bound.map(|bound| bound + 1))?;
+        let Some((&0, bytes)) = self.take(length)?.split_last() else {
+            return Err(CodecError::String);
+        };
+        std::str::from_utf8(bytes).map_err(|_err| CodecError::String)
+    }
+
+    /// Decodes the DHEADER of a sequence or array, if it has one.
+    pub(crate) fn decode_dheader(
+        &mut self,
+        primitive_elements: bool,
+    ) -> std::result::Result<(), CodecError> {
+        if primitive_elements || self.version == Version::Xcdr1 {
+            return Ok(());
+        }
+        // NOTE: the length is only checked as the elements of final types are
+        // decoded as they are anyway.
+        let length = self.decode_length(None)?;
+        if length > self.bytes.len().saturating_sub(self.position) {
+            return Err(CodecError::UnexpectedEnd);
+        }
+        Ok(())
+    }
+
+    fn decode(&mut self, type_: &DynamicType) -> Result<DynamicData> {
+        Ok(match type_ {
+            DynamicType::Bool => DynamicData::Bool(self.decode_bool()?),
+            DynamicType::Int8 => DynamicData::Int8(self.decode_i8()?),
+            DynamicType::UInt8 => DynamicData::UInt8(self.decode_u8()?),
+            DynamicType::Int16 => DynamicData::Int16(self.decode_i16()?),
+            DynamicType::UInt16 => DynamicData::UInt16(self.decode_u16()?),
+            DynamicType::Int32 => DynamicData::Int32(self.decode_i32()?),
+            DynamicType::UInt32 => DynamicData::UInt32(self.decode_u32()?),
+            DynamicType::Int64 => DynamicData::Int64(self.decode_i64()?),
+            DynamicType::UInt64 => DynamicData::UInt64(self.decode_u64()?),
+            DynamicType::Float32 => DynamicData::Float32(self.decode_f32()?),
+            DynamicType::Float64 => DynamicData::Float64(self.decode_f64()?),
+            DynamicType::String { bound } => {
+                DynamicData::String(self.decode_str(*bound)?.to_owned())
+            }
+            DynamicType::Sequence { element, bound } => {
+                self.decode_dheader(element.is_primitive())?;
+                let length = self.decode_length(*bound)?;
+                // NOTE: the capacity is limited by the remaining bytes so that a
+                // corrupt length cannot trigger a huge allocation.
+                let remaining = self.bytes.len().saturating_sub(self.position);
+                let mut elements = Vec::with_capacity(length.min(remaining));
+                for _ in 0..length {
+                    elements.push(self.decode(element)?);
+                }
+                DynamicData::Sequence(elements)
+            }
+            DynamicType::Array { element, length } => {
+                self.decode_dheader(element.is_primitive())?;
+                DynamicData::Array(
+                    (0..*length)
+                        .map(|_| self.decode(element))
+                        .collect::<Result<_>>()?,
+                )
+            }
+            DynamicType::Enum(enum_type) => {
+                let value = self.decode_u32()?;
+                if !usize::try_from(value).is_ok_and(|value| value < enum_type.enumerators.len()) {
+                    return Err(Error::BadParameter);
+                }
+                DynamicData::Enum(value)
+            }
+            DynamicType::Struct(struct_type) => self.decode_struct(struct_type)?,
+        })
+    }
+
+    fn decode_struct(&mut self, struct_type: &StructType) -> Result<DynamicData> {
+        struct_type
+            .members
+            .iter()
+            .map(|member| Ok((member.name.clone(), self.decode(&member.type_)?)))
+            .collect::<Result<DynamicStruct>>()
+            .map(DynamicData::Struct)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::EnumType;
+    use crate::internal::traits::CdrHeader;
+
+    fn data_type() -> StructType {
+        StructType::new("Data")
+            .with_key_member("x", DynamicType::UInt32)
+            .with_member("y", DynamicType::Int32)
+            .with_member("message", DynamicType::String { bound: None })
+    }
+
+    #[test]
+    fn test_serialize_matches_cdr_encoding() {
+        let data = crate::tests::topic::Data {
+            x: 1,
+            y: -2,
+            message: "hello".to_owned(),
+        };
+        let dynamic = DynamicData::from(
+            DynamicStruct::new()
+                .with_field("x", 1u32)
+                .with_field("y", -2i32)
+                .with_field("message", "hello"),
+        );
+
+        let mut expected = byteorder::NativeEndian::cdr_header().to_vec();
+        cdr_encoding::to_writer::<_, byteorder::NativeEndian, _>(&mut expected, &data).unwrap();
+        let actual = serialize(&data_type(), &dynamic, Endianness::NATIVE).unwrap();
+        assert_eq!(actual, expected);
+
+        let expected = cdr_encoding::to_vec::<_, byteorder::BigEndian>(&data).unwrap();
+        let actual = serialize_payload(&data_type(), &dynamic, Endianness::Big).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_roundtrip_nested_types() {
+        let point = StructType::new("Point")
+            .with_member("x", DynamicType::Float64)
+            .with_member("y", DynamicType::Float64);
+        let struct_type = StructType::new("Shape")
+            .with_member("visible", DynamicType::Bool)
+            .with_member(
+                "color",
+                EnumType::new("Color", ["Red", "Green", "Blue"]).into(),
+            )
+            .with_member(
+                "points",
+                DynamicType::Sequence {
+                    element: Box::new(point.clone().into()),
+                    bound: Some(4),
+                },
+            )
+            .with_member(
+                "tag",
+                DynamicType::Array {
+                    element: Box::new(DynamicType::UInt8),
+                    length: 3,
+                },
+            );
+
+        let point = |x: f64, y: f64| {
+            DynamicData::from(DynamicStruct::new().with_field("x", x).with_field("y", y))
+        };
+        let data = DynamicData::from(
+            DynamicStruct::new()
+                .with_field("visible", true)
+                .with_field("color", DynamicData::Enum(2))
+                .with_field("points", vec![point(0.0, 1.0), point(2.5, -3.0)])
+                .with_field(
+                    "tag",
+                    DynamicData::Array(vec![1u8.into(), 2u8.into(), 3u8.into()]),
+                ),
+        );
+
+        for endianness in [Endianness::Big, Endianness::Little] {
+            let serialized = serialize(&struct_type, &data, endianness).unwrap();
+            assert_eq!(deserialize(&struct_type, &serialized).unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn test_serialize_rejects_mismatched_data() {
+        let struct_type = data_type();
+        let valid = DynamicStruct::new()
+            .with_field("x", 1u32)
+            .with_field("y", -2i32)
+            .with_field("message", "hello");
+
+        // Wrong primitive type.
+        let data = valid.clone().with_field("x", 1i32).into();
+        assert_eq!(
+            serialize(&struct_type, &data, Endianness::NATIVE),
+            Err(Error::BadParameter)
+        );
+        // Unknown field.
+        let data = valid.clone().with_field("z", 1i32).into();
+        assert_eq!(
+            serialize(&struct_type, &data, Endianness::NATIVE),
+            Err(Error::BadParameter)
+        );
+        // Missing field.
+        let data = DynamicStruct::new().with_field("x", 1u32).into();
+        assert_eq!(
+            serialize(&struct_type, &data, Endianness::NATIVE),
+            Err(Error::BadParameter)
+        );
+        // Exceeded bound.
+        let bounded =
+            StructType::new("Bounded").with_member("s", DynamicType::String { bound: Some(2) });
+        let data = DynamicStruct::new().with_field("s", "abc").into();
+        assert_eq!(
+            serialize(&bounded, &data, Endianness::NATIVE),
+            Err(Error::BadParameter)
+        );
+    }
+
+    #[test]
+    fn test_deserialize_rejects_truncated_data() {
+        let data = DynamicStruct::new()
+            .with_field("x", 1u32)
+            .with_field("y", -2i32)
+            .with_field("message", "hello")
+            .into();
+        let serialized = serialize(&data_type(), &data, Endianness::NATIVE).unwrap();
+
+        for length in 0..serialized.len() {
+            assert_eq!(
+                deserialize(&data_type(), &serialized[..length]),
+                Err(Error::BadParameter)
+            );
+        }
+    }
+
+    #[test]
+    fn test_cdr_headers() {
+        assert_eq!(
+            Endianness::Big.cdr_header(Version::Xcdr1),
+            byteorder::BigEndian::cdr_header()
+        );
+        assert_eq!(
+            Endianness::Little.cdr_header(Version::Xcdr1),
+            byteorder::LittleEndian::cdr_header()
+        );
+
+        for endianness in [Endianness::Big, Endianness::Little] {
+            for version in [Version::Xcdr1, Version::Xcdr2] {
+                let header = endianness.cdr_header(version);
+                assert_eq!(Endianness::from_cdr_header(&header), Some(endianness));
+                assert_eq!(Version::from_cdr_header(&header), Some(version));
+            }
+        }
+        assert_eq!(Endianness::Big.cdr_header(Version::Xcdr2), [0, 6, 0, 0]);
+        assert_eq!(Endianness::Little.cdr_header(Version::Xcdr2), [0, 7, 0, 0]);
+        assert_eq!(Endianness::from_cdr_header(&[0, 2, 0, 0]), None);
+        assert_eq!(Version::from_cdr_header(&[0, 2, 0, 0]), None);
+    }
+
+    #[test]
+    fn test_deserialize_xcdr2() {
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        struct Reading {
+            id: u8,
+            stamp: i64,
+            tags: Vec<String>,
+            values: Vec<u16>,
+        }
+
+        // The XCDR2 encoding of the IDL type:
+        //
+        //   @final struct Reading {
+        //     octet id;
+        //     int64 stamp;
+        //     sequence<string> tags;
+        //     sequence<uint16> values;
+        //   };
+        #[rustfmt::skip]
+        let serialized = [
+            // The big-endian XCDR2 header.
+            0, 6, 0, 0,
+            // id
+            7, 0, 0, 0,
+            // stamp, aligned to 4 bytes.
+            0, 0, 0, 0, 0, 0, 1, 0,
+            // tags: the DHEADER, the length, and the strings.
+            0, 0, 0, 19,
+            0, 0, 0, 2,
+            0, 0, 0, 2, b'a', 0, 0, 0,
+            0, 0, 0, 3, b'b', b'c', 0, 0,
+            // values: no DHEADER for primitive elements.
+            0, 0, 0, 2,
+            0, 1, 0, 2,
+        ];
+        let struct_type = StructType::new("Reading")
+            .with_member("id", DynamicType::UInt8)
+            .with_member("stamp", DynamicType::Int64)
+            .with_member(
+                "tags",
+                DynamicType::Sequence {
+                    element: Box::new(DynamicType::String { bound: None }),
+                    bound: None,
+                },
+            )
+            .with_member(
+                "values",
+                DynamicType::Sequence {
+                    element: Box::new(DynamicType::UInt16),
+                    bound: None,
+                },
+            );
+        let data = DynamicData::from(
+            DynamicStruct::new()
+                .with_field("id", 7u8)
+                .with_field("stamp", 256i64)
+                .with_field(
+                    "tags",
+                    vec![DynamicData::from("a"), DynamicData::from("bc")],
+                )
+                .with_field(
+                    "values",
+                    vec![DynamicData::from(1u16), DynamicData::from(2u16)],
+                ),
+        );
+        assert_eq!(deserialize(&struct_type, &serialized).unwrap(), data);
+
+        let mut encoder = Encoder::new(
+            Endianness::Big.cdr_header(Version::Xcdr2).to_vec(),
+            Endianness::Big,
+            Version::Xcdr2,
+        );
+        encoder.encode_struct(&struct_type, &data).unwrap();
+        assert_eq!(encoder.into_buffer(), serialized);
+
+        // The same sample as the equivalent `Topicable` type.
+        let (reading, _) = crate::internal::cdr::from_bytes::<Reading>(
+            serialized.get(DDSI_RTPS_HEADER_SIZE..).unwrap(),
+            Endianness::Big,
+            Version::Xcdr2,
+        )
+        .unwrap();
+        assert_eq!(
+            reading,
+            Reading {
+                id: 7,
+                stamp: 256,
+                tags: vec!["a".to_owned(), "bc".to_owned()],
+                values: vec![1, 2],
+            }
+        );
+    }
+
+    #[test]
+    fn test_encode_into_slice() {
+        let data = DynamicStruct::new()
+            .with_field("x", 1u32)
+            .with_field("y", -2i32)
+            .with_field("message", "hello")
+            .into();
+        let expected = serialize_payload(&data_type(), &data, Endianness::Little).unwrap();
+
+        let mut bytes = vec![0; expected.len()];
+        let mut encoder = Encoder::new(
+            SliceBuffer::new(&mut bytes),
+            Endianness::Little,
+            Version::Xcdr1,
+        );
+        encoder.encode_struct(&data_type(), &data).unwrap();
+        assert_eq!(bytes, expected);
+
+        let mut bytes = vec![0; expected.len() - 1];
+        let mut encoder = Encoder::new(
+            SliceBuffer::new(&mut bytes),
+            Endianness::Little,
+            Version::Xcdr1,
+        );
+        assert_eq!(
+            encoder.encode_struct(&data_type(), &data),
+            Err(Error::BadParameter)
+        );
+
+        let mut encoder = Encoder::new(SizeBuffer::default(), Endianness::Little, Version::Xcdr1);
+        encoder.encode_struct(&data_type(), &data).unwrap();
+        assert_eq!(encoder.position(), expected.len());
+    }
+}
@@ -0,0 +1,332 @@
+/// A sample whose shape is described at runtime by a
+/// [`DynamicType`](crate::dynamic::DynamicType).
+///
+/// Each variant corresponds to the [`DynamicType`](crate::dynamic::DynamicType)
+/// variant of the same name. Values can be inspected with the `as_*`
+/// accessors or by matching directly, and constructed either from scratch or
+/// by starting from [`DynamicType::default_value`](crate::dynamic::DynamicType::default_value).
+///
+/// # Examples
+///
+/// ```
+/// use cyclonedds::dynamic::{DynamicData, DynamicStruct};
+///
+/// let mut sample = DynamicData::from(
+///     DynamicStruct::new()
+///         .with_field("id", 7u32)
+///         .with_field("name", "seven")
+///         .with_field("values", vec![DynamicData::from(1.0f64), DynamicData::from(2.0f64)]),
+/// );
+///
+/// assert_eq!(sample.field("id").and_then(DynamicData::as_u64), Some(7));
+/// assert_eq!(sample.field("name").and_then(DynamicData::as_str), Some("seven"));
+///
+/// if let Some(id) = sample.field_mut("id") {
+///     *id = DynamicData::UInt32(8);
+/// }
+/// assert_eq!(sample.field("id"), Some(&DynamicData::UInt32(8)));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum DynamicData {
+    /// A `boolean`.
+    Bool(bool),
+    /// An `int8`.
+    Int8(i8),
+    /// A `uint8` / `octet`.
+    UInt8(u8),
+    /// An `int16`.
+    Int16(i16),
+    /// A `uint16`.
+    UInt16(u16),
+    /// An `int32`.
+    Int32(i32),
+    /// A `uint32`.
+    UInt32(u32),
+    /// An `int64`.
+    Int64(i64),
+    /// A `uint64`.
+    UInt64(u64),
+    /// A `float`.
+    Float32(f32),
+    /// A `double`.
+    Float64(f64),
+    /// A `string`.
+    String(String),
+    /// The elements of a `sequence`.
+    Sequence(Vec<DynamicData>),
+    /// The elements of a fixed-length array.
+    Array(Vec<DynamicData>),
+    /// The position of the enumerator in its
+    /// [`EnumType`](crate::dynamic::EnumType).
+    Enum(u32),
+    /// The fields of a `struct`.
+    Struct(DynamicStruct),
+}
+
+/// The fields of a [`DynamicData::Struct`], in member order.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DynamicStruct {
+    fields: Vec<(String, DynamicData)>,
+}
+
+impl DynamicData {
+    /// Returns the value if this is a [`DynamicData::Bool`].
+    #[must_use]
+    pub const fn as_bool(&self) -> Option<bool> {
+        match self {
+            DynamicData::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of any integer variant that fits in an [`i64`].
+    #[must_use]
+    pub fn as_i64(&self) -> Option<i64> {
+        match *self {
+            DynamicData::Int8(value) => Some(value.into()),
+            DynamicData::UInt8(value) => Some(value.into()),
+            DynamicData::Int16(value) => Some(value.into()),
+            DynamicData::UInt16(value) => Some(value.into()),
+            DynamicData::Int32(value) => Some(value.into()),
+            DynamicData::UInt32(value) => Some(value.into()),
+            DynamicData::Int64(value) => Some(value),
+            DynamicData::UInt64(value) => value.try_into().ok(),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of any integer variant that fits in a [`u64`].
+    #[must_use]
+    pub fn as_u64(&self) -> Option<u64> {
+        match *self {
+            DynamicData::Int8(value) => value.try_into().ok(),
+            DynamicData::UInt8(value) => Some(value.into()),
+            DynamicData::Int16(value) => value.try_into().ok(),
+            DynamicData::UInt16(value) => Some(value.into()),
+            DynamicData::Int32(value) => value.try_into().ok(),
+            DynamicData::UInt32(value) => Some(value.into()),
+            DynamicData::Int64(value) => value.try_into().ok(),
+            DynamicData::UInt64(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a [`DynamicData::Float32`] or
+    /// [`DynamicData::Float64`].
+    #[must_use]
+    pub fn as_f64(&self) -> Option<f64> {
+        match *self {
+            DynamicData::Float32(value) => Some(value.into()),
+            DynamicData::Float64(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if this is a [`DynamicData::String`].
+    #[must_use]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            DynamicData::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the elements of a [`DynamicData::Sequence`] or
+    /// [`DynamicData::Array`].
+    #[must_use]
+    pub fn as_slice(&self) -> Option<&[DynamicData]> {
+        match self {
+            DynamicData::Sequence(elements) | DynamicData::Array(elements) => Some(elements),
+            _ => None,
+        }
+    }
+
+    /// Returns the enumerator position if this is a [`DynamicData::Enum`].
+    #[must_use]
+    pub const fn as_enum(&self) -> Option<u32> {
+        match self {
+            DynamicData::Enum(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the fields if this is a [`DynamicData::Struct`].
+    #[must_use]
+    pub const fn as_struct(&self) -> Option<&DynamicStruct> {
+        match self {
+            DynamicData::Struct(fields) => Some(fields),
+            _ => None,
+        }
+    }
+
+    /// Returns the fields mutably if this is a [`DynamicData::Struct`].
+    #[must_use]
+    pub const fn as_struct_mut(&mut self) -> Option<&mut DynamicStruct> {
+        match self {
+            DynamicData::Struct(fields) => Some(fields),
+            _ => None,
+        }
+    }
+
+    /// Returns the field called `name` if this is a [`DynamicData::Struct`].
+    #[must_use]
+    pub fn field(&self, name: &str) -> Option<&DynamicData> {
+        self.as_struct().and_then(|fields| fields.get(name))
+    }
+
+    /// Returns the field called `name` mutably if this is a
+    /// [`DynamicData::Struct`].
+    #[must_use]
+    pub fn field_mut(&mut self, name: &str) -> Option<&mut DynamicData> {
+        self.as_struct_mut().and_then(|fields| fields.get_mut(name))
+    }
+}
+
+impl DynamicStruct {
+    /// Creates a structure with no fields.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { fields: Vec::new() }
+    }
+
+    /// Sets the field called `name`, replacing any existing value.
+    #[must_use]
+    pub fn with_field<N, V>(mut self, name: N, value: V) -> Self
+    where
+        N: Into<String>,
+        V: Into<DynamicData>,
+    {
+        self.set(name, value);
+        self
+    }
+
+    /// Sets the field called `name`, returning the value it replaced.
+    ///
+    /// New fields are appended after the existing ones.
+    pub fn set<N, V>(&mut self, name: N, value: V) -> Option<DynamicData>
+    where
+        N: Into<String>,
+        V: Into<DynamicData>,
+    {
+        let name = name.into();
+        let value = value.into();
+        if let Some(field) = self.get_mut(&name) {
+            Some(std::mem::replace(field, value))
+        } else {
+            self.fields.push((name, value));
+            None
+        }
+    }
+
+    /// Returns the field called `name`.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&DynamicData> {
+        self.fields
+            .iter()
+            .find_map(|(field, value)| (field.as_str() == name).then_some(value))
+    }
+
+    /// Returns the field called `name` mutably.
+    #[must_use]
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut DynamicData> {
+        self.fields
+            .iter_mut()
+            .find_map(|(field, value)| (field.as_str() == name).then_some(value))
+    }
+
+    /// Returns an iterator over the field names and values in order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &DynamicData)> {
+        self.fields
+            .iter()
+            .map(|(name, value)| (name.as_str(), value))
+    }
+
+    /// Returns the number of fields.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Returns `true` if there are no fields.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+}
+
+impl FromIterator<(String, DynamicData)> for DynamicStruct {
+    fn from_iter<I: IntoIterator<Item = (String, DynamicData)>>(iter: I) -> Self {
+        Self {
+            fields: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl IntoIterator for DynamicStruct {
+    type Item = (String, DynamicData);
+    type IntoIter = std::vec::IntoIter<(String, DynamicData)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.fields.into_iter()
+    }
+}
+
+macro_rules! impl_from_for_dynamic_data {
+    ($($t:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl From<$t> for DynamicData {
+                fn from(value: $t) -> Self {
+                    DynamicData::$variant(value.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_for_dynamic_data! {
+    bool => Bool,
+    i8 => Int8,
+    u8 => UInt8,
+    i16 => Int16,
+    u16 => UInt16,
+    i32 => Int32,
+    u32 => UInt32,
+    i64 => Int64,
+    u64 => UInt64,
+    f32 => Float32,
+    f64 => Float64,
+    String => String,
+    &str => String,
+    Vec<DynamicData> => Sequence,
+    DynamicStruct => Struct,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dynamic_struct_set_replaces_in_place() {
+        let mut fields = DynamicStruct::new()
+            .with_field("a", 1i32)
+            .with_field("b", 2i32);
+
+        assert_eq!(fields.set("a", 3i32), Some(DynamicData::Int32(1)));
+        assert_eq!(fields.set("c", 4i32), None);
+
+        let names: Vec<_> = fields.iter().map(|(name, _)| name).collect();
+        assert_eq!(names, ["a", "b", "c"]);
+        assert_eq!(fields.get("a"), Some(&DynamicData::Int32(3)));
+        assert_eq!(fields.len(), 3);
+    }
+
+    #[test]
+    fn test_dynamic_data_integer_accessors() {
+        assert_eq!(DynamicData::Int8(-1).as_i64(), Some(-1));
+        assert_eq!(DynamicData::Int8(-1).as_u64(), None);
+        assert_eq!(DynamicData::UInt64(u64::MAX).as_i64(), None);
+        assert_eq!(DynamicData::UInt64(u64::MAX).as_u64(), Some(u64::MAX));
+        assert_eq!(DynamicData::Float32(0.5).as_f64(), Some(0.5));
+        assert_eq!(DynamicData::String("x".into()).as_i64(), None);
+    }
+}
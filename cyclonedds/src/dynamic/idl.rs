@@ -0,0 +1,709 @@
+//! A parser for the subset of IDL that can be described by a
+//! [`DynamicType`].
+//!
+//! Modules, structures (including inheritance and forward declarations),
+//! enumerations, typedefs, and integer constants are supported along with the
+//! `@key` member annotation. Preprocessor directives are ignored so `#include`d
+//! types must be provided in the same string.
+
+use crate::dynamic::{DynamicType, EnumType, Member, StructType};
+
+/// An error produced by [`StructType::from_idl`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IdlError {
+    /// The IDL could not be parsed or uses an unsupported construct.
+    Syntax {
+        /// The line (starting from 1) the error was detected on.
+        line: usize,
+        /// A description of the problem.
+        message: String,
+    },
+    /// The IDL was parsed but contains no structure with the requested name.
+    TypeNotFound(String),
+}
+
+impl std::fmt::Display for IdlError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IdlError::Syntax { line, message } => write!(f, "IDL error on line {line}: {message}"),
+            IdlError::TypeNotFound(name) => write!(f, "IDL does not define a struct named {name}"),
+        }
+    }
+}
+
+impl std::error::Error for IdlError {}
+
+impl StructType {
+    /// Parses `idl` and returns the structure called `type_name`.
+    ///
+    /// `type_name` is the fully scoped name of the structure, e.g.
+    /// `geometry::Position`, which is also the DDS type name used when the
+    /// structure is the type of a
+    /// [`DynamicTopic`](crate::dynamic::DynamicTopic).
+    ///
+    /// Only the constructs that map onto the plain (XCDR1) CDR encoding used
+    /// by this crate are accepted. Unions, `@optional` members, `@mutable`
+    /// types, characters, and `long double` are rejected with an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IdlError`] if the IDL could not be parsed or does not
+    /// define a structure called `type_name`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclonedds::dynamic::{DynamicType, StructType};
+    ///
+    /// let idl = r"
+    ///     module geometry {
+    ///         struct Position {
+    ///             @key uint32 id;
+    ///             double x, y;
+    ///             sequence<string<16>, 4> tags;
+    ///         };
+    ///     };
+    /// ";
+    ///
+    /// let position = StructType::from_idl(idl, "geometry::Position")?;
+    /// assert_eq!(position.name, "geometry::Position");
+    /// assert_eq!(position.members.len(), 4);
+    /// assert!(position.member("id").is_some_and(|member| member.key));
+    /// assert_eq!(
+    ///     position.member("y").map(|member| &member.type_),
+    ///     Some(&DynamicType::Float64)
+    /// );
+    /// # Ok::<_, cyclonedds::dynamic::IdlError>(())
+    /// ```
+    pub fn from_idl(idl: &str, type_name: &str) -> Result<StructType, IdlError> {
+        let mut parser = Parser::new(tokenize(idl)?);
+        parser.parse_specification()?;
+
+        let type_name = type_name.strip_prefix("::").unwrap_or(type_name);
+        match parser.definitions.remove(type_name) {
+            Some(Definition::Type(DynamicType::Struct(struct_type))) => Ok(struct_type),
+            _ => Err(IdlError::TypeNotFound(type_name.to_owned())),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Token {
+    Identifier(String),
+    Number(String),
+    Literal,
+    Scope,
+    Punctuation(char),
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Identifier(value) | Token::Number(value) => write!(f, "`{value}`"),
+            Token::Literal => write!(f, "literal"),
+            Token::Scope => write!(f, "`::`"),
+            Token::Punctuation(value) => write!(f, "`{value}`"),
+        }
+    }
+}
+
+fn tokenize(idl: &str) -> Result<Vec<(Token, usize)>, IdlError> {
+    let mut tokens = Vec::new();
+    let mut line = 1;
+    let mut chars = idl.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\n' => line += 1,
+            c if c.is_whitespace() => {}
+            // Preprocessor directives are skipped entirely.
+            '#' => while chars.next_if(|&c| c != '\n').is_some() {},
+            '/' if chars.next_if_eq(&'/').is_some() => {
+                while chars.next_if(|&c| c != '\n').is_some() {}
+            }
+            '/' if chars.next_if_eq(&'*').is_some() => {
+                let mut previous = '\0';
+                loop {
+                    match chars.next() {
+                        Some('/') if previous == '*' => break,
+                        Some(c) => {
+                            if c == '\n' {
+                                line += 1;
+                            }
+                            previous = c;
+                        }
+                        None => {
+                            return Err(IdlError::Syntax {
+                                line,
+                                message: "unterminated comment".to_owned(),
+                            });
+                        }
+                    }
+                }
+            }
+            '"' | '\'' => {
+                let mut escaped = false;
+                loop {
+                    match chars.next() {
+                        Some(next) if next == c && !escaped => break,
+                        Some(next) => escaped = next == '\\' && !escaped,
+                        None => {
+                            return Err(IdlError::Syntax {
+                                line,
+                                message: "unterminated literal".to_owned(),
+                            });
+                        }
+                    }
+                }
+                tokens.push((Token::Literal, line));
+            }
+            ':' if chars.next_if_eq(&':').is_some() => tokens.push((Token::Scope, line)),
+            c if c.is_ascii_alphabetic() || c == '_' => {
+                let mut identifier = String::from(c);
+                while let Some(c) = chars.next_if(|&c| c.is_ascii_alphanumeric() || c == '_') {
+                    identifier.push(c);
+                }
+                // A leading underscore escapes identifiers that clash with keywords.
+                if identifier.starts_with('_') {
+                    identifier.remove(0);
+                }
+                tokens.push((Token::Identifier(identifier), line));
+            }
+            c if c.is_ascii_digit() => {
+                let mut number = String::from(c);
+                while let Some(c) = chars.next_if(|&c| c.is_ascii_alphanumeric() || c == '.') {
+                    number.push(c);
+                }
+                tokens.push((Token::Number(number), line));
+            }
+            '{' | '}' | ';' | '<' | '>' | ',' | '[' | ']' | ':' | '@' | '(' | ')' | '=' | '-'
+            | '+' => tokens.push((Token::Punctuation(c), line)),
+            c => {
+                return Err(IdlError::Syntax {
+                    line,
+                    message: format!("unexpected character `{c}`"),
+                });
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+#[derive(Clone)]
+enum Definition {
+    Type(DynamicType),
+    Constant(usize),
+    ForwardDeclaration,
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    position: usize,
+    scope: Vec<String>,
+    definitions: std::collections::HashMap<String, Definition>,
+}
+
+impl Parser {
+    fn new(tokens: Vec<(Token, usize)>) -> Self {
+        Self {
+            tokens,
+            position: 0,
+            scope: Vec::new(),
+            definitions: std::collections::HashMap::new(),
+        }
+    }
+
+    fn line(&self) -> usize {
+        self.tokens
+            .get(self.position)
+            .or_else(|| self.tokens.last())
+            .map_or(1, |(_, line)| *line)
+    }
+
+    fn error<T>(&self, message: impl Into<String>) -> Result<T, IdlError> {
+        Err(IdlError::Syntax {
+            line: self.line(),
+            message: message.into(),
+        })
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position).map(|(token, _)| token)
+    }
+
+    fn next(&mut self) -> Result<Token, IdlError> {
+        let token = self.peek().cloned();
+        match token {
+            Some(token) => {
+                self.position += 1;
+                Ok(token)
+            }
+            None => self.error("unexpected end of input"),
+        }
+    }
+
+    fn next_if(&mut self, expected: &Token) -> bool {
+        let matches = self.peek() == Some(expected);
+        if matches {
+            self.position += 1;
+        }
+        matches
+    }
+
+    fn next_if_keyword(&mut self, keyword: &str) -> bool {
+        self.next_if(&Token::Identifier(keyword.to_owned()))
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), IdlError> {
+        if self.next_if(&Token::Punctuation(c)) {
+            Ok(())
+        } else {
+            let found = self
+                .peek()
+                .map_or_else(|| "end of input".to_owned(), ToString::to_string);
+            self.error(format!("expected `{c}` but found {found}"))
+        }
+    }
+
+    fn expect_identifier(&mut self) -> Result<String, IdlError> {
+        match self.next()? {
+            Token::Identifier(identifier) => Ok(identifier),
+            token => self.error(format!("expected an identifier but found {token}")),
+        }
+    }
+
+    fn scoped(&self, name: &str) -> String {
+        self.scope
+            .iter()
+            .map(String::as_str)
+            .chain([name])
+            .collect::<Vec<_>>()
+            .join("::")
+    }
+
+    fn define(&mut self, name: &str, definition: Definition) -> Result<(), IdlError> {
+        let name = self.scoped(name);
+        let (defined, forward_declarable) = match self.definitions.get(&name) {
+            None | Some(Definition::ForwardDeclaration) => (false, true),
+            Some(Definition::Type(DynamicType::Struct(_))) => (true, true),
+            Some(_) => (true, false),
+        };
+
+        match (defined, &definition) {
+            (false, _) => {
+                self.definitions.insert(name, definition);
+                Ok(())
+            }
+            // A forward declaration of an already defined struct is a no-op.
+            (true, Definition::ForwardDeclaration) if forward_declarable => Ok(()),
+            _ => self.error(format!("`{name}` is defined more than once")),
+        }
+    }
+
+    fn resolve(&mut self) -> Result<Definition, IdlError> {
+        let absolute = self.next_if(&Token::Scope);
+        let mut name = self.expect_identifier()?;
+        while self.next_if(&Token::Scope) {
+            name.push_str("::");
+            name.push_str(&self.expect_identifier()?);
+        }
+
+        let depth = if absolute { 0 } else { self.scope.len() };
+        let found = (0..=depth).rev().find_map(|depth| {
+            let candidate = self
+                .scope
+                .iter()
+                .take(depth)
+                .map(String::as_str)
+                .chain([name.as_str()])
+                .collect::<Vec<_>>()
+                .join("::");
+            self.definitions.get_key_value(&candidate)
+        });
+
+        match found {
+            Some((_, Definition::ForwardDeclaration)) => {
+                self.error(format!("`{name}` is used before it is defined"))
+            }
+            Some((_, definition)) => Ok(definition.clone()),
+            None => self.error(format!("`{name}` is not defined")),
+        }
+    }
+
+    /// Parses annotations, returning their names and whether a `@key` has a
+    /// `FALSE` argument.
+    fn parse_annotations(&mut self) -> Result<Vec<(String, bool)>, IdlError> {
+        let mut annotations = Vec::new();
+        while self.next_if(&Token::Punctuation('@')) {
+            let name = self.expect_identifier()?;
+            let mut enabled = true;
+            if self.next_if(&Token::Punctuation('(')) {
+                let mut depth = 1;
+                while depth > 0 {
+                    match self.next()? {
+                        Token::Punctuation('(') => depth += 1,
+                        Token::Punctuation(')') => depth -= 1,
+                        Token::Identifier(value) if value.eq_ignore_ascii_case("false") => {
+                            enabled = false;
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            annotations.push((name, enabled));
+        }
+        Ok(annotations)
+    }
+
+    fn parse_specification(&mut self) -> Result<(), IdlError> {
+        while self.peek().is_some() {
+            self.parse_definition()?;
+        }
+        Ok(())
+    }
+
+    fn parse_definition(&mut self) -> Result<(), IdlError> {
+        for (annotation, enabled) in self.parse_annotations()? {
+            if annotation == "mutable" && enabled {
+                return self.error("@mutable types are not supported");
+            }
+        }
+
+        match self.expect_identifier()?.as_str() {
+            "module" => {
+                let name = self.expect_identifier()?;
+                self.expect('{')?;
+                self.scope.push(name);
+                while !self.next_if(&Token::Punctuation('}')) {
+                    self.parse_definition()?;
+                }
+                self.scope.pop();
+            }
+            "struct" => self.parse_struct()?,
+            "enum" => self.parse_enum()?,
+            "typedef" => {
+                let type_ = self.parse_type_spec()?;
+                loop {
+                    let (name, type_) = self.parse_declarator(type_.clone())?;
+                    self.define(&name, Definition::Type(type_))?;
+                    if !self.next_if(&Token::Punctuation(',')) {
+                        break;
+                    }
+                }
+            }
+            "const" => {
+                let type_ = self.parse_type_spec()?;
+                let name = self.expect_identifier()?;
+                self.expect('=')?;
+                match type_ {
+                    DynamicType::String { .. } => {
+                        self.next()?;
+                    }
+                    _ => {
+                        let value = self.parse_positive_integer()?;
+                        self.define(&name, Definition::Constant(value))?;
+                    }
+                }
+            }
+            keyword => return self.error(format!("`{keyword}` definitions are not supported")),
+        }
+
+        self.expect(';')
+    }
+
+    fn parse_struct(&mut self) -> Result<(), IdlError> {
+        let name = self.expect_identifier()?;
+        if self.peek() == Some(&Token::Punctuation(';')) {
+            return self.define(&name, Definition::ForwardDeclaration);
+        }
+
+        let mut members = if self.next_if(&Token::Punctuation(':')) {
+            match self.resolve()? {
+                Definition::Type(DynamicType::Struct(base)) => base.members,
+                _ => return self.error(format!("the base of `{name}` is not a struct")),
+            }
+        } else {
+            Vec::new()
+        };
+
+        self.expect('{')?;
+        while !self.next_if(&Token::Punctuation('}')) {
+            let mut key = false;
+            for (annotation, enabled) in self.parse_annotations()? {
+                match annotation.as_str() {
+                    "key" => key = enabled,
+                    "optional" if enabled => {
+                        return self.error("@optional members are not supported");
+                    }
+                    _ => {}
+                }
+            }
+
+            let type_ = self.parse_type_spec()?;
+            loop {
+                let (name, type_) = self.parse_declarator(type_.clone())?;
+                if members.iter().any(|member: &Member| member.name == name) {
+                    return self.error(format!("member `{name}` is declared more than once"));
+                }
+                members.push(Member::new(name, type_).with_key(key));
+                if !self.next_if(&Token::Punctuation(',')) {
+                    break;
+                }
+            }
+            self.expect(';')?;
+        }
+
+        let struct_type = StructType {
+            name: self.scoped(&name),
+            members,
+        };
+        self.define(&name, Definition::Type(struct_type.into()))
+    }
+
+    fn parse_enum(&mut self) -> Result<(), IdlError> {
+        let name = self.expect_identifier()?;
+        let mut enumerators = Vec::new();
+        self.expect('{')?;
+        loop {
+            if self.peek() == Some(&Token::Punctuation('@')) {
+                return self.error("annotated enumerators are not supported");
+            }
+            enumerators.push(self.expect_identifier()?);
+            if !self.next_if(&Token::Punctuation(',')) {
+                break;
+            }
+        }
+        self.expect('}')?;
+
+        let enum_type = EnumType::new(self.scoped(&name), enumerators);
+        self.define(&name, Definition::Type(enum_type.into()))
+    }
+
+    /// Parses a member or typedef name along with any array dimensions.
+    fn parse_declarator(&mut self, type_: DynamicType) -> Result<(String, DynamicType), IdlError> {
+        let name = self.expect_identifier()?;
+        let mut dimensions = Vec::new();
+        while self.next_if(&Token::Punctuation('[')) {
+            dimensions.push(self.parse_positive_integer()?);
+            self.expect(']')?;
+        }
+
+        // `T name[2][3]` is an array of two arrays of three `T`.
+        let type_ =
+            dimensions
+                .into_iter()
+                .rev()
+                .fold(type_, |element, length| DynamicType::Array {
+                    element: Box::new(element),
+                    length,
+                });
+        Ok((name, type_))
+    }
+
+    fn parse_positive_integer(&mut self) -> Result<usize, IdlError> {
+        match self.peek().cloned() {
+            Some(Token::Number(number)) => {
+                self.position += 1;
+                let parsed = if let Some(hex) = number
+                    .strip_prefix("0x")
+                    .or_else(|| number.strip_prefix("0X"))
+                {
+                    usize::from_str_radix(hex, 16)
+                } else {
+                    number.parse()
+                };
+                parsed.or_else(|_| self.error(format!("`{number}` is not a positive integer")))
+            }
+            Some(Token::Identifier(_) | Token::Scope) => match self.resolve()? {
+                Definition::Constant(value) => Ok(value),
+                _ => self.error("expected an integer constant"),
+            },
+            _ => self.error("expected a positive integer"),
+        }
+    }
+
+    fn parse_type_spec(&mut self) -> Result<DynamicType, IdlError> {
+        let Some(Token::Identifier(keyword)) = self.peek().cloned() else {
+            return match self.resolve()? {
+                Definition::Type(type_) => Ok(type_),
+                _ => self.error("expected a type"),
+            };
+        };
+
+        let type_ = match keyword.as_str() {
+            "boolean" => DynamicType::Bool,
+            "int8" => DynamicType::Int8,
+            "octet" | "uint8" => DynamicType::UInt8,
+            "short" | "int16" => DynamicType::Int16,
+            "uint16" => DynamicType::UInt16,
+            "int32" => DynamicType::Int32,
+            "uint32" => DynamicType::UInt32,
+            "int64" => DynamicType::Int64,
+            "uint64" => DynamicType::UInt64,
+            "float" => DynamicType::Float32,
+            "double" => DynamicType::Float64,
+            "long" => {
+                self.position += 1;
+                if self.next_if_keyword("double") {
+                    return self.error("`long double` is not supported");
+                }
+                return Ok(if self.next_if_keyword("long") {
+                    DynamicType::Int64
+                } else {
+                    DynamicType::Int32
+                });
+            }
+            "unsigned" => {
+                self.position += 1;
+                return if self.next_if_keyword("short") {
+                    Ok(DynamicType::UInt16)
+                } else if self.next_if_keyword("long") {
+                    Ok(if self.next_if_keyword("long") {
+                        DynamicType::UInt64
+                    } else {
+                        DynamicType::UInt32
+                    })
+                } else {
+                    self.error("expected `short` or `long` after `unsigned`")
+                };
+            }
+            "string" => {
+                self.position += 1;
+                let bound = if self.next_if(&Token::Punctuation('<')) {
+                    let bound = self.parse_positive_integer()?;
+                    self.expect('>')?;
+                    Some(bound)
+                } else {
+                    None
+                };
+                return Ok(DynamicType::String { bound });
+            }
+            "sequence" => {
+                self.position += 1;
+                self.expect('<')?;
+                let element = self.parse_type_spec()?;
+                let bound = if self.next_if(&Token::Punctuation(',')) {
+                    Some(self.parse_positive_integer()?)
+                } else {
+                    None
+                };
+                self.expect('>')?;
+                return Ok(DynamicType::Sequence {
+                    element: Box::new(element),
+                    bound,
+                });
+            }
+            "char" | "wchar" | "wstring" | "any" | "fixed" | "map" => {
+                return self.error(format!("`{keyword}` is not supported"));
+            }
+            _ => {
+                return match self.resolve()? {
+                    Definition::Type(type_) => Ok(type_),
+                    _ => self.error(format!("`{keyword}` is not a type")),
+                };
+            }
+        };
+
+        self.position += 1;
+        Ok(type_)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_idl_resolves_scoped_types() {
+        let idl = r"
+            #include <other.idl>
+            module a {
+                const long MAX_NAME = 8;
+                enum Color { RED, GREEN, BLUE };
+                typedef sequence<long, 0x10> Values;
+
+                /* A point
+                   in space. */
+                struct Point { double x; double y; };
+
+                module b {
+                    struct Base { @key(TRUE) string<MAX_NAME> name; };
+
+                    @final
+                    struct Shape : Base {
+                        Color color;           // resolved from the enclosing module
+                        ::a::Point corners[2][3];
+                        Values values;
+                        @key(FALSE) unsigned long long id;
+                        sequence<sequence<octet>> blobs;
+                    };
+                };
+            };
+        ";
+
+        let shape = StructType::from_idl(idl, "a::b::Shape").unwrap();
+        let point = StructType::new("a::Point")
+            .with_member("x", DynamicType::Float64)
+            .with_member("y", DynamicType::Float64);
+        let expected = StructType::new("a::b::Shape")
+            .with_key_member("name", DynamicType::String { bound: Some(8) })
+            .with_member(
+                "color",
+                EnumType::new("a::Color", ["RED", "GREEN", "BLUE"]).into(),
+            )
+            .with_member(
+                "corners",
+                DynamicType::Array {
+                    element: Box::new(DynamicType::Array {
+                        element: Box::new(point.into()),
+                        length: 3,
+                    }),
+                    length: 2,
+                },
+            )
+            .with_member(
+                "values",
+                DynamicType::Sequence {
+                    element: Box::new(DynamicType::Int32),
+                    bound: Some(16),
+                },
+            )
+            .with_member("id", DynamicType::UInt64)
+            .with_member(
+                "blobs",
+                DynamicType::Sequence {
+                    element: Box::new(DynamicType::Sequence {
+                        element: Box::new(DynamicType::UInt8),
+                        bound: None,
+                    }),
+                    bound: None,
+                },
+            );
+        assert_eq!(shape, expected);
+    }
+
+    #[test]
+    fn test_from_idl_errors() {
+        let result = StructType::from_idl("struct A { long x; };", "B");
+        assert_eq!(result, Err(IdlError::TypeNotFound("B".to_owned())));
+
+        let result = StructType::from_idl("struct A {\n  Unknown x;\n};", "A");
+        assert!(matches!(result, Err(IdlError::Syntax { line: 2, .. })));
+
+        let result = StructType::from_idl("union U switch (long) { case 1: long x; };", "U");
+        assert!(matches!(result, Err(IdlError::Syntax { line: 1, .. })));
+
+        let result = StructType::from_idl("struct A { @optional long x; };", "A");
+        assert!(matches!(result, Err(IdlError::Syntax { .. })));
+
+        let result = StructType::from_idl("@mutable struct A { long x; };", "A");
+        assert!(matches!(result, Err(IdlError::Syntax { .. })));
+
+        let result = StructType::from_idl("struct A { long x; long x; };", "A");
+        assert!(matches!(result, Err(IdlError::Syntax { .. })));
+
+        let result = StructType::from_idl("struct A { long x; ", "A");
+        assert!(matches!(result, Err(IdlError::Syntax { .. })));
+    }
+}
@@ -0,0 +1,244 @@
+use crate::dynamic::{DynamicData, DynamicTopic};
+use crate::internal::ffi;
+use crate::{Result, Subscriber};
+
+/// A data reader for a [`DynamicTopic`].
+///
+/// This behaves like a [`Reader`](crate::Reader), including the distinction
+/// between [`peek`](DynamicReader::peek), [`read`](DynamicReader::read), and
+/// [`take`](DynamicReader::take), but returns [`DynamicSample`]s.
+#[derive(Debug, PartialEq, Eq)]
+pub struct DynamicReader<'domain, 'participant, 'topic> {
+    pub(crate) inner: cyclonedds_sys::dds_entity_t,
+    phantom_topic: std::marker::PhantomData<&'topic DynamicTopic<'domain, 'participant>>,
+}
+
+/// Builder for [`DynamicReader`] (accessible via [`DynamicReader::builder`]).
+#[derive(Debug)]
+pub struct DynamicReaderBuilder<'domain, 'participant, 'topic, 'qos> {
+    subscriber: Option<&'participant Subscriber<'domain, 'participant>>,
+    topic: &'topic DynamicTopic<'domain, 'participant>,
+    qos: Option<&'qos crate::QoS>,
+}
+
+/// A sample received from a [`DynamicReader`].
+///
+/// Like a [`SampleOrKey`](crate::sample::SampleOrKey) this is either a full
+/// sample or, for instance state changes such as disposal, just the key
+/// fields of the instance.
+#[derive(Clone, Debug)]
+pub struct DynamicSample {
+    pub(crate) data: DynamicData,
+    pub(crate) is_sample: bool,
+    pub(crate) info: crate::sample::Info,
+}
+
+impl DynamicSample {
+    /// Returns the metadata associated with this sample.
+    #[must_use]
+    pub const fn info(&self) -> &crate::sample::Info {
+        &self.info
+    }
+
+    /// Returns the full sample, or `None` if this is a key-only sample.
+    #[must_use]
+    pub const fn sample(&self) -> Option<&DynamicData> {
+        if self.is_sample {
+            Some(&self.data)
+        } else {
+            None
+        }
+    }
+
+    /// Consumes `self` and returns the full sample, or `None` if this is a
+    /// key-only sample.
+    #[must_use]
+    pub fn into_sample(self) -> Option<DynamicData> {
+        self.is_sample.then_some(self.data)
+    }
+
+    /// Returns the key fields, or `None` if this is a full sample.
+    #[must_use]
+    pub const fn key(&self) -> Option<&DynamicData> {
+        if self.is_sample {
+            None
+        } else {
+            Some(&self.data)
+        }
+    }
+
+    /// Consumes `self` and returns the key fields, or `None` if this is a
+    /// full sample.
+    #[must_use]
+    pub fn into_key(self) -> Option<DynamicData> {
+        (!self.is_sample).then_some(self.data)
+    }
+
+    /// Returns `true` if this is a full sample.
+    #[must_use]
+    pub const fn is_sample(&self) -> bool {
+        self.is_sample
+    }
+
+    /// Returns `true` if this is a key-only sample.
+    #[must_use]
+    pub const fn is_key(&self) -> bool {
+        !self.is_sample
+    }
+}
+
+impl<'d, 'p, 't, 'q> DynamicReaderBuilder<'d, 'p, 't, 'q> {
+    /// Creates a new [`DynamicReaderBuilder`] for the given [`DynamicTopic`].
+    #[must_use]
+    pub const fn new(topic: &'t DynamicTopic<'d, 'p>) -> Self {
+        Self {
+            subscriber: None,
+            topic,
+            qos: None,
+        }
+    }
+
+    /// Sets the [`QoS`](crate::QoS) for this reader builder.
+    #[must_use]
+    pub const fn with_qos(mut self, qos: &'q crate::QoS) -> Self {
+        self.qos = Some(qos);
+        self
+    }
+
+    /// Sets the [`Subscriber`] for this reader builder.
+    ///
+    /// If unset the reader is created directly under the participant of the
+    /// topic.
+    #[must_use]
+    pub const fn with_subscriber(mut self, subscriber: &'p Subscriber<'d, 'p>) -> Self {
+        self.subscriber = Some(subscriber);
+        self
+    }
+
+    /// Builds the [`DynamicReader`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the reader fails to create.
+    pub fn build(self) -> Result<DynamicReader<'d, 'p, 't>> {
+        Ok(DynamicReader {
+            inner: ffi::dds_create_reader(
+                self.subscriber
+                    .map_or(ffi::dds_get_participant(self.topic.inner)?, |subscriber| {
+                        subscriber.inner
+                    }),
+                self.topic.inner,
+                self.qos.map(|qos| &qos.inner),
+                None,
+            )?,
+            phantom_topic: std::marker::PhantomData,
+        })
+    }
+}
+
+impl<'d, 'p, 't> DynamicReader<'d, 'p, 't> {
+    /// Creates a new `DynamicReader` for the given [`DynamicTopic`] with
+    /// default [`QoS`](crate::QoS).
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the reader fails to create.
+    pub fn new(topic: &'t DynamicTopic<'d, 'p>) -> Result<Self> {
+        Self::builder(topic).build()
+    }
+
+    /// Returns a [`DynamicReaderBuilder`] for constructing a reader with
+    /// custom [`QoS`](crate::QoS) or under a specific [`Subscriber`].
+    #[must_use]
+    pub const fn builder<'q>(
+        topic: &'t DynamicTopic<'d, 'p>,
+    ) -> DynamicReaderBuilder<'d, 'p, 't, 'q> {
+        DynamicReaderBuilder::new(topic)
+    }
+
+    /// Removes and returns all available samples from the reader cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the reader fails to take samples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclonedds::dynamic::{
+    ///     DynamicData, DynamicReader, DynamicStruct, DynamicTopic, DynamicType, DynamicWriter,
+    ///     StructType,
+    /// };
+    /// # use cyclonedds::{Domain, Participant};
+    /// # let domain = Domain::default();
+    /// # let participant = Participant::new(&domain)?;
+    ///
+    /// let struct_type = StructType::new("Data").with_member("x", DynamicType::Int32);
+    /// let topic = DynamicTopic::new(&participant, "MyTopic", struct_type)?;
+    /// let reader = DynamicReader::new(&topic)?;
+    /// let writer = DynamicWriter::new(&topic)?;
+    ///
+    /// let sample = DynamicData::from(DynamicStruct::new().with_field("x", 1i32));
+    /// writer.write(&sample)?;
+    ///
+    /// let samples = reader.take()?;
+    /// assert_eq!(samples[0].sample(), Some(&sample));
+    /// assert!(reader.take()?.is_empty());
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn take(&self) -> Result<Vec<DynamicSample>> {
+        ffi::dds_peek_read_take_dynamic::<ffi::read_operation::Take>(self.inner)
+    }
+
+    /// Returns all available samples from the reader cache without removing
+    /// them, marking them as read.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the reader fails to read samples.
+    pub fn read(&self) -> Result<Vec<DynamicSample>> {
+        ffi::dds_peek_read_take_dynamic::<ffi::read_operation::Read>(self.inner)
+    }
+
+    /// Returns all available samples without marking them as read or removing
+    /// them from the cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the reader fails to peek.
+    pub fn peek(&self) -> Result<Vec<DynamicSample>> {
+        ffi::dds_peek_read_take_dynamic::<ffi::read_operation::Peek>(self.inner)
+    }
+
+    /// Returns the name of the [`DynamicTopic`] this reader is associated
+    /// with.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the topic name could not be
+    /// retrieved.
+    pub fn topic_name(&self) -> Result<String> {
+        ffi::dds_get_topic(self.inner).and_then(ffi::dds_get_name)
+    }
+
+    /// Returns the name of the type of the [`DynamicTopic`] this reader is
+    /// associated with.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the type name could not be
+    /// retrieved.
+    pub fn type_name(&self) -> Result<String> {
+        ffi::dds_get_topic(self.inner).and_then(ffi::dds_get_type_name)
+    }
+}
+
+impl Drop for DynamicReader<'_, '_, '_> {
+    fn drop(&mut self) {
+        let result = ffi::dds_delete(self.inner);
+        debug_assert!(
+            result.is_ok(),
+            "unable to delete {self:?}: failed with {result:?}"
+        );
+    }
+}
@@ -0,0 +1,146 @@
+use std::sync::Arc;
+
+use crate::dynamic::StructType;
+use crate::internal::dynamic_sertype::DynamicSertype;
+use crate::internal::ffi;
+use crate::{Participant, Result};
+
+/// A communication channel whose data type is described at runtime.
+///
+/// The DDS type name of the topic is the [`name`](StructType::name) of its
+/// [`StructType`].
+#[derive(Debug)]
+pub struct DynamicTopic<'domain, 'participant> {
+    pub(crate) inner: cyclonedds_sys::dds_entity_t,
+    pub(crate) struct_type: Arc<StructType>,
+    phantom: std::marker::PhantomData<&'participant Participant<'domain>>,
+}
+
+/// Builder for [`DynamicTopic`] (accessible via [`DynamicTopic::builder`]).
+#[derive(Debug)]
+pub struct DynamicTopicBuilder<'domain, 'participant, 'qos, 'name> {
+    participant: &'participant Participant<'domain>,
+    topic_name: &'name str,
+    struct_type: StructType,
+    qos: Option<&'qos crate::QoS>,
+}
+
+impl<'d, 'p, 'q, 'n> DynamicTopicBuilder<'d, 'p, 'q, 'n> {
+    /// Creates a new [`DynamicTopicBuilder`] for the given [`Participant`].
+    #[must_use]
+    pub const fn new(
+        participant: &'p Participant<'d>,
+        topic_name: &'n str,
+        struct_type: StructType,
+    ) -> Self {
+        Self {
+            participant,
+            topic_name,
+            struct_type,
+            qos: None,
+        }
+    }
+
+    /// Sets the [`QoS`](crate::QoS) for this topic builder.
+    #[must_use]
+    pub const fn with_qos(mut self, qos: &'q crate::QoS) -> Self {
+        self.qos = Some(qos);
+        self
+    }
+
+    /// Builds the [`DynamicTopic`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the topic failed to create, for
+    /// example because a topic of the same name but a different type already
+    /// exists.
+    pub fn build(self) -> Result<DynamicTopic<'d, 'p>> {
+        let name = std::ffi::CString::new(self.topic_name)
+            .map_err(|_err| crate::error::Error::BadParameter)?;
+        let type_name = std::ffi::CString::new(self.struct_type.name.as_str())
+            .map_err(|_err| crate::error::Error::BadParameter)?;
+
+        let struct_type = Arc::new(self.struct_type);
+        let mut sertype = std::mem::ManuallyDrop::new(Box::new(DynamicSertype::new(
+            &type_name,
+            Arc::clone(&struct_type),
+        )));
+
+        let inner = ffi::dds_create_topic(
+            self.participant.inner,
+            &name,
+            &mut &mut sertype.inner,
+            self.qos.map(|qos| &qos.inner),
+            None,
+        )
+        .inspect_err(|_| {
+            ffi::ddsi_sertype_unref(&mut sertype.inner);
+        })?;
+
+        Ok(DynamicTopic {
+            inner,
+            struct_type,
+            phantom: std::marker::PhantomData,
+        })
+    }
+}
+
+impl<'d, 'p> DynamicTopic<'d, 'p> {
+    /// Creates a new `DynamicTopic` with the given name and type under
+    /// `participant` using default [`QoS`](crate::QoS).
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the topic fails to create.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclonedds::dynamic::{DynamicTopic, DynamicType, StructType};
+    /// # use cyclonedds::{Domain, Participant};
+    /// # let domain = Domain::default();
+    /// # let participant = Participant::new(&domain)?;
+    ///
+    /// let struct_type = StructType::new("Data")
+    ///     .with_key_member("x", DynamicType::Int32)
+    ///     .with_member("y", DynamicType::Int32);
+    /// let topic = DynamicTopic::new(&participant, "MyTopic", struct_type)?;
+    /// assert_eq!(topic.struct_type().name, "Data");
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn new(
+        participant: &'p Participant<'d>,
+        topic_name: &str,
+        struct_type: StructType,
+    ) -> Result<Self> {
+        Self::builder(participant, topic_name, struct_type).build()
+    }
+
+    /// Returns a [`DynamicTopicBuilder`] for constructing a topic with custom
+    /// [`QoS`](crate::QoS).
+    #[must_use]
+    pub const fn builder<'q, 'n>(
+        participant: &'p Participant<'d>,
+        topic_name: &'n str,
+        struct_type: StructType,
+    ) -> DynamicTopicBuilder<'d, 'p, 'q, 'n> {
+        DynamicTopicBuilder::new(participant, topic_name, struct_type)
+    }
+
+    /// Returns the type of the samples on this topic.
+    #[must_use]
+    pub fn struct_type(&self) -> &StructType {
+        &self.struct_type
+    }
+}
+
+impl Drop for DynamicTopic<'_, '_> {
+    fn drop(&mut self) {
+        let result = ffi::dds_delete(self.inner);
+        debug_assert!(
+            result.is_ok(),
+            "unable to delete {self:?}: failed with {result:?}"
+        );
+    }
+}
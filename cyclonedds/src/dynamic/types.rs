@@ -0,0 +1,428 @@
+use crate::cdr_bounds::{CdrSize, Padding};
+use crate::dynamic::{DynamicData, DynamicStruct};
+
+/// A runtime description of a DDS data type.
+///
+/// This covers the subset of the IDL type system that maps directly onto the
+/// CDR encoding used by [`Topicable`](crate::Topicable) types: primitives,
+/// strings, sequences, arrays, enumerations, and structures.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DynamicType {
+    /// `boolean`, encoded as a single byte.
+    Bool,
+    /// `int8`.
+    Int8,
+    /// `uint8` / `octet`.
+    UInt8,
+    /// `int16` / `short`.
+    Int16,
+    /// `uint16` / `unsigned short`.
+    UInt16,
+    /// `int32` / `long`.
+    Int32,
+    /// `uint32` / `unsigned long`.
+    UInt32,
+    /// `int64` / `long long`.
+    Int64,
+    /// `uint64` / `unsigned long long`.
+    UInt64,
+    /// `float`.
+    Float32,
+    /// `double`.
+    Float64,
+    /// `string` or `string<bound>`.
+    String {
+        /// The maximum number of characters, if bounded.
+        bound: Option<usize>,
+    },
+    /// `sequence<element>` or `sequence<element, bound>`.
+    Sequence {
+        /// The type of each element.
+        element: Box<DynamicType>,
+        /// The maximum number of elements, if bounded.
+        bound: Option<usize>,
+    },
+    /// A fixed-length array, e.g. `element name[length]`.
+    Array {
+        /// The type of each element.
+        element: Box<DynamicType>,
+        /// The number of elements.
+        length: usize,
+    },
+    /// An `enum`.
+    Enum(EnumType),
+    /// A `struct`.
+    Struct(StructType),
+}
+
+/// A runtime description of an IDL `enum`.
+///
+/// Enumerators are encoded as their zero-based position in
+/// [`enumerators`](EnumType::enumerators).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EnumType {
+    /// The fully scoped name of the enumeration, e.g. `module::Color`.
+    pub name: String,
+    /// The names of the enumerators in declaration order.
+    pub enumerators: Vec<String>,
+}
+
+/// A runtime description of an IDL `struct`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StructType {
+    /// The fully scoped name of the structure, e.g. `module::Position`.
+    ///
+    /// When used as the type of a [`DynamicTopic`](crate::dynamic::DynamicTopic)
+    /// this is the DDS type name and so must match the type name used by
+    /// remote readers and writers.
+    pub name: String,
+    /// The members in declaration (and so serialization) order.
+    pub members: Vec<Member>,
+}
+
+/// A single member of a [`StructType`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Member {
+    /// The name of the member.
+    pub name: String,
+    /// The type of the member.
+    pub type_: DynamicType,
+    /// Whether the member is part of the key (i.e. annotated with `@key`).
+    pub key: bool,
+}
+
+impl DynamicType {
+    /// Returns the default value for this type.
+    ///
+    /// Numbers are zero, strings and sequences are empty, arrays are filled
+    /// with the default value of their element type, enumerations take their
+    /// first enumerator, and structures take the default value of every
+    /// member.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclonedds::dynamic::{DynamicData, DynamicType};
+    ///
+    /// let array = DynamicType::Array {
+    ///     element: Box::new(DynamicType::Int16),
+    ///     length: 2,
+    /// };
+    /// assert_eq!(
+    ///     array.default_value(),
+    ///     DynamicData::Array(vec![DynamicData::Int16(0), DynamicData::Int16(0)])
+    /// );
+    /// ```
+    #[must_use]
+    pub fn default_value(&self) -> DynamicData {
+        match self {
+            DynamicType::Bool => DynamicData::Bool(false),
+            DynamicType::Int8 => DynamicData::Int8(0),
+            DynamicType::UInt8 => DynamicData::UInt8(0),
+            DynamicType::Int16 => DynamicData::Int16(0),
+            DynamicType::UInt16 => DynamicData::UInt16(0),
+            DynamicType::Int32 => DynamicData::Int32(0),
+            DynamicType::UInt32 => DynamicData::UInt32(0),
+            DynamicType::Int64 => DynamicData::Int64(0),
+            DynamicType::UInt64 => DynamicData::UInt64(0),
+            DynamicType::Float32 => DynamicData::Float32(0.0),
+            DynamicType::Float64 => DynamicData::Float64(0.0),
+            DynamicType::String { .. } => DynamicData::String(String::new()),
+            DynamicType::Sequence { .. } => DynamicData::Sequence(Vec::new()),
+            DynamicType::Array { element, length } => {
+                DynamicData::Array(vec![element.default_value(); *length])
+            }
+            DynamicType::Enum(_) => DynamicData::Enum(0),
+            DynamicType::Struct(struct_type) => struct_type.default_value(),
+        }
+    }
+
+    /// Whether this is a primitive or an enumeration, the elements of which are
+    /// not preceded by a DHEADER in XCDR2 sequences and arrays.
+    pub(crate) const fn is_primitive(&self) -> bool {
+        !matches!(
+            self,
+            DynamicType::String { .. }
+                | DynamicType::Sequence { .. }
+                | DynamicType::Array { .. }
+                | DynamicType::Struct(_)
+        )
+    }
+
+    /// Returns the CDR alignment of this type in bytes.
+    pub(crate) fn alignment(&self) -> usize {
+        match self {
+            DynamicType::Bool | DynamicType::Int8 | DynamicType::UInt8 => 1,
+            DynamicType::Int16 | DynamicType::UInt16 => 2,
+            DynamicType::Int32
+            | DynamicType::UInt32
+            | DynamicType::Float32
+            | DynamicType::String { .. }
+            | DynamicType::Enum(_) => 4,
+            DynamicType::Int64 | DynamicType::UInt64 | DynamicType::Float64 => 8,
+            DynamicType::Sequence { element, .. } => 4usize.max(element.alignment()),
+            DynamicType::Array { element, .. } => element.alignment(),
+            DynamicType::Struct(struct_type) => struct_type.alignment(),
+        }
+    }
+
+    /// Returns the maximum serialized CDR size of this type following the
+    /// same rules as the [`CdrBounds`](crate::cdr_bounds::CdrBounds)
+    /// implementations of the equivalent Rust types.
+    pub(crate) fn max_serialized_cdr_size(&self) -> CdrSize {
+        match self {
+            DynamicType::String { bound: Some(bound) } => {
+                // Length prefix, characters, and the NUL terminator.
+                CdrSize::Bounded(4 + bound + 1)
+            }
+            DynamicType::Sequence {
+                element,
+                bound: Some(bound),
+            } => {
+                4usize.with_padding(element.alignment())
+                    + element
+                        .max_serialized_cdr_size()
+                        .with_padding(element.alignment())
+                        * *bound
+            }
+            DynamicType::String { bound: None } | DynamicType::Sequence { bound: None, .. } => {
+                CdrSize::Unbounded
+            }
+            DynamicType::Array { element, length } => {
+                element
+                    .max_serialized_cdr_size()
+                    .with_padding(element.alignment())
+                    * *length
+            }
+            DynamicType::Struct(struct_type) => struct_type.max_serialized_cdr_size(),
+            primitive => CdrSize::Bounded(primitive.alignment()),
+        }
+    }
+}
+
+impl EnumType {
+    /// Creates a new enumeration from its scoped name and enumerators.
+    pub fn new<N, E, S>(name: N, enumerators: E) -> Self
+    where
+        N: Into<String>,
+        E: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            name: name.into(),
+            enumerators: enumerators.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl StructType {
+    /// Creates a new structure with no members.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclonedds::dynamic::{DynamicType, StructType};
+    ///
+    /// let position = StructType::new("geometry::Position")
+    ///     .with_key_member("id", DynamicType::UInt32)
+    ///     .with_member("x", DynamicType::Float64)
+    ///     .with_member("y", DynamicType::Float64);
+    /// assert!(position.is_keyed());
+    /// ```
+    pub fn new<N>(name: N) -> Self
+    where
+        N: Into<String>,
+    {
+        Self {
+            name: name.into(),
+            members: Vec::new(),
+        }
+    }
+
+    /// Appends a non-key member to this structure.
+    #[must_use]
+    pub fn with_member<N>(mut self, name: N, type_: DynamicType) -> Self
+    where
+        N: Into<String>,
+    {
+        self.members.push(Member::new(name, type_));
+        self
+    }
+
+    /// Appends a key member to this structure.
+    #[must_use]
+    pub fn with_key_member<N>(mut self, name: N, type_: DynamicType) -> Self
+    where
+        N: Into<String>,
+    {
+        self.members.push(Member::new(name, type_).with_key(true));
+        self
+    }
+
+    /// Returns the member called `name`, if any.
+    #[must_use]
+    pub fn member(&self, name: &str) -> Option<&Member> {
+        self.members.iter().find(|member| member.name == name)
+    }
+
+    /// Returns `true` if any member is part of the key.
+    #[must_use]
+    pub fn is_keyed(&self) -> bool {
+        self.members.iter().any(|member| member.key)
+    }
+
+    /// Returns the default value of this structure, see
+    /// [`DynamicType::default_value`].
+    #[must_use]
+    pub fn default_value(&self) -> DynamicData {
+        DynamicData::Struct(
+            self.members
+                .iter()
+                .map(|member| (member.name.clone(), member.type_.default_value()))
+                .collect(),
+        )
+    }
+
+    /// Returns the structure consisting of just the key members.
+    ///
+    /// This mirrors the key type generated by the
+    /// [`Topicable`](crate::Topicable) derive and is what is serialized for
+    /// key-only samples and keyhashes.
+    pub(crate) fn key_type(&self) -> StructType {
+        StructType {
+            name: self.name.clone(),
+            members: self
+                .members
+                .iter()
+                .filter(|member| member.key)
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Extracts the key fields from `data`, which may either be a full sample
+    /// or already just a key.
+    pub(crate) fn key_of(&self, data: &DynamicData) -> Option<DynamicData> {
+        let DynamicData::Struct(fields) = data else {
+            return None;
+        };
+
+        self.members
+            .iter()
+            .filter(|member| member.key)
+            .map(|member| {
+                fields
+                    .get(&member.name)
+                    .map(|value| (member.name.clone(), value.clone()))
+            })
+            .collect::<Option<DynamicStruct>>()
+            .map(DynamicData::Struct)
+    }
+
+    pub(crate) fn alignment(&self) -> usize {
+        self.members
+            .iter()
+            .map(|member| member.type_.alignment())
+            .max()
+            .unwrap_or(1)
+    }
+
+    // NOTE: like the key type generated by the `Topicable` derive this is the
+    // plain sum of the member sizes so that both sides agree on when an MD5
+    // keyhash is required.
+    pub(crate) fn max_serialized_cdr_size(&self) -> CdrSize {
+        self.members
+            .iter()
+            .fold(CdrSize::Bounded(0), |size, member| {
+                size + member.type_.max_serialized_cdr_size()
+            })
+    }
+}
+
+impl Member {
+    /// Creates a new non-key member.
+    pub fn new<N>(name: N, type_: DynamicType) -> Self
+    where
+        N: Into<String>,
+    {
+        Self {
+            name: name.into(),
+            type_,
+            key: false,
+        }
+    }
+
+    /// Sets whether this member is part of the key.
+    #[must_use]
+    pub const fn with_key(mut self, key: bool) -> Self {
+        self.key = key;
+        self
+    }
+}
+
+impl From<StructType> for DynamicType {
+    fn from(struct_type: StructType) -> Self {
+        DynamicType::Struct(struct_type)
+    }
+}
+
+impl From<EnumType> for DynamicType {
+    fn from(enum_type: EnumType) -> Self {
+        DynamicType::Enum(enum_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_max_serialized_cdr_size() {
+        assert_eq!(
+            DynamicType::Int32.max_serialized_cdr_size(),
+            CdrSize::Bounded(4)
+        );
+        assert_eq!(
+            DynamicType::String { bound: None }.max_serialized_cdr_size(),
+            CdrSize::Unbounded
+        );
+        assert_eq!(
+            DynamicType::String { bound: Some(8) }.max_serialized_cdr_size(),
+            CdrSize::Bounded(13)
+        );
+        assert_eq!(
+            DynamicType::Array {
+                element: Box::new(DynamicType::UInt16),
+                length: 3,
+            }
+            .max_serialized_cdr_size(),
+            CdrSize::Bounded(6)
+        );
+
+        let key = StructType::new("Data")
+            .with_key_member("x", DynamicType::UInt32)
+            .with_key_member("y", DynamicType::Int64)
+            .with_member("message", DynamicType::String { bound: None })
+            .key_type();
+        assert_eq!(key.max_serialized_cdr_size(), CdrSize::Bounded(12));
+        assert_eq!(key.alignment(), 8);
+    }
+
+    #[test]
+    fn test_key_of() {
+        let struct_type = StructType::new("Data")
+            .with_key_member("x", DynamicType::UInt32)
+            .with_member("y", DynamicType::Int32);
+        let sample = DynamicData::from(
+            DynamicStruct::new()
+                .with_field("x", 7u32)
+                .with_field("y", -1i32),
+        );
+
+        let key = struct_type.key_of(&sample).unwrap();
+        assert_eq!(key, DynamicStruct::new().with_field("x", 7u32).into());
+        // Extracting the key from a key is a no-op.
+        assert_eq!(struct_type.key_of(&key).unwrap(), key);
+        assert_eq!(struct_type.key_of(&DynamicData::UInt32(7)), None);
+    }
+}
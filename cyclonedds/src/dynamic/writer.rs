@@ -0,0 +1,200 @@
+use crate::dynamic::{DynamicData, DynamicTopic};
+use crate::internal::ffi;
+use crate::{Publisher, Result};
+
+/// A data writer for a [`DynamicTopic`].
+///
+/// Samples are [`DynamicData::Struct`] values whose fields match the
+/// [`StructType`](crate::dynamic::StructType) of the topic. Writing a sample
+/// that does not match fails with
+/// [`Error::BadParameter`](crate::Error::BadParameter).
+///
+/// The instance lifecycle operations take either a full sample or just its
+/// key fields.
+#[derive(Debug, PartialEq, Eq)]
+pub struct DynamicWriter<'domain, 'participant, 'topic> {
+    pub(crate) inner: cyclonedds_sys::dds_entity_t,
+    phantom_topic: std::marker::PhantomData<&'topic DynamicTopic<'domain, 'participant>>,
+}
+
+/// Builder for [`DynamicWriter`] (accessible via [`DynamicWriter::builder`]).
+#[derive(Debug)]
+pub struct DynamicWriterBuilder<'domain, 'participant, 'topic, 'qos> {
+    publisher: Option<&'participant Publisher<'domain, 'participant>>,
+    topic: &'topic DynamicTopic<'domain, 'participant>,
+    qos: Option<&'qos crate::QoS>,
+}
+
+impl<'d, 'p, 't, 'q> DynamicWriterBuilder<'d, 'p, 't, 'q> {
+    /// Creates a new [`DynamicWriterBuilder`] for the given [`DynamicTopic`].
+    #[must_use]
+    pub const fn new(topic: &'t DynamicTopic<'d, 'p>) -> Self {
+        Self {
+            publisher: None,
+            topic,
+            qos: None,
+        }
+    }
+
+    /// Sets the [`QoS`](crate::QoS) for this writer builder.
+    #[must_use]
+    pub const fn with_qos(mut self, qos: &'q crate::QoS) -> Self {
+        self.qos = Some(qos);
+        self
+    }
+
+    /// Sets the [`Publisher`] for this writer builder.
+    ///
+    /// If unset the writer is created directly under the participant of the
+    /// topic.
+    #[must_use]
+    pub const fn with_publisher(mut self, publisher: &'p Publisher<'d, 'p>) -> Self {
+        self.publisher = Some(publisher);
+        self
+    }
+
+    /// Builds the [`DynamicWriter`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the writer fails to create.
+    pub fn build(self) -> Result<DynamicWriter<'d, 'p, 't>> {
+        Ok(DynamicWriter {
+            inner: ffi::dds_create_writer(
+                self.publisher
+                    .map_or(ffi::dds_get_participant(self.topic.inner)?, |publisher| {
+                        publisher.inner
+                    }),
+                self.topic.inner,
+                self.qos.map(|qos| &qos.inner),
+                None,
+            )?,
+            phantom_topic: std::marker::PhantomData,
+        })
+    }
+}
+
+impl<'d, 'p, 't> DynamicWriter<'d, 'p, 't> {
+    /// Creates a new `DynamicWriter` for the given [`DynamicTopic`] with
+    /// default [`QoS`](crate::QoS).
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the writer fails to create.
+    pub fn new(topic: &'t DynamicTopic<'d, 'p>) -> Result<Self> {
+        Self::builder(topic).build()
+    }
+
+    /// Returns a [`DynamicWriterBuilder`] for constructing a writer with
+    /// custom [`QoS`](crate::QoS) or under a specific [`Publisher`].
+    #[must_use]
+    pub const fn builder<'q>(
+        topic: &'t DynamicTopic<'d, 'p>,
+    ) -> DynamicWriterBuilder<'d, 'p, 't, 'q> {
+        DynamicWriterBuilder::new(topic)
+    }
+
+    /// Writes a sample.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the sample does not match the
+    /// type of the topic or the writer fails to write it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclonedds::dynamic::{
+    ///     DynamicData, DynamicStruct, DynamicTopic, DynamicType, DynamicWriter, StructType,
+    /// };
+    /// # use cyclonedds::{Domain, Participant};
+    /// # let domain = Domain::default();
+    /// # let participant = Participant::new(&domain)?;
+    ///
+    /// let struct_type = StructType::new("Data").with_key_member("x", DynamicType::Int32);
+    /// let topic = DynamicTopic::new(&participant, "MyTopic", struct_type)?;
+    /// let writer = DynamicWriter::new(&topic)?;
+    ///
+    /// writer.write(&DynamicData::from(DynamicStruct::new().with_field("x", 1i32)))?;
+    ///
+    /// // The field has the wrong type.
+    /// let mismatched = DynamicData::from(DynamicStruct::new().with_field("x", 1u8));
+    /// assert!(writer.write(&mismatched).is_err());
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn write(&self, sample: &DynamicData) -> Result<()> {
+        ffi::dds_write_dynamic(self.inner, sample)
+    }
+
+    /// Writes a sample with an explicit source timestamp.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the sample does not match the
+    /// type of the topic or the writer fails to write it.
+    pub fn write_with_timestamp(&self, sample: &DynamicData, timestamp: crate::Time) -> Result<()> {
+        ffi::dds_write_dynamic_with_timestamp(self.inner, sample, timestamp.inner)
+    }
+
+    /// Writes a sample and then disposes of its instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the sample does not match the
+    /// type of the topic or the writer fails to write and dispose it.
+    pub fn write_dispose(&self, sample: &DynamicData) -> Result<()> {
+        ffi::dds_write_dispose_dynamic(self.inner, sample)
+    }
+
+    /// Disposes of the instance identified by the key fields of `key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if `key` is missing any of the key
+    /// fields or the writer fails to dispose of the instance.
+    pub fn dispose(&self, key: &DynamicData) -> Result<()> {
+        ffi::dds_dispose_dynamic(self.inner, key)
+    }
+
+    /// Unregisters the instance identified by the key fields of `key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if `key` is missing any of the key
+    /// fields or the writer fails to unregister the instance.
+    pub fn unregister_instance(&self, key: &DynamicData) -> Result<()> {
+        ffi::dds_unregister_instance_dynamic(self.inner, key)
+    }
+
+    /// Returns the name of the [`DynamicTopic`] this writer is associated
+    /// with.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the topic name could not be
+    /// retrieved.
+    pub fn topic_name(&self) -> Result<String> {
+        ffi::dds_get_topic(self.inner).and_then(ffi::dds_get_name)
+    }
+
+    /// Returns the name of the type of the [`DynamicTopic`] this writer is
+    /// associated with.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the type name could not be
+    /// retrieved.
+    pub fn type_name(&self) -> Result<String> {
+        ffi::dds_get_topic(self.inner).and_then(ffi::dds_get_type_name)
+    }
+}
+
+impl Drop for DynamicWriter<'_, '_, '_> {
+    fn drop(&mut self) {
+        let result = ffi::dds_delete(self.inner);
+        debug_assert!(
+            result.is_ok(),
+            "unable to delete {self:?}: failed with {result:?}"
+        );
+    }
+}
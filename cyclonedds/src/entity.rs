@@ -25,6 +25,112 @@ pub struct EntityId {
     pub(crate) inner: cyclonedds_sys::dds_entity_t,
 }
 
+/// A globally unique identifier for an entity.
+///
+/// Unlike an [`InstanceHandle`] or an [`EntityId`], which are only meaningful
+/// within the local process, a GUID identifies an entity across the whole
+/// domain and is shared with remote participants during discovery.
+///
+/// A GUID consists of a 12 byte prefix, shared by all entities of a
+/// participant, and a 4 byte entity ID unique within that participant. Its
+/// [`Display`](std::fmt::Display) implementation matches the format Cyclone
+/// uses in its traces, i.e. four colon separated 32-bit hexadecimal words.
+///
+/// # Examples
+///
+/// ```
+/// use cyclonedds::entity::Entity;
+/// # use cyclonedds::{Domain, Participant};
+/// # let domain = Domain::default();
+///
+/// let participant = Participant::new(&domain)?;
+/// let guid = participant.guid()?;
+/// println!("participant: {guid}");
+/// # Ok::<_, cyclonedds::Error>(())
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Ord, PartialOrd, Hash)]
+pub struct Guid {
+    pub(crate) inner: [u8; 16],
+}
+
+impl Guid {
+    /// Creates a GUID from its 16 byte representation.
+    #[must_use]
+    pub const fn from_bytes(bytes: [u8; 16]) -> Self {
+        Self { inner: bytes }
+    }
+
+    /// Returns the 16 byte representation of the GUID.
+    #[must_use]
+    pub const fn to_bytes(&self) -> [u8; 16] {
+        self.inner
+    }
+
+    /// Returns the 12 byte prefix of the GUID.
+    ///
+    /// All entities belonging to the same participant share the same prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclonedds::entity::Entity;
+    /// use cyclonedds::{Publisher, Subscriber};
+    /// # use cyclonedds::{Domain, Participant};
+    /// # let domain = Domain::default();
+    /// # let participant = Participant::new(&domain)?;
+    ///
+    /// let publisher = Publisher::new(&participant)?;
+    /// let subscriber = Subscriber::new(&participant)?;
+    /// assert_eq!(publisher.guid()?.prefix(), subscriber.guid()?.prefix());
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    #[must_use]
+    pub const fn prefix(&self) -> [u8; 12] {
+        let [p0, p1, p2, p3, p4, p5, p6, p7, p8, p9, p10, p11, _, _, _, _] = self.inner;
+        [p0, p1, p2, p3, p4, p5, p6, p7, p8, p9, p10, p11]
+    }
+
+    /// Returns the 4 byte entity ID of the GUID.
+    ///
+    /// The entity ID identifies the entity within its participant.
+    #[must_use]
+    pub const fn entity_id(&self) -> [u8; 4] {
+        let [_, _, _, _, _, _, _, _, _, _, _, _, e0, e1, e2, e3] = self.inner;
+        [e0, e1, e2, e3]
+    }
+}
+
+impl std::fmt::Display for Guid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let [
+            b0,
+            b1,
+            b2,
+            b3,
+            b4,
+            b5,
+            b6,
+            b7,
+            b8,
+            b9,
+            b10,
+            b11,
+            b12,
+            b13,
+            b14,
+            b15,
+        ] = self.inner;
+        write!(
+            f,
+            "{:x}:{:x}:{:x}:{:x}",
+            u32::from_be_bytes([b0, b1, b2, b3]),
+            u32::from_be_bytes([b4, b5, b6, b7]),
+            u32::from_be_bytes([b8, b9, b10, b11]),
+            u32::from_be_bytes([b12, b13, b14, b15]),
+        )
+    }
+}
+
 mod private {
     /// Private trait for sealing downstream implementation of the
     /// [`Entity`](super::Entity) trait.
@@ -118,6 +224,43 @@ pub trait Entity: private::Sealed {
         Ok(InstanceHandle { inner })
     }
 
+    /// Returns the [`Guid`] of this entity.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the GUID could not be retrieved
+    /// (e.g. the entity no longer exists or has no GUID, such as a
+    /// [`WaitSet`](crate::WaitSet)).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclonedds::entity::Entity;
+    /// use cyclonedds::{Reader, Topic, Writer};
+    ///
+    /// # #[derive(
+    /// #     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+    /// # )]
+    /// # struct Data {
+    /// #     x: i32,
+    /// # }
+    /// # let domain = cyclonedds::Domain::default();
+    /// # let participant = cyclonedds::Participant::new(&domain)?;
+    /// let topic = Topic::<Data>::new(&participant, "Example")?;
+    /// let reader = Reader::new(&topic)?;
+    /// let writer = Writer::new(&topic)?;
+    ///
+    /// // The GUID of the writer is what the reader sees of it.
+    /// let handle = reader.matched_publications()?[0];
+    /// assert_eq!(reader.matched_publication_data(handle)?.key, writer.guid()?);
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    fn guid(&self) -> Result<Guid> {
+        let entity = self.id();
+        let inner = ffi::dds_get_guid(entity.inner)?;
+        Ok(Guid { inner })
+    }
+
     /// Returns the set of status flags that have changed since they were last
     /// [`read`](crate::Reader::read) or [`taken`](crate::Reader::take).
     ///
@@ -340,6 +483,146 @@ pub trait Entity: private::Sealed {
         let mask = mask.bits();
         ffi::dds_set_status_mask(entity.inner, mask)
     }
+
+    /// Returns the [`EntityId`] of the parent of this entity.
+    ///
+    /// The parent of a [`Participant`](crate::Participant) is the (implicit)
+    /// domain entity it was created in.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the parent of the entity could not
+    /// be retrieved (e.g. the entity no longer exists).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclonedds::entity::Entity;
+    /// use cyclonedds::{Publisher, Topic, Writer};
+    ///
+    /// # #[derive(
+    /// #     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+    /// # )]
+    /// # struct Data {
+    /// #     x: i32,
+    /// # }
+    /// # let domain = cyclonedds::Domain::default();
+    /// # let participant = cyclonedds::Participant::new(&domain)?;
+    /// let topic = Topic::<Data>::new(&participant, "Example")?;
+    /// let publisher = Publisher::new(&participant)?;
+    /// let writer = Writer::builder(&topic).with_publisher(&publisher).build()?;
+    ///
+    /// assert_eq!(writer.parent()?, publisher.id());
+    /// assert_eq!(publisher.parent()?, participant.id());
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    fn parent(&self) -> Result<EntityId> {
+        let entity = self.id();
+        let inner = ffi::dds_get_parent(entity.inner)?;
+        Ok(EntityId { inner })
+    }
+
+    /// Returns the [`EntityIds`](EntityId) of the children of this entity.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the children of the entity could
+    /// not be retrieved (e.g. the entity no longer exists).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclonedds::entity::Entity;
+    /// use cyclonedds::{Publisher, Subscriber};
+    ///
+    /// # let domain = cyclonedds::Domain::default();
+    /// # let participant = cyclonedds::Participant::new(&domain)?;
+    /// let publisher = Publisher::new(&participant)?;
+    /// let subscriber = Subscriber::new(&participant)?;
+    ///
+    /// let children = participant.children()?;
+    /// assert!(children.contains(&publisher.id()));
+    /// assert!(children.contains(&subscriber.id()));
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    fn children(&self) -> Result<Vec<EntityId>> {
+        let entity = self.id();
+        let children = ffi::dds_get_children(entity.inner)?;
+        Ok(children
+            .into_iter()
+            .map(|inner| EntityId { inner })
+            .collect())
+    }
+
+    /// Returns the [`EntityId`] of the [`Participant`](crate::Participant)
+    /// this entity belongs to.
+    ///
+    /// For a participant this is its own [`EntityId`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the participant of the entity
+    /// could not be retrieved (e.g. the entity no longer exists).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclonedds::entity::Entity;
+    /// use cyclonedds::{Reader, Topic};
+    ///
+    /// # #[derive(
+    /// #     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+    /// # )]
+    /// # struct Data {
+    /// #     x: i32,
+    /// # }
+    /// # let domain = cyclonedds::Domain::default();
+    /// # let participant = cyclonedds::Participant::new(&domain)?;
+    /// let topic = Topic::<Data>::new(&participant, "Example")?;
+    /// let reader = Reader::new(&topic)?;
+    ///
+    /// assert_eq!(reader.participant()?, participant.id());
+    /// assert_eq!(participant.participant()?, participant.id());
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    fn participant(&self) -> Result<EntityId> {
+        let entity = self.id();
+        let inner = ffi::dds_get_participant(entity.inner)?;
+        Ok(EntityId { inner })
+    }
+
+    /// Returns the ID of the [`Domain`](crate::Domain) this entity belongs to.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the domain ID of the entity could
+    /// not be retrieved (e.g. the entity no longer exists).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclonedds::entity::Entity;
+    /// use cyclonedds::{Reader, Topic};
+    ///
+    /// # #[derive(
+    /// #     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+    /// # )]
+    /// # struct Data {
+    /// #     x: i32,
+    /// # }
+    /// # let domain = cyclonedds::Domain::default();
+    /// # let participant = cyclonedds::Participant::new(&domain)?;
+    /// let topic = Topic::<Data>::new(&participant, "Example")?;
+    /// let reader = Reader::new(&topic)?;
+    ///
+    /// // All entities in a participant share the same domain.
+    /// assert_eq!(reader.domain_id()?, participant.domain_id()?);
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    fn domain_id(&self) -> Result<u32> {
+        let entity = self.id();
+        ffi::dds_get_domainid(entity.inner)
+    }
 }
 
 macro_rules! impl_entity {
@@ -372,7 +655,12 @@ impl_entity!(crate::Writer<'_, '_, '_, T> where T: crate::Topicable);
 impl_entity!(crate::ReadCondition<'_, '_, '_, '_, T> where T: crate::Topicable);
 impl_entity!(crate::QueryCondition<'_, '_, '_, '_, T, F> where T: crate::Topicable, F: Fn(&T) -> bool);
 impl_entity!(crate::GuardCondition<'_>);
+impl_entity!(crate::StatusCondition<'_>);
 impl_entity!(crate::WaitSet<'_, '_, '_, A> where A);
+impl_entity!(crate::builtin::BuiltinReader<'_, '_, T> where T: crate::builtin::BuiltinTopic);
+impl_entity!(crate::dynamic::DynamicTopic<'_, '_>);
+impl_entity!(crate::dynamic::DynamicReader<'_, '_, '_>);
+impl_entity!(crate::dynamic::DynamicWriter<'_, '_, '_>);
 
 #[cfg(test)]
 mod tests {
@@ -497,4 +785,124 @@ mod tests {
             .unwrap();
         assert!(status.is_empty());
     }
+
+    #[test]
+    fn test_entity_hierarchy() {
+        let domain_id = crate::tests::domain::unique_id();
+        let domain = crate::Domain::new(domain_id).unwrap();
+        let participant = crate::Participant::new(&domain).unwrap();
+        let topic_name = crate::tests::topic::unique_name();
+        let topic =
+            crate::Topic::<crate::tests::topic::Data>::new(&participant, &topic_name).unwrap();
+        let subscriber = crate::Subscriber::new(&participant).unwrap();
+        let reader = crate::Reader::builder(&topic)
+            .with_subscriber(&subscriber)
+            .build()
+            .unwrap();
+
+        assert_eq!(reader.parent().unwrap(), subscriber.id());
+        assert_eq!(subscriber.parent().unwrap(), participant.id());
+        assert_eq!(topic.parent().unwrap(), participant.id());
+        assert_eq!(
+            participant.parent().unwrap(),
+            EntityId {
+                inner: domain.inner
+            }
+        );
+
+        assert_eq!(subscriber.children().unwrap(), vec![reader.id()]);
+        let children = participant.children().unwrap();
+        assert!(children.contains(&topic.id()));
+        assert!(children.contains(&subscriber.id()));
+        assert!(reader.children().unwrap().is_empty());
+
+        assert_eq!(reader.participant().unwrap(), participant.id());
+        assert_eq!(topic.participant().unwrap(), participant.id());
+        assert_eq!(participant.participant().unwrap(), participant.id());
+
+        assert_eq!(participant.domain_id().unwrap(), domain_id);
+        assert_eq!(reader.domain_id().unwrap(), domain_id);
+    }
+
+    #[test]
+    fn test_entity_hierarchy_on_invalid_participant() {
+        let domain_id = crate::tests::domain::unique_id();
+        let domain = crate::Domain::new(domain_id).unwrap();
+        let mut participant = crate::Participant::new(&domain).unwrap();
+        let participant_id = participant.inner;
+        participant.inner = 0;
+
+        assert_eq!(
+            crate::Error::BadParameter,
+            participant.parent().unwrap_err()
+        );
+        assert_eq!(
+            crate::Error::BadParameter,
+            participant.children().unwrap_err()
+        );
+        assert_eq!(
+            crate::Error::BadParameter,
+            participant.participant().unwrap_err()
+        );
+        assert_eq!(
+            crate::Error::BadParameter,
+            participant.domain_id().unwrap_err()
+        );
+
+        participant.inner = participant_id;
+    }
+
+    #[test]
+    fn test_guid_display() {
+        let guid = Guid::from_bytes([
+            0x01, 0x10, 0x1a, 0x3c, 0x5d, 0x7e, 0x0b, 0x4e, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00,
+            0x01, 0xc1,
+        ]);
+        assert_eq!(guid.to_string(), "1101a3c:5d7e0b4e:f:1c1");
+        assert_eq!(
+            guid.prefix(),
+            [
+                0x01, 0x10, 0x1a, 0x3c, 0x5d, 0x7e, 0x0b, 0x4e, 0x00, 0x00, 0x00, 0x0f
+            ]
+        );
+        assert_eq!(guid.entity_id(), [0x00, 0x00, 0x01, 0xc1]);
+        assert_eq!(Guid::from_bytes(guid.to_bytes()), guid);
+    }
+
+    #[test]
+    fn test_entity_guid() {
+        let domain_id = crate::tests::domain::unique_id();
+        let domain = crate::Domain::new(domain_id).unwrap();
+        let participant = crate::Participant::new(&domain).unwrap();
+        let topic_name = crate::tests::topic::unique_name();
+        let topic =
+            crate::Topic::<crate::tests::topic::Data>::new(&participant, &topic_name).unwrap();
+        let reader = crate::Reader::new(&topic).unwrap();
+        let writer = crate::Writer::new(&topic).unwrap();
+
+        let participant_guid = participant.guid().unwrap();
+        let reader_guid = reader.guid().unwrap();
+        let writer_guid = writer.guid().unwrap();
+        assert_ne!(reader_guid, writer_guid);
+        assert_eq!(reader_guid.prefix(), participant_guid.prefix());
+        assert_eq!(writer_guid.prefix(), participant_guid.prefix());
+
+        let handle = writer.instance_handle().unwrap();
+        let publication = reader.matched_publication_data(handle).unwrap();
+        assert_eq!(publication.key, writer_guid);
+        assert_eq!(publication.participant_key, participant_guid);
+    }
+
+    #[test]
+    fn test_entity_guid_on_invalid_participant() {
+        let domain_id = crate::tests::domain::unique_id();
+        let domain = crate::Domain::new(domain_id).unwrap();
+        let mut participant = crate::Participant::new(&domain).unwrap();
+        let participant_id = participant.inner;
+        participant.inner = 0;
+
+        assert_eq!(crate::Error::BadParameter, participant.guid().unwrap_err());
+
+        participant.inner = participant_id;
+    }
 }
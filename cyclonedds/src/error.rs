@@ -4,6 +4,10 @@
 pub type Result<T, E = Error> = std::result::Result<T, E>;
 
 /// Errors that can occur during DDS operations.
+///
+/// Most variants mirror the DDS return codes. Failures that originate in the
+/// Rust layer, such as a sample that cannot be CDR-encoded, additionally record
+/// an [`ErrorContext`] describing the operation that failed.
 #[non_exhaustive]
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
 pub enum Error {
@@ -33,6 +37,11 @@ pub enum Error {
     IllegalOperation,
     /// When credentials are insufficient to use the function.
     NotAllowedBySecurity,
+    /// When a sample could not be serialized to its CDR encoding.
+    ///
+    /// The operation that failed and the underlying encoding error are
+    /// available through [`ErrorContext::last`].
+    Serialization,
 }
 
 impl std::fmt::Display for Error {
@@ -54,6 +63,7 @@ impl std::fmt::Display for Error {
                 Error::NoData => "data not provided",
                 Error::IllegalOperation => "operation is illegal",
                 Error::NotAllowedBySecurity => "insufficient credentials",
+                Error::Serialization => "unable to serialize sample",
             }
         )
     }
@@ -61,6 +71,134 @@ impl std::fmt::Display for Error {
 
 impl std::error::Error for Error {}
 
+thread_local! {
+    /// The context of the last [`Error::Serialization`] returned on this
+    /// thread.
+    static LAST_CONTEXT: std::cell::RefCell<Option<ErrorContext>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+/// Describes the operation during which an [`Error::Serialization`] occurred.
+///
+/// The context is kept out of band so that [`Error`] stays [`Copy`]: it is
+/// recorded per thread and can be retrieved with [`ErrorContext::last`] after
+/// an operation returned [`Error::Serialization`].
+///
+/// # Examples
+///
+/// ```
+/// # use cyclonedds::{Domain, Participant, Topic, Writer};
+/// # let domain = Domain::default();
+/// # let participant = Participant::new(&domain)?;
+/// # #[derive(Clone, Debug, Default, serde::Deserialize)]
+/// # struct Data;
+/// # impl serde::Serialize for Data {
+/// #     fn serialize<S: serde::Serializer>(&self, _: S) -> Result<S::Ok, S::Error> {
+/// #         Err(serde::ser::Error::custom("not today"))
+/// #     }
+/// # }
+/// # impl cyclonedds::Topicable for Data {
+/// #     type Key = ();
+/// #     fn from_key(_: &()) -> Self { Self }
+/// #     fn as_key(&self) {}
+/// # }
+/// use cyclonedds::{Error, ErrorContext};
+///
+/// let topic = Topic::<Data>::new(&participant, "MyTopic")?;
+/// let writer = Writer::new(&topic)?;
+///
+/// let Err(Error::Serialization) = writer.write(&Data) else {
+///     panic!("the sample should fail to serialize");
+/// };
+/// let context = ErrorContext::last().expect("recorded with the error");
+/// assert_eq!(context.operation(), "write");
+/// assert_eq!(context.topic_name(), Some("MyTopic"));
+/// # Ok::<_, cyclonedds::Error>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct ErrorContext {
+    operation: &'static str,
+    entity: crate::entity::EntityId,
+    topic_name: Option<String>,
+    location: &'static std::panic::Location<'static>,
+    source: std::sync::Arc<cdr_encoding::Error>,
+}
+
+impl ErrorContext {
+    pub(crate) fn new(
+        operation: &'static str,
+        entity: cyclonedds_sys::dds_entity_t,
+        topic_name: Option<String>,
+        location: &'static std::panic::Location<'static>,
+        source: cdr_encoding::Error,
+    ) -> Self {
+        Self {
+            operation,
+            entity: crate::entity::EntityId { inner: entity },
+            topic_name,
+            location,
+            source: std::sync::Arc::new(source),
+        }
+    }
+
+    /// Records this context for [`ErrorContext::last`] and returns the
+    /// [`Error::Serialization`] it describes.
+    pub(crate) fn into_error(self) -> Error {
+        LAST_CONTEXT.set(Some(self));
+        Error::Serialization
+    }
+
+    /// Returns the context of the last [`Error::Serialization`] returned on
+    /// the calling thread, or `None` if there has not been one.
+    ///
+    /// The context is replaced by every later [`Error::Serialization`] on the
+    /// same thread.
+    #[must_use]
+    pub fn last() -> Option<Self> {
+        LAST_CONTEXT.with_borrow(Clone::clone)
+    }
+
+    /// Returns the name of the operation that failed (e.g. `"write"`).
+    #[must_use]
+    pub const fn operation(&self) -> &'static str {
+        self.operation
+    }
+
+    /// Returns the entity the operation was performed on.
+    #[must_use]
+    pub const fn entity(&self) -> crate::entity::EntityId {
+        self.entity
+    }
+
+    /// Returns the name of the topic involved, if it could be determined.
+    #[must_use]
+    pub fn topic_name(&self) -> Option<&str> {
+        self.topic_name.as_deref()
+    }
+
+    /// Returns the location in the calling code of the failed operation.
+    #[must_use]
+    pub const fn location(&self) -> &'static std::panic::Location<'static> {
+        self.location
+    }
+}
+
+impl std::fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} on entity {}", self.operation, self.entity.inner)?;
+        if let Some(topic_name) = &self.topic_name {
+            write!(f, " (topic \"{topic_name}\")")?;
+        }
+        write!(f, " at {}", self.location)
+    }
+}
+
+impl std::error::Error for ErrorContext {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(self.source.as_ref())
+    }
+}
+
 /// Conversion trait for mapping status codes to the Result type.
 pub(crate) trait IntoError<T>: Sized {
     type Error;
@@ -186,6 +324,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_serialization_error() {
+        let location = std::panic::Location::caller();
+        let error = ErrorContext::new(
+            "write",
+            1,
+            Some(String::from("Example")),
+            location,
+            cdr_encoding::Error::Message(String::from("unsupported")),
+        )
+        .into_error();
+
+        assert_eq!(error, Error::Serialization);
+        assert_eq!(format!("{error}"), "unable to serialize sample");
+
+        let context = ErrorContext::last().unwrap();
+        assert_eq!(
+            format!("{context}"),
+            format!("write on entity 1 (topic \"Example\") at {location}")
+        );
+        let source = std::error::Error::source(&context).unwrap();
+        assert!(source.to_string().contains("unsupported"));
+
+        // The context is recorded per thread.
+        let other = std::thread::spawn(ErrorContext::last).join().unwrap();
+        assert!(other.is_none());
+    }
+
     #[test]
     #[should_panic = "internal error: entered unreachable code"]
     fn test_out_of_bounds_error_panics() {
@@ -11,7 +11,7 @@ use crate::internal::ffi;
 #[derive(Debug)]
 pub struct GuardCondition<'owner> {
     pub(crate) inner: cyclonedds_sys::dds_entity_t,
-    phantom: std::marker::PhantomData<&'owner dyn Entity>,
+    phantom: std::marker::PhantomData<&'owner (dyn Entity + Sync)>,
 }
 
 impl<'o> GuardCondition<'o> {
@@ -49,6 +49,9 @@ impl<'o> GuardCondition<'o> {
     /// Setting to `true` unblocks any [`WaitSet`](crate::WaitSet) this
     /// condition is attached to. Setting to `false` resets it.
     ///
+    /// This only needs a shared reference so that the condition can be
+    /// triggered from another thread while it is attached to a waitset.
+    ///
     /// # Errors
     ///
     /// Returns an [`Error`](crate::Error) if the condition failed to set.
@@ -61,11 +64,11 @@ impl<'o> GuardCondition<'o> {
     /// # let participant = Participant::new(&domain)?;
     /// use cyclonedds::GuardCondition;
     ///
-    /// let mut guard_condition = GuardCondition::new(&participant)?;
+    /// let guard_condition = GuardCondition::new(&participant)?;
     /// guard_condition.set(true)?;
     /// # Ok::<_, cyclonedds::Error>(())
     /// ```
-    pub fn set(&mut self, triggered: bool) -> Result<()> {
+    pub fn set(&self, triggered: bool) -> Result<()> {
         ffi::dds_set_guardcondition(self.inner, triggered)
     }
 
@@ -85,7 +88,7 @@ impl<'o> GuardCondition<'o> {
     /// # let participant = Participant::new(&domain)?;
     /// use cyclonedds::GuardCondition;
     ///
-    /// let mut guard = GuardCondition::new(&participant)?;
+    /// let guard = GuardCondition::new(&participant)?;
     /// guard.set(true)?;
     /// assert_eq!(guard.peek()?, true);
     /// # Ok::<_, cyclonedds::Error>(())
@@ -108,7 +111,7 @@ impl<'o> GuardCondition<'o> {
     /// # let participant = Participant::new(&domain)?;
     /// use cyclonedds::GuardCondition;
     ///
-    /// let mut guard = GuardCondition::new(&participant)?;
+    /// let guard = GuardCondition::new(&participant)?;
     /// guard.set(true)?;
     /// assert_eq!(guard.read()?, true);
     /// // State is preserved after read.
@@ -189,7 +192,7 @@ mod tests {
         let domain_id = crate::tests::domain::unique_id();
         let domain = crate::Domain::new(domain_id).unwrap();
         let participant = crate::Participant::new(&domain).unwrap();
-        let mut guard_condition = GuardCondition::new(&participant).unwrap();
+        let guard_condition = GuardCondition::new(&participant).unwrap();
 
         guard_condition.set(true).unwrap();
         guard_condition.set(false).unwrap();
@@ -200,7 +203,7 @@ mod tests {
         let domain_id = crate::tests::domain::unique_id();
         let domain = crate::Domain::new(domain_id).unwrap();
         let participant = crate::Participant::new(&domain).unwrap();
-        let mut guard_condition = GuardCondition::new(&participant).unwrap();
+        let guard_condition = GuardCondition::new(&participant).unwrap();
 
         guard_condition.set(true).unwrap();
         let triggered = guard_condition.peek().unwrap();
@@ -220,7 +223,7 @@ mod tests {
         let domain_id = crate::tests::domain::unique_id();
         let domain = crate::Domain::new(domain_id).unwrap();
         let participant = crate::Participant::new(&domain).unwrap();
-        let mut guard_condition = GuardCondition::new(&participant).unwrap();
+        let guard_condition = GuardCondition::new(&participant).unwrap();
 
         guard_condition.set(true).unwrap();
         let triggered = guard_condition.read().unwrap();
@@ -17,6 +17,26 @@
 //!
 //! **Use at your own risk.**
 
+/// Reports an internal diagnostic at the given `log` level.
+///
+/// With the `log` feature the message is emitted through the [`log`](https://docs.rs/log)
+/// crate under the `cyclonedds` target; otherwise it is discarded, as some of
+/// these (e.g. undeserializable samples) can be triggered by remote peers and
+/// the library should not write to standard error on their behalf.
+macro_rules! diagnostic {
+    ($level:ident, $($arg:tt)+) => {{
+        #[cfg(feature = "log")]
+        ::log::$level!(target: "cyclonedds", $($arg)+);
+        // NOTE: the message is never formatted, this only keeps the arguments
+        // used so that both configurations produce the same warnings.
+        #[cfg(not(feature = "log"))]
+        let _ = || ::std::format!($($arg)+);
+    }};
+}
+
+pub(crate) mod cdr;
+pub mod dynamic_serdata;
+pub mod dynamic_sertype;
 pub mod ffi;
 pub mod key_hash;
 pub mod serdata;
@@ -0,0 +1,1112 @@
+//! A serde (de)serializer for CDR, used for the samples of
+//! [`Topicable`](crate::Topicable) types that set
+//! [`OPTIONAL_MEMBERS`](crate::Topicable::OPTIONAL_MEMBERS) and for any XCDR2
+//! payload that is received.
+//!
+//! It is a front-end to the [`Encoder`] and [`Decoder`] of the
+//! [`dynamic`](crate::dynamic) codec. In XCDR1 values are laid out the same
+//! way as by `cdr_encoding`, with a `char` as a `u32` code point and an
+//! [`Option`] as a `u32` discriminant followed by the value when present. In
+//! XCDR2 values are laid out the way they are for final IDL types:
+//!
+//! - Primitives are aligned to at most 4 bytes.
+//! - An [`Option`] struct member is an `@optional` member: a `bool` presence
+//!   flag followed by the value when present. An [`Option`] anywhere else (e.g.
+//!   in a `Vec<Option<T>>`) fails to serialize.
+//! - Sequences of non-primitive elements are preceded by a `u32` DHEADER that
+//!   holds the length of the rest of their serialization.
+//!
+//! Serde does not tell a (de)serializer what a sequence holds, so whether its
+//! elements are primitive is decided by their Rust type name: only `bool`,
+//! `char`, and the integer and floating point types are. A sequence of enums,
+//! which IDL considers primitive, or of newtypes around primitives, so gets a
+//! DHEADER. Fixed-size arrays are serialized by serde as tuples, and so never
+//! get a DHEADER, unlike IDL arrays of non-primitive elements. Maps never get
+//! one either.
+//!
+//! As XCDR2 optional members are not identified by a member ID, the members of
+//! a struct are only told apart by their order. Fields skipped with
+//! `#[serde(skip)]` are thus simply not part of the type, while fields skipped
+//! with `#[serde(skip_serializing_if = "...")]` fail to serialize, as they would
+//! otherwise be misread as the next member.
+
+use serde::de::IntoDeserializer;
+use serde::{de, ser};
+
+use crate::dynamic::cdr::{Buffer, CodecError, Decoder, Encoder, Endianness, Version};
+
+type Error = cdr_encoding::Error;
+type Result<T> = std::result::Result<T, Error>;
+
+/// Serializes `value` into `buffer`, aligning relative to its current end.
+pub(crate) fn to_writer<T, B>(
+    buffer: &mut B,
+    value: &T,
+    endianness: Endianness,
+    version: Version,
+) -> Result<()>
+where
+    T: ser::Serialize + ?Sized,
+    B: Buffer,
+{
+    value.serialize(&mut Serializer {
+        encoder: Encoder::new(buffer, endianness, version),
+        member: false,
+    })
+}
+
+/// Deserializes a `T` from `bytes`, returning it along with the number of
+/// bytes that were consumed.
+pub(crate) fn from_bytes<'de, T>(
+    bytes: &'de [u8],
+    endianness: Endianness,
+    version: Version,
+) -> Result<(T, usize)>
+where
+    T: de::Deserialize<'de>,
+{
+    let mut deserializer = Deserializer {
+        decoder: Decoder::new(bytes, endianness, version),
+        member: false,
+    };
+    let value = T::deserialize(&mut deserializer)?;
+    Ok((value, deserializer.decoder.position()))
+}
+
+impl From<CodecError> for Error {
+    fn from(error: CodecError) -> Self {
+        Error::Message(error.to_string())
+    }
+}
+
+fn error(message: impl std::fmt::Display) -> Error {
+    Error::Message(message.to_string())
+}
+
+fn not_a_member() -> Error {
+    error("an `Option` can only be an optional member of a struct in XCDR2")
+}
+
+/// Whether `T` is a primitive, the elements of which are not preceded by a
+/// DHEADER in XCDR2 sequences.
+fn is_primitive<T>() -> bool
+where
+    T: ?Sized,
+{
+    matches!(
+        std::any::type_name::<T>().trim_start_matches('&'),
+        "bool"
+            | "char"
+            | "i8"
+            | "u8"
+            | "i16"
+            | "u16"
+            | "i32"
+            | "u32"
+            | "i64"
+            | "u64"
+            | "f32"
+            | "f64"
+    )
+}
+
+struct Serializer<'a, B> {
+    encoder: Encoder<&'a mut B>,
+    /// Whether the value being serialized is a struct member.
+    member: bool,
+}
+
+macro_rules! serialize_primitive {
+    ($($name:ident: $t:ty => $encode:ident),* $(,)?) => {
+        $(
+            fn $name(self, value: $t) -> Result<()> {
+                self.member = false;
+                self.encoder.$encode(value)?;
+                Ok(())
+            }
+        )*
+    };
+}
+
+impl<B> Serializer<'_, B>
+where
+    B: Buffer,
+{
+    fn serialize_variant(&mut self, variant_index: u32) -> Result<()> {
+        self.member = false;
+        self.encoder.encode_u32(variant_index)?;
+        Ok(())
+    }
+
+    /// Encodes whether an [`Option`] is present.
+    fn serialize_presence(&mut self, present: bool) -> Result<()> {
+        let member = std::mem::take(&mut self.member);
+        match self.encoder.version() {
+            Version::Xcdr1 => self.encoder.encode_u32(u32::from(present))?,
+            Version::Xcdr2 if member => self.encoder.encode_u8(u8::from(present))?,
+            Version::Xcdr2 => return Err(not_a_member()),
+        }
+        Ok(())
+    }
+}
+
+impl<'a, 'b, B> ser::Serializer for &'a mut Serializer<'b, B>
+where
+    B: Buffer,
+{
+    type Ok = ();
+    type Error = Error;
+
+    type SerializeSeq = Compound<'a, 'b, B>;
+    type SerializeTuple = Compound<'a, 'b, B>;
+    type SerializeTupleStruct = Compound<'a, 'b, B>;
+    type SerializeTupleVariant = Compound<'a, 'b, B>;
+    type SerializeMap = Compound<'a, 'b, B>;
+    type SerializeStruct = Compound<'a, 'b, B>;
+    type SerializeStructVariant = Compound<'a, 'b, B>;
+
+    serialize_primitive! {
+        serialize_i8: i8 => encode_i8,
+        serialize_u8: u8 => encode_u8,
+        serialize_i16: i16 => encode_i16,
+        serialize_u16: u16 => encode_u16,
+        serialize_i32: i32 => encode_i32,
+        serialize_u32: u32 => encode_u32,
+        serialize_i64: i64 => encode_i64,
+        serialize_u64: u64 => encode_u64,
+        serialize_f32: f32 => encode_f32,
+        serialize_f64: f64 => encode_f64,
+    }
+
+    fn serialize_bool(self, value: bool) -> Result<()> {
+        self.serialize_u8(u8::from(value))
+    }
+
+    fn serialize_char(self, value: char) -> Result<()> {
+        self.serialize_u32(u32::from(value))
+    }
+
+    fn serialize_str(self, value: &str) -> Result<()> {
+        self.member = false;
+        self.encoder.encode_str(value)?;
+        Ok(())
+    }
+
+    fn serialize_bytes(self, value: &[u8]) -> Result<()> {
+        self.member = false;
+        self.encoder.encode_length(value.len())?;
+        self.encoder.encode_bytes(value)?;
+        Ok(())
+    }
+
+    fn serialize_none(self) -> Result<()> {
+        self.serialize_presence(false)
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<()>
+    where
+        T: ser::Serialize + ?Sized,
+    {
+        self.serialize_presence(true)?;
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<()> {
+        self.member = false;
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<()> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<()> {
+        self.serialize_variant(variant_index)
+    }
+
+    fn serialize_newtype_struct<T>(self, _name: &'static str, value: &T) -> Result<()>
+    where
+        T: ser::Serialize + ?Sized,
+    {
+        self.member = false;
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        value: &T,
+    ) -> Result<()>
+    where
+        T: ser::Serialize + ?Sized,
+    {
+        self.serialize_variant(variant_index)?;
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, length: Option<usize>) -> Result<Self::SerializeSeq> {
+        self.member = false;
+        let length = length.ok_or_else(|| error("sequences must have a known length"))?;
+        Ok(Compound::new(self, Some(length)))
+    }
+
+    // NOTE: unlike `serialize_seq`, this knows the type of the elements even
+    // when there are none, and so whether an empty sequence has a DHEADER.
+    fn collect_seq<I>(self, iter: I) -> Result<()>
+    where
+        I: IntoIterator,
+        I::Item: ser::Serialize,
+    {
+        let iter = iter.into_iter();
+        let length = match iter.size_hint() {
+            (lower, Some(upper)) if lower == upper => Some(lower),
+            _ => None,
+        };
+        let mut sequence = self.serialize_seq(length)?;
+        sequence.begin_elements(is_primitive::<I::Item>())?;
+        for element in iter {
+            ser::SerializeSeq::serialize_element(&mut sequence, &element)?;
+        }
+        ser::SerializeSeq::end(sequence)
+    }
+
+    fn serialize_tuple(self, _length: usize) -> Result<Self::SerializeTuple> {
+        self.member = false;
+        Ok(Compound::new(self, None))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        length: usize,
+    ) -> Result<Self::SerializeTupleStruct> {
+        self.serialize_tuple(length)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        _length: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        self.serialize_variant(variant_index)?;
+        Ok(Compound::new(self, None))
+    }
+
+    fn serialize_map(self, length: Option<usize>) -> Result<Self::SerializeMap> {
+        self.serialize_seq(length)
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _length: usize,
+    ) -> Result<Self::SerializeStruct> {
+        self.member = false;
+        Ok(Compound::new(self, None))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        _length: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        self.serialize_variant(variant_index)?;
+        Ok(Compound::new(self, None))
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+}
+
+/// The serializer for the elements of sequences, tuples, maps and the
+/// members of structs.
+struct Compound<'a, 'b, B> {
+    serializer: &'a mut Serializer<'b, B>,
+    /// The length of a sequence or map, until it is encoded ahead of the first
+    /// element.
+    length: Option<usize>,
+    /// Where the DHEADER of a sequence is to be filled in.
+    dheader: Option<usize>,
+}
+
+impl<'a, 'b, B> Compound<'a, 'b, B>
+where
+    B: Buffer,
+{
+    const fn new(serializer: &'a mut Serializer<'b, B>, length: Option<usize>) -> Self {
+        Self {
+            serializer,
+            length,
+            dheader: None,
+        }
+    }
+
+    /// Encodes the DHEADER, if the elements need one, and the length of a
+    /// sequence whose elements are about to be serialized.
+    fn begin_elements(&mut self, primitive: bool) -> Result<()> {
+        if let Some(length) = self.length.take() {
+            let encoder = &mut self.serializer.encoder;
+            self.dheader = encoder.begin_dheader(primitive)?;
+            encoder.encode_length(length)?;
+        }
+        Ok(())
+    }
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: ser::Serialize + ?Sized,
+    {
+        self.begin_elements(is_primitive::<T>())?;
+        value.serialize(&mut *self.serializer)
+    }
+
+    fn serialize_member<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: ser::Serialize + ?Sized,
+    {
+        self.serializer.member = true;
+        let result = value.serialize(&mut *self.serializer);
+        self.serializer.member = false;
+        result
+    }
+
+    fn end(mut self) -> Result<()> {
+        self.begin_elements(true)?;
+        self.serializer.encoder.end_dheader(self.dheader)?;
+        Ok(())
+    }
+}
+
+macro_rules! impl_serialize_elements {
+    ($($trait:ident::$method:ident => $serialize:ident),* $(,)?) => {
+        $(
+            impl<B> ser::$trait for Compound<'_, '_, B>
+            where
+                B: Buffer,
+            {
+                type Ok = ();
+                type Error = Error;
+
+                fn $method<T>(&mut self, value: &T) -> Result<()>
+                where
+                    T: ser::Serialize + ?Sized,
+                {
+                    self.$serialize(value)
+                }
+
+                fn end(self) -> Result<()> {
+                    Compound::end(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_serialize_elements! {
+    SerializeSeq::serialize_element => serialize_element,
+    SerializeTuple::serialize_element => serialize_element,
+    SerializeTupleStruct::serialize_field => serialize_element,
+    SerializeTupleVariant::serialize_field => serialize_element,
+}
+
+impl<B> ser::SerializeMap for Compound<'_, '_, B>
+where
+    B: Buffer,
+{
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<()>
+    where
+        T: ser::Serialize + ?Sized,
+    {
+        // Maps have no DHEADER, whatever their keys and values are.
+        self.begin_elements(true)?;
+        key.serialize(&mut *self.serializer)
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: ser::Serialize + ?Sized,
+    {
+        value.serialize(&mut *self.serializer)
+    }
+
+    fn end(self) -> Result<()> {
+        Compound::end(self)
+    }
+}
+
+macro_rules! impl_serialize_members {
+    ($($trait:ident),* $(,)?) => {
+        $(
+            impl<B> ser::$trait for Compound<'_, '_, B>
+            where
+                B: Buffer,
+            {
+                type Ok = ();
+                type Error = Error;
+
+                fn serialize_field<T>(&mut self, _key: &'static str, value: &T) -> Result<()>
+                where
+                    T: ser::Serialize + ?Sized,
+                {
+                    self.serialize_member(value)
+                }
+
+                fn skip_field(&mut self, key: &'static str) -> Result<()> {
+                    Err(error(format_args!(
+                        "the member `{key}` cannot be skipped, as CDR has no way to tell it is \
+                         missing"
+                    )))
+                }
+
+                fn end(self) -> Result<()> {
+                    Compound::end(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_serialize_members! {
+    SerializeStruct,
+    SerializeStructVariant,
+}
+
+struct Deserializer<'de> {
+    decoder: Decoder<'de>,
+    /// Whether the value being deserialized is a struct member.
+    member: bool,
+}
+
+macro_rules! deserialize_primitive {
+    ($($name:ident: $decode:ident => $visit:ident),* $(,)?) => {
+        $(
+            fn $name<V>(self, visitor: V) -> Result<V::Value>
+            where
+                V: de::Visitor<'de>,
+            {
+                self.member = false;
+                visitor.$visit(self.decoder.$decode()?)
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> {
+    fn decode_char(&mut self) -> Result<char> {
+        let value = self.decoder.decode_u32()?;
+        char::from_u32(value).ok_or_else(|| error(format_args!("invalid char {value:#x}")))
+    }
+
+    fn decode_str(&mut self) -> Result<&'de str> {
+        Ok(self.decoder.decode_str(None)?)
+    }
+
+    fn decode_bytes(&mut self) -> Result<&'de [u8]> {
+        let length = self.decoder.decode_length(None)?;
+        Ok(self.decoder.take(length)?)
+    }
+
+    /// Decodes whether an [`Option`] is present.
+    fn decode_presence(&mut self) -> Result<bool> {
+        let member = std::mem::take(&mut self.member);
+        match self.decoder.version() {
+            Version::Xcdr1 => match self.decoder.decode_u32()? {
+                0 => Ok(false),
+                1 => Ok(true),
+                value => Err(error(format_args!("invalid `Option` discriminant {value}"))),
+            },
+            Version::Xcdr2 if member => Ok(self.decoder.decode_bool()?),
+            Version::Xcdr2 => Err(not_a_member()),
+        }
+    }
+
+    const fn access(&mut self, length: Option<usize>, members: bool) -> Access<'_, 'de> {
+        Access {
+            deserializer: self,
+            remaining: length,
+            members,
+        }
+    }
+}
+
+impl<'de> de::Deserializer<'de> for &mut Deserializer<'de> {
+    type Error = Error;
+
+    deserialize_primitive! {
+        deserialize_bool: decode_bool => visit_bool,
+        deserialize_i8: decode_i8 => visit_i8,
+        deserialize_u8: decode_u8 => visit_u8,
+        deserialize_i16: decode_i16 => visit_i16,
+        deserialize_u16: decode_u16 => visit_u16,
+        deserialize_i32: decode_i32 => visit_i32,
+        deserialize_u32: decode_u32 => visit_u32,
+        deserialize_i64: decode_i64 => visit_i64,
+        deserialize_u64: decode_u64 => visit_u64,
+        deserialize_f32: decode_f32 => visit_f32,
+        deserialize_f64: decode_f64 => visit_f64,
+        deserialize_identifier: decode_u32 => visit_u32,
+    }
+
+    fn deserialize_char<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: de::Visitor<'de>,
+    {
+        self.member = false;
+        visitor.visit_char(self.decode_char()?)
+    }
+
+    fn deserialize_str<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: de::Visitor<'de>,
+    {
+        self.member = false;
+        visitor.visit_borrowed_str(self.decode_str()?)
+    }
+
+    fn deserialize_string<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: de::Visitor<'de>,
+    {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_bytes<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: de::Visitor<'de>,
+    {
+        self.member = false;
+        visitor.visit_borrowed_bytes(self.decode_bytes()?)
+    }
+
+    fn deserialize_byte_buf<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: de::Visitor<'de>,
+    {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_any<V>(self, _visitor: V) -> Result<V::Value>
+    where
+        V: de::Visitor<'de>,
+    {
+        Err(error("CDR is not a self-describing format"))
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: de::Visitor<'de>,
+    {
+        if self.decode_presence()? {
+            visitor.visit_some(self)
+        } else {
+            visitor.visit_none()
+        }
+    }
+
+    fn deserialize_unit<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: de::Visitor<'de>,
+    {
+        self.member = false;
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V>(self, _name: &'static str, visitor: V) -> Result<V::Value>
+    where
+        V: de::Visitor<'de>,
+    {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V>(self, _name: &'static str, visitor: V) -> Result<V::Value>
+    where
+        V: de::Visitor<'de>,
+    {
+        self.member = false;
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: de::Visitor<'de>,
+    {
+        self.member = false;
+        // NOTE: the length, and any DHEADER before it, is decoded once the
+        // type of the elements is known.
+        visitor.visit_seq(self.access(None, false))
+    }
+
+    fn deserialize_tuple<V>(self, length: usize, visitor: V) -> Result<V::Value>
+    where
+        V: de::Visitor<'de>,
+    {
+        self.member = false;
+        visitor.visit_seq(self.access(Some(length), false))
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        _name: &'static str,
+        length: usize,
+        visitor: V,
+    ) -> Result<V::Value>
+    where
+        V: de::Visitor<'de>,
+    {
+        self.deserialize_tuple(length, visitor)
+    }
+
+    fn deserialize_map<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: de::Visitor<'de>,
+    {
+        self.member = false;
+        let length = self.decoder.decode_length(None)?;
+        visitor.visit_map(self.access(Some(length), false))
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value>
+    where
+        V: de::Visitor<'de>,
+    {
+        self.member = false;
+        visitor.visit_seq(self.access(Some(fields.len()), true))
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value>
+    where
+        V: de::Visitor<'de>,
+    {
+        self.member = false;
+        visitor.visit_enum(self)
+    }
+
+    fn deserialize_ignored_any<V>(self, _visitor: V) -> Result<V::Value>
+    where
+        V: de::Visitor<'de>,
+    {
+        Err(error("CDR is not a self-describing format"))
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+}
+
+impl<'de> de::EnumAccess<'de> for &mut Deserializer<'de> {
+    type Error = Error;
+    type Variant = Self;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self)>
+    where
+        V: de::DeserializeSeed<'de>,
+    {
+        let variant_index = self.decoder.decode_u32()?;
+        let value =
+            seed.deserialize(IntoDeserializer::<Error>::into_deserializer(variant_index))?;
+        Ok((value, self))
+    }
+}
+
+impl<'de> de::VariantAccess<'de> for &mut Deserializer<'de> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<()> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value>
+    where
+        T: de::DeserializeSeed<'de>,
+    {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V>(self, length: usize, visitor: V) -> Result<V::Value>
+    where
+        V: de::Visitor<'de>,
+    {
+        visitor.visit_seq(self.access(Some(length), false))
+    }
+
+    fn struct_variant<V>(self, fields: &'static [&'static str], visitor: V) -> Result<V::Value>
+    where
+        V: de::Visitor<'de>,
+    {
+        visitor.visit_seq(self.access(Some(fields.len()), true))
+    }
+}
+
+/// The access to the elements of sequences, tuples, maps and the members of
+/// structs.
+struct Access<'a, 'de> {
+    deserializer: &'a mut Deserializer<'de>,
+    /// The number of elements left, unless this is a sequence whose length is
+    /// yet to be decoded.
+    remaining: Option<usize>,
+    /// Whether the elements are struct members.
+    members: bool,
+}
+
+impl<'de> Access<'_, 'de> {
+    /// The number of elements left, decoding the length of a sequence of `T`
+    /// (and its DHEADER, if it has one) on the first call.
+    fn remaining<T>(&mut self) -> Result<usize> {
+        if let Some(remaining) = self.remaining {
+            return Ok(remaining);
+        }
+        let decoder = &mut self.deserializer.decoder;
+        decoder.decode_dheader(is_primitive::<T>())?;
+        let length = decoder.decode_length(None)?;
+        self.remaining = Some(length);
+        Ok(length)
+    }
+
+    fn next<T>(&mut self, seed: T) -> Result<Option<T::Value>>
+    where
+        T: de::DeserializeSeed<'de>,
+    {
+        let remaining = self.remaining::<T::Value>()?;
+        if remaining == 0 {
+            return Ok(None);
+        }
+        self.remaining = Some(remaining - 1);
+
+        self.deserializer.member = self.members;
+        let result = seed.deserialize(&mut *self.deserializer);
+        self.deserializer.member = false;
+        result.map(Some)
+    }
+}
+
+impl<'de> de::SeqAccess<'de> for Access<'_, 'de> {
+    type Error = Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>>
+    where
+        T: de::DeserializeSeed<'de>,
+    {
+        self.next(seed)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.remaining
+    }
+}
+
+impl<'de> de::MapAccess<'de> for Access<'_, 'de> {
+    type Error = Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>>
+    where
+        K: de::DeserializeSeed<'de>,
+    {
+        self.next(seed)
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value>
+    where
+        V: de::DeserializeSeed<'de>,
+    {
+        seed.deserialize(&mut *self.deserializer)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.remaining
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+    enum Shape {
+        Point,
+        Circle(f64),
+        Rectangle { width: u16, height: u16 },
+    }
+
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Plain {
+        flag: bool,
+        x: u64,
+        small: i16,
+        letter: char,
+        name: String,
+        values: Vec<u16>,
+        array: [u32; 2],
+        shape: Shape,
+        shapes: Vec<Shape>,
+        nested: (u8, f64),
+        present: Option<u32>,
+        absent: Option<f64>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Optional {
+        a: u8,
+        b: Option<u64>,
+        c: Option<u32>,
+        d: u16,
+    }
+
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Reading {
+        id: u8,
+        stamp: i64,
+        tags: Vec<String>,
+        values: Vec<u16>,
+    }
+
+    fn plain() -> Plain {
+        Plain {
+            flag: true,
+            x: 0x0102_0304_0506_0708,
+            small: -3,
+            letter: 'λ',
+            name: "hello".to_string(),
+            values: vec![1, 2, 3],
+            array: [4, 5],
+            shape: Shape::Circle(1.5),
+            shapes: vec![
+                Shape::Point,
+                Shape::Rectangle {
+                    width: 2,
+                    height: 3,
+                },
+            ],
+            nested: (7, 2.5),
+            present: Some(6),
+            absent: None,
+        }
+    }
+
+    fn serialize<T>(value: &T, endianness: Endianness) -> Result<Vec<u8>>
+    where
+        T: ser::Serialize,
+    {
+        let mut serialized = vec![];
+        to_writer(&mut serialized, value, endianness, Version::Xcdr2)?;
+        Ok(serialized)
+    }
+
+    #[test]
+    fn test_xcdr1_matches_cdr_encoding() {
+        for endianness in [Endianness::Big, Endianness::Little] {
+            let mut actual = vec![];
+            to_writer(&mut actual, &plain(), endianness, Version::Xcdr1).unwrap();
+            let expected = match endianness {
+                Endianness::Big => cdr_encoding::to_vec::<_, byteorder::BigEndian>(&plain()),
+                Endianness::Little => cdr_encoding::to_vec::<_, byteorder::LittleEndian>(&plain()),
+            }
+            .unwrap();
+            assert_eq!(actual, expected);
+
+            let (deserialized, length) =
+                from_bytes::<Plain>(&actual, endianness, Version::Xcdr1).unwrap();
+            assert_eq!(deserialized, plain());
+            assert_eq!(length, actual.len());
+        }
+    }
+
+    #[test]
+    fn test_alignment_is_relative_to_the_start() {
+        let mut buffer = vec![0xff; 4];
+        to_writer(&mut buffer, &(1_u8, 2_u32), Endianness::Big, Version::Xcdr1).unwrap();
+        assert_eq!(buffer, [0xff, 0xff, 0xff, 0xff, 1, 0, 0, 0, 0, 0, 0, 2]);
+    }
+
+    #[test]
+    fn test_xcdr2_roundtrip() {
+        for endianness in [Endianness::Big, Endianness::Little] {
+            let serialized = serialize(&plain(), endianness).unwrap();
+            let (deserialized, length) =
+                from_bytes::<Plain>(&serialized, endianness, Version::Xcdr2).unwrap();
+            assert_eq!(deserialized, plain());
+            assert_eq!(length, serialized.len());
+        }
+    }
+
+    #[test]
+    fn test_xcdr2_optional_members() {
+        // The XCDR2 encoding of the IDL type:
+        //
+        //   @final struct Optional {
+        //     octet a;
+        //     @optional uint64 b;
+        //     @optional uint32 c;
+        //     uint16 d;
+        //   };
+        let sample = Optional {
+            a: 1,
+            b: Some(2),
+            c: None,
+            d: 3,
+        };
+        let serialized = serialize(&sample, Endianness::Big).unwrap();
+        #[rustfmt::skip]
+        assert_eq!(
+            serialized,
+            [
+                // a
+                1,
+                // b: present, and aligned to 4 bytes.
+                1, 0, 0,
+                0, 0, 0, 0, 0, 0, 0, 2,
+                // c: absent.
+                0, 0,
+                // d
+                0, 3,
+            ]
+        );
+        let (deserialized, _) =
+            from_bytes::<Optional>(&serialized, Endianness::Big, Version::Xcdr2).unwrap();
+        assert_eq!(deserialized, sample);
+
+        let sample = Optional {
+            a: 1,
+            b: None,
+            c: Some(4),
+            d: 3,
+        };
+        let serialized = serialize(&sample, Endianness::Little).unwrap();
+        assert_eq!(serialized, [1, 0, 1, 0, 4, 0, 0, 0, 3, 0]);
+        let (deserialized, _) =
+            from_bytes::<Optional>(&serialized, Endianness::Little, Version::Xcdr2).unwrap();
+        assert_eq!(deserialized, sample);
+    }
+
+    #[test]
+    fn test_xcdr2_sequences() {
+        // The XCDR2 encoding of the IDL type:
+        //
+        //   @final struct Reading {
+        //     octet id;
+        //     int64 stamp;
+        //     sequence<string> tags;
+        //     sequence<uint16> values;
+        //   };
+        #[rustfmt::skip]
+        let expected = [
+            // id
+            7, 0, 0, 0,
+            // stamp, aligned to 4 bytes.
+            0, 0, 0, 0, 0, 0, 1, 0,
+            // tags: the DHEADER, the length, and the strings.
+            0, 0, 0, 19,
+            0, 0, 0, 2,
+            0, 0, 0, 2, b'a', 0, 0, 0,
+            0, 0, 0, 3, b'b', b'c', 0, 0,
+            // values: no DHEADER for primitive elements.
+            0, 0, 0, 2,
+            0, 1, 0, 2,
+        ];
+        let sample = Reading {
+            id: 7,
+            stamp: 256,
+            tags: vec!["a".to_string(), "bc".to_string()],
+            values: vec![1, 2],
+        };
+        let serialized = serialize(&sample, Endianness::Big).unwrap();
+        assert_eq!(serialized, expected);
+
+        let (deserialized, _) =
+            from_bytes::<Reading>(&expected, Endianness::Big, Version::Xcdr2).unwrap();
+        assert_eq!(deserialized, sample);
+
+        // An empty sequence of non-primitive elements still has a DHEADER.
+        let sample = Reading {
+            tags: vec![],
+            values: vec![],
+            ..sample
+        };
+        let serialized = serialize(&sample, Endianness::Big).unwrap();
+        assert_eq!(
+            serialized.get(12..).unwrap(),
+            [0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0]
+        );
+        let (deserialized, _) =
+            from_bytes::<Reading>(&serialized, Endianness::Big, Version::Xcdr2).unwrap();
+        assert_eq!(deserialized, sample);
+    }
+
+    #[test]
+    fn test_xcdr2_rejects_invalid_data() {
+        // A presence flag that is neither 0 nor 1.
+        let serialized = [1, 2, 0, 0];
+        assert!(from_bytes::<Optional>(&serialized, Endianness::Big, Version::Xcdr2).is_err());
+
+        // Truncated value.
+        let serialized = [1, 1, 0, 0, 0, 0];
+        assert!(from_bytes::<Optional>(&serialized, Endianness::Big, Version::Xcdr2).is_err());
+
+        // A DHEADER longer than the rest of the payload.
+        let serialized = [7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0];
+        assert!(from_bytes::<Reading>(&serialized, Endianness::Big, Version::Xcdr2).is_err());
+    }
+
+    #[test]
+    fn test_option_outside_of_struct() {
+        let error = serialize(&vec![Some(1_u8)], Endianness::Big).unwrap_err();
+        assert!(error.to_string().contains("optional member"));
+
+        let error = serialize(&Some(1_u8), Endianness::Big).unwrap_err();
+        assert!(error.to_string().contains("optional member"));
+
+        let serialized = [0, 0, 0, 4, 0, 0, 0, 1, 0];
+        assert!(
+            from_bytes::<Vec<Option<u8>>>(&serialized, Endianness::Big, Version::Xcdr2).is_err()
+        );
+    }
+
+    #[test]
+    fn test_skipped_members() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Skipped {
+            #[serde(skip)]
+            cache: u32,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            value: Option<u32>,
+        }
+
+        let sample = Skipped {
+            cache: 1,
+            value: Some(2),
+        };
+        let serialized = serialize(&sample, Endianness::Big).unwrap();
+        assert_eq!(serialized, [1, 0, 0, 0, 0, 0, 0, 2]);
+
+        let sample = Skipped {
+            cache: 1,
+            value: None,
+        };
+        let error = serialize(&sample, Endianness::Big).unwrap_err();
+        assert!(error.to_string().contains("`value` cannot be skipped"));
+    }
+}
@@ -0,0 +1,125 @@
+//! The [`DynamicSerdata`] is the runtime-typed counterpart to the
+//! [`Serdata`](crate::internal::serdata::Serdata).
+
+use crate::Result;
+use crate::cdr_bounds::CdrSize;
+use crate::dynamic::DynamicData;
+use crate::dynamic::cdr::{self, Endianness};
+use crate::internal::dynamic_sertype::DynamicSertype;
+use crate::internal::ffi;
+use crate::internal::serdata::Kind;
+use crate::internal::traits::Hash32;
+
+/// The extension point for wrapping [`cyclonedds_sys::ddsi_serdata`] for
+/// runtime-typed topics.
+///
+/// Unlike the typed [`Serdata`](crate::internal::serdata::Serdata) the
+/// serialized form is computed eagerly, since producing it is also what
+/// validates the sample against the [`StructType`](crate::dynamic::StructType).
+#[repr(C)]
+#[derive(Debug)]
+pub struct DynamicSerdata {
+    pub(crate) inner: cyclonedds_sys::ddsi_serdata,
+    kind: Kind,
+    /// The full sample for [`Kind::Data`] or just the key fields for
+    /// [`Kind::Key`].
+    sample: DynamicData,
+    /// The native-endian CDR serialized `sample` including the CDR header.
+    serialized: Vec<u8>,
+    /// The big-endian CDR serialized key fields without a CDR header.
+    key: Vec<u8>,
+    /// The upper bound on the size of `key`, which decides how the keyhash is
+    /// computed.
+    key_max_serialized_cdr_size: CdrSize,
+}
+
+impl DynamicSerdata {
+    /// Create a new [`DynamicSerdata`] from a sample (for [`Kind::Data`]) or
+    /// from a sample or key (for [`Kind::Key`]).
+    pub fn from_sample(sertype: &DynamicSertype, kind: Kind, sample: &DynamicData) -> Result<Self> {
+        let sample = match kind {
+            Kind::Data => sample.clone(),
+            Kind::Key => sertype
+                .struct_type
+                .key_of(sample)
+                .ok_or(crate::Error::BadParameter)?,
+        };
+        let serialized = cdr::serialize(
+            Self::struct_type(sertype, kind),
+            &sample,
+            Endianness::NATIVE,
+        )?;
+
+        Self::new(sertype, kind, sample, serialized)
+    }
+
+    /// Create a new [`DynamicSerdata`] from CDR serialized bytes including
+    /// the CDR header.
+    pub fn from_serialized(
+        sertype: &DynamicSertype,
+        kind: Kind,
+        serialized: Vec<u8>,
+    ) -> Result<Self> {
+        let sample = cdr::deserialize(Self::struct_type(sertype, kind), &serialized)?;
+
+        Self::new(sertype, kind, sample, serialized)
+    }
+
+    fn new(
+        sertype: &DynamicSertype,
+        kind: Kind,
+        sample: DynamicData,
+        serialized: Vec<u8>,
+    ) -> Result<Self> {
+        let key = sertype
+            .struct_type
+            .key_of(&sample)
+            .ok_or(crate::Error::BadParameter)?;
+        let key = cdr::serialize_payload(&sertype.key_type, &key, Endianness::Big)?;
+
+        let mut inner = ffi::ddsi_serdata_new(&sertype.inner, kind.into());
+        inner.hash = key.hash32();
+
+        Ok(Self {
+            inner,
+            kind,
+            sample,
+            serialized,
+            key,
+            key_max_serialized_cdr_size: sertype.key_type.max_serialized_cdr_size(),
+        })
+    }
+
+    fn struct_type(sertype: &DynamicSertype, kind: Kind) -> &crate::dynamic::StructType {
+        match kind {
+            Kind::Data => &sertype.struct_type,
+            Kind::Key => &sertype.key_type,
+        }
+    }
+
+    /// Get the sample (for [`Kind::Data`]) or key (for [`Kind::Key`]) stored
+    /// by the [`DynamicSerdata`].
+    pub const fn sample(&self) -> &DynamicData {
+        &self.sample
+    }
+
+    /// Get the big-endian CDR serialized key.
+    pub fn key(&self) -> &[u8] {
+        &self.key
+    }
+
+    /// Get the upper bound on the size of the serialized key.
+    pub const fn key_max_serialized_cdr_size(&self) -> CdrSize {
+        self.key_max_serialized_cdr_size
+    }
+
+    /// Get the CDR serialized sample including the CDR header.
+    pub fn serialized(&self) -> &[u8] {
+        &self.serialized
+    }
+
+    /// Get the kind associated with this serdata.
+    pub const fn kind(&self) -> Kind {
+        self.kind
+    }
+}
@@ -0,0 +1,96 @@
+//! The [`DynamicSertype`] is the runtime-typed counterpart to the
+//! [`Sertype`](crate::internal::sertype::Sertype).
+//!
+//! Rather than being monomorphized over a [`Topicable`](crate::Topicable) type
+//! it carries a [`StructType`] which drives the (de)serialization of the
+//! [`DynamicData`](crate::dynamic::DynamicData) samples flowing through it.
+
+use std::sync::Arc;
+
+use crate::dynamic::StructType;
+use crate::internal::ffi;
+
+/// The extension point for wrapping [`cyclonedds_sys::ddsi_sertype`] for
+/// runtime-typed topics.
+#[repr(C)]
+#[derive(Debug)]
+pub struct DynamicSertype {
+    pub(crate) inner: cyclonedds_sys::ddsi_sertype,
+    pub(crate) struct_type: Arc<StructType>,
+    pub(crate) key_type: StructType,
+}
+
+impl DynamicSertype {
+    // NOTE: the struct update syntax has been used intentionally even though all
+    // the fields are specified. This is to ensure that this code will still compile
+    // even if the C library adds new ops in the future.
+    #[allow(clippy::needless_update)]
+    pub(crate) const SERTYPE_OPS: cyclonedds_sys::ddsi_sertype_ops =
+        cyclonedds_sys::ddsi_sertype_ops {
+            version: ffi::sertype_ops::SertypeVersion::V0.as_ffi(),
+            arg: ffi::sertype_ops::ARG,
+            free: Some(ffi::dynamic_sertype_ops::free),
+            zero_samples: Some(ffi::dynamic_sertype_ops::zero_samples),
+            realloc_samples: Some(ffi::dynamic_sertype_ops::realloc_samples),
+            free_samples: Some(ffi::dynamic_sertype_ops::free_samples),
+            equal: Some(ffi::dynamic_sertype_ops::equal),
+            hash: Some(ffi::dynamic_sertype_ops::hash),
+            type_id: None,
+            type_map: None,
+            type_info: None,
+            derive_sertype: None,
+            get_serialized_size: Some(ffi::dynamic_sertype_ops::get_serialized_size),
+            serialize_into: Some(ffi::dynamic_sertype_ops::serialize_into),
+            ..ffi::sertype_ops::zeroed_sertype_ops()
+        };
+    // NOTE: the struct update syntax has been used intentionally even though all
+    // the fields are specified. This is to ensure that this code will still compile
+    // even if the C library adds new ops in the future.
+    #[allow(clippy::needless_update)]
+    pub(crate) const SERDATA_OPS: cyclonedds_sys::ddsi_serdata_ops =
+        cyclonedds_sys::ddsi_serdata_ops {
+            eqkey: Some(ffi::dynamic_serdata_ops::eqkey),
+            get_size: Some(ffi::dynamic_serdata_ops::get_size),
+            from_ser: Some(ffi::dynamic_serdata_ops::from_ser),
+            from_ser_iov: Some(ffi::dynamic_serdata_ops::from_ser_iov),
+            from_keyhash: Some(ffi::dynamic_serdata_ops::from_keyhash),
+            from_sample: Some(ffi::dynamic_serdata_ops::from_sample),
+            to_ser: Some(ffi::dynamic_serdata_ops::to_ser),
+            to_ser_ref: Some(ffi::dynamic_serdata_ops::to_ser_ref),
+            to_ser_unref: Some(ffi::dynamic_serdata_ops::to_ser_unref),
+            to_sample: Some(ffi::dynamic_serdata_ops::to_sample),
+            to_untyped: Some(ffi::dynamic_serdata_ops::to_untyped),
+            untyped_to_sample: Some(ffi::dynamic_serdata_ops::untyped_to_sample),
+            free: Some(ffi::dynamic_serdata_ops::free),
+            print: Some(ffi::dynamic_serdata_ops::print),
+            get_keyhash: Some(ffi::dynamic_serdata_ops::get_keyhash),
+            // NOTE: dynamic samples are never loaned so there is no shared
+            // memory representation to construct them from.
+            from_loaned_sample: None,
+            from_psmx: None,
+            ..ffi::serdata_ops::zeroed_serdata_ops()
+        };
+
+    /// Create a new [`DynamicSertype`] for samples of `struct_type`.
+    pub fn new(type_name: &std::ffi::CStr, struct_type: Arc<StructType>) -> Self {
+        let inner = ffi::ddsi_sertype_new(
+            type_name,
+            &Self::SERTYPE_OPS,
+            &Self::SERDATA_OPS,
+            struct_type.is_keyed(),
+        );
+        let key_type = struct_type.key_type();
+
+        DynamicSertype {
+            inner,
+            struct_type,
+            key_type,
+        }
+    }
+}
+
+impl Drop for DynamicSertype {
+    fn drop(&mut self) {
+        ffi::ddsi_sertype_fini(&mut self.inner);
+    }
+}
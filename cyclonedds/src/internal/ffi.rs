@@ -5,6 +5,8 @@
 
 #![allow(unsafe_code)]
 
+pub mod dynamic_serdata_ops;
+pub mod dynamic_sertype_ops;
 mod listener;
 pub mod serdata_ops;
 pub mod sertype_ops;
@@ -34,6 +36,15 @@ where
     Key(T::Key),
 }
 
+/// The runtime-typed counterpart to [`InternalSample`], where whether it is a
+/// sample or a key is decided by the serdata kind it is used with.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum InternalDynamicSample<'a> {
+    None,
+    Ref(&'a crate::dynamic::DynamicData),
+    Owned(crate::dynamic::DynamicData),
+}
+
 pub fn dds_get_instance_handle(
     entity: cyclonedds_sys::dds_entity_t,
 ) -> Result<cyclonedds_sys::dds_instance_handle_t> {
@@ -42,6 +53,12 @@ pub fn dds_get_instance_handle(
     Ok(handle)
 }
 
+pub fn dds_get_guid(entity: cyclonedds_sys::dds_entity_t) -> Result<[u8; 16]> {
+    let mut guid = cyclonedds_sys::dds_guid_t::default();
+    unsafe { cyclonedds_sys::dds_get_guid(entity, &raw mut guid) }.into_error()?;
+    Ok(guid.v)
+}
+
 pub fn dds_get_status_changes(entity: cyclonedds_sys::dds_entity_t) -> Result<u32> {
     let mut status = 0;
     unsafe { cyclonedds_sys::dds_get_status_changes(entity, &raw mut status) }.into_error()?;
@@ -71,6 +88,171 @@ pub fn dds_set_status_mask(entity: cyclonedds_sys::dds_entity_t, mask: u32) -> R
     Ok(())
 }
 
+pub fn dds_get_inconsistent_topic_status(
+    topic: cyclonedds_sys::dds_entity_t,
+) -> Result<crate::status::InconsistentTopic> {
+    let mut status = cyclonedds_sys::dds_inconsistent_topic_status_t::default();
+    unsafe { cyclonedds_sys::dds_get_inconsistent_topic_status(topic, &raw mut status) }
+        .into_error()?;
+    Ok(status.into())
+}
+
+pub fn dds_get_liveliness_lost_status(
+    writer: cyclonedds_sys::dds_entity_t,
+) -> Result<crate::status::LivelinessLost> {
+    let mut status = cyclonedds_sys::dds_liveliness_lost_status_t::default();
+    unsafe { cyclonedds_sys::dds_get_liveliness_lost_status(writer, &raw mut status) }
+        .into_error()?;
+    Ok(status.into())
+}
+
+pub fn dds_get_offered_deadline_missed_status(
+    writer: cyclonedds_sys::dds_entity_t,
+) -> Result<crate::status::OfferedDeadlineMissed> {
+    let mut status = cyclonedds_sys::dds_offered_deadline_missed_status_t::default();
+    unsafe { cyclonedds_sys::dds_get_offered_deadline_missed_status(writer, &raw mut status) }
+        .into_error()?;
+    Ok(status.into())
+}
+
+pub fn dds_get_offered_incompatible_qos_status(
+    writer: cyclonedds_sys::dds_entity_t,
+) -> Result<crate::status::OfferedIncompatibleQoS> {
+    let mut status = cyclonedds_sys::dds_offered_incompatible_qos_status_t::default();
+    unsafe { cyclonedds_sys::dds_get_offered_incompatible_qos_status(writer, &raw mut status) }
+        .into_error()?;
+    Ok(status.into())
+}
+
+pub fn dds_get_publication_matched_status(
+    writer: cyclonedds_sys::dds_entity_t,
+) -> Result<crate::status::PublicationMatched> {
+    let mut status = cyclonedds_sys::dds_publication_matched_status_t::default();
+    unsafe { cyclonedds_sys::dds_get_publication_matched_status(writer, &raw mut status) }
+        .into_error()?;
+    Ok(status.into())
+}
+
+pub fn dds_get_sample_lost_status(
+    reader: cyclonedds_sys::dds_entity_t,
+) -> Result<crate::status::SampleLost> {
+    let mut status = cyclonedds_sys::dds_sample_lost_status_t::default();
+    unsafe { cyclonedds_sys::dds_get_sample_lost_status(reader, &raw mut status) }.into_error()?;
+    Ok(status.into())
+}
+
+pub fn dds_get_sample_rejected_status(
+    reader: cyclonedds_sys::dds_entity_t,
+) -> Result<crate::status::SampleRejected> {
+    let mut status = cyclonedds_sys::dds_sample_rejected_status_t::default();
+    unsafe { cyclonedds_sys::dds_get_sample_rejected_status(reader, &raw mut status) }
+        .into_error()?;
+    Ok(status.into())
+}
+
+pub fn dds_get_liveliness_changed_status(
+    reader: cyclonedds_sys::dds_entity_t,
+) -> Result<crate::status::LivelinessChanged> {
+    let mut status = cyclonedds_sys::dds_liveliness_changed_status_t::default();
+    unsafe { cyclonedds_sys::dds_get_liveliness_changed_status(reader, &raw mut status) }
+        .into_error()?;
+    Ok(status.into())
+}
+
+pub fn dds_get_requested_deadline_missed_status(
+    reader: cyclonedds_sys::dds_entity_t,
+) -> Result<crate::status::RequestedDeadlineMissed> {
+    let mut status = cyclonedds_sys::dds_requested_deadline_missed_status_t::default();
+    unsafe { cyclonedds_sys::dds_get_requested_deadline_missed_status(reader, &raw mut status) }
+        .into_error()?;
+    Ok(status.into())
+}
+
+pub fn dds_get_requested_incompatible_qos_status(
+    reader: cyclonedds_sys::dds_entity_t,
+) -> Result<crate::status::RequestedIncompatibleQoS> {
+    let mut status = cyclonedds_sys::dds_requested_incompatible_qos_status_t::default();
+    unsafe { cyclonedds_sys::dds_get_requested_incompatible_qos_status(reader, &raw mut status) }
+        .into_error()?;
+    Ok(status.into())
+}
+
+pub fn dds_get_subscription_matched_status(
+    reader: cyclonedds_sys::dds_entity_t,
+) -> Result<crate::status::SubscriptionMatched> {
+    let mut status = cyclonedds_sys::dds_subscription_matched_status_t::default();
+    unsafe { cyclonedds_sys::dds_get_subscription_matched_status(reader, &raw mut status) }
+        .into_error()?;
+    Ok(status.into())
+}
+
+/// Returns the [`Sertype`](crate::internal::sertype::Sertype) that Cyclone
+/// uses for `entity`, which may be one registered by an earlier topic of the
+/// same type.
+fn entity_sertype<'a, T>(
+    entity: cyclonedds_sys::dds_entity_t,
+) -> Result<&'a crate::internal::sertype::Sertype<T>>
+where
+    T: crate::Topicable,
+{
+    let mut sertype = std::ptr::null();
+    unsafe { cyclonedds_sys::dds_get_entity_sertype(entity, &raw mut sertype) }.into_error()?;
+    Ok(unsafe { &*(sertype.cast::<crate::internal::sertype::Sertype<T>>()) })
+}
+
+/// Starts reporting the samples of the type of `reader` that could not be
+/// deserialized to `reader`.
+pub fn dds_register_undeserializable_reader<T>(reader: cyclonedds_sys::dds_entity_t) -> Result<()>
+where
+    T: crate::Topicable,
+{
+    entity_sertype::<T>(reader)?
+        .undeserializable
+        .register(reader);
+    Ok(())
+}
+
+/// Stops reporting the samples of the type of `reader` that could not be
+/// deserialized to `reader`.
+pub fn dds_unregister_undeserializable_reader<T>(reader: cyclonedds_sys::dds_entity_t) -> Result<()>
+where
+    T: crate::Topicable,
+{
+    entity_sertype::<T>(reader)?
+        .undeserializable
+        .unregister(reader);
+    Ok(())
+}
+
+pub fn dds_get_type_undeserializable_status<T>(
+    reader: cyclonedds_sys::dds_entity_t,
+) -> Result<crate::status::SampleUndeserializable>
+where
+    T: crate::Topicable,
+{
+    entity_sertype::<T>(reader)?
+        .undeserializable
+        .take_status(reader)
+        .ok_or(crate::Error::BadParameter)
+}
+
+pub fn dds_set_type_undeserializable_callback<T>(
+    reader: cyclonedds_sys::dds_entity_t,
+    callback: Option<crate::status::UndeserializableCallback>,
+) -> Result<()>
+where
+    T: crate::Topicable,
+{
+    if entity_sertype::<T>(reader)?
+        .undeserializable
+        .set_callback(reader, callback)
+    {
+        Ok(())
+    } else {
+        Err(crate::Error::BadParameter)
+    }
+}
+
 /// Create a new serdata. This is primarily used by the
 /// [`Serdata`][`crate::internal::serdata::Serdata`] wrapper.
 pub fn ddsi_serdata_new(
@@ -151,6 +333,45 @@ pub fn dds_delete(entity: cyclonedds_sys::dds_entity_t) -> Result<()> {
     Ok(())
 }
 
+/// Forwards a Cyclone log or trace message to [`crate::logging`].
+///
+/// # Safety
+/// `data` must be null or point to a valid `dds_log_data_t` whose `message`
+/// is valid for reads of `size` bytes and whose `file` is null or
+/// null-terminated.
+#[cfg(feature = "log")]
+unsafe extern "C" fn log_sink(
+    _userdata: *mut std::ffi::c_void,
+    data: *const cyclonedds_sys::dds_log_data_t,
+) {
+    let Some(data) = (unsafe { data.as_ref() }) else {
+        return;
+    };
+    let message = if data.message.is_null() {
+        &[][..]
+    } else {
+        unsafe { std::slice::from_raw_parts(data.message.cast::<u8>(), data.size) }
+    };
+    let file = (!data.file.is_null())
+        .then(|| unsafe { std::ffi::CStr::from_ptr(data.file) }.to_string_lossy());
+
+    crate::logging::forward(
+        data.priority,
+        &String::from_utf8_lossy(message),
+        file.as_deref(),
+        data.line,
+    );
+}
+
+/// Installs (or with `forward == false` removes) [`log_sink`] as the sink for
+/// both Cyclone's log and trace output.
+#[cfg(feature = "log")]
+pub fn dds_set_log_and_trace_sink(forward: bool) {
+    let sink: cyclonedds_sys::dds_log_write_fn_t = if forward { Some(log_sink) } else { None };
+    unsafe { cyclonedds_sys::dds_set_log_sink(sink, std::ptr::null_mut()) };
+    unsafe { cyclonedds_sys::dds_set_trace_sink(sink, std::ptr::null_mut()) };
+}
+
 /// Create a domain. This is primarily used by the
 /// [`Domain`][`crate::Domain`] wrapper.
 pub fn dds_create_domain(
@@ -185,13 +406,13 @@ pub fn dds_qos_set_user_data(qos: &mut cyclonedds_sys::dds_qos_t, user_data: &[u
 
 pub fn dds_qos_set_topic_data(qos: &mut cyclonedds_sys::dds_qos_t, topic_data: &[u8]) {
     unsafe {
-        cyclonedds_sys::dds_qset_userdata(qos, topic_data.as_ptr().cast(), topic_data.len());
+        cyclonedds_sys::dds_qset_topicdata(qos, topic_data.as_ptr().cast(), topic_data.len());
     }
 }
 
 pub fn dds_qos_set_group_data(qos: &mut cyclonedds_sys::dds_qos_t, group_data: &[u8]) {
     unsafe {
-        cyclonedds_sys::dds_qset_userdata(qos, group_data.as_ptr().cast(), group_data.len());
+        cyclonedds_sys::dds_qset_groupdata(qos, group_data.as_ptr().cast(), group_data.len());
     }
 }
 
@@ -368,6 +589,500 @@ pub fn dds_qos_set_entity_name(qos: &mut cyclonedds_sys::dds_qos_t, name: &std::
     }
 }
 
+pub fn dds_qos_set_property(
+    qos: &mut cyclonedds_sys::dds_qos_t,
+    name: &std::ffi::CStr,
+    value: &std::ffi::CStr,
+    propagate: bool,
+) {
+    unsafe {
+        cyclonedds_sys::dds_qset_prop_propagate(qos, name.as_ptr(), value.as_ptr(), propagate);
+    }
+}
+
+pub fn dds_qos_set_binary_property(
+    qos: &mut cyclonedds_sys::dds_qos_t,
+    name: &std::ffi::CStr,
+    value: &[u8],
+    propagate: bool,
+) {
+    unsafe {
+        cyclonedds_sys::dds_qset_bprop_propagate(
+            qos,
+            name.as_ptr(),
+            value.as_ptr().cast(),
+            value.len(),
+            propagate,
+        );
+    }
+}
+
+pub fn dds_qos_unset_property(qos: &mut cyclonedds_sys::dds_qos_t, name: &std::ffi::CStr) {
+    unsafe { cyclonedds_sys::dds_qunset_prop(qos, name.as_ptr()) }
+}
+
+pub fn dds_qos_unset_binary_property(qos: &mut cyclonedds_sys::dds_qos_t, name: &std::ffi::CStr) {
+    unsafe { cyclonedds_sys::dds_qunset_bprop(qos, name.as_ptr()) }
+}
+
+pub fn dds_qos_set_ignore_local(
+    qos: &mut cyclonedds_sys::dds_qos_t,
+    kind: cyclonedds_sys::dds_ignorelocal_kind_t,
+) {
+    unsafe { cyclonedds_sys::dds_qset_ignorelocal(qos, kind) }
+}
+
+#[allow(clippy::fn_params_excessive_bools)]
+pub fn dds_qos_set_type_consistency(
+    qos: &mut cyclonedds_sys::dds_qos_t,
+    kind: cyclonedds_sys::dds_type_consistency_kind_t,
+    ignore_sequence_bounds: bool,
+    ignore_string_bounds: bool,
+    ignore_member_names: bool,
+    prevent_type_widening: bool,
+    force_type_validation: bool,
+) {
+    unsafe {
+        cyclonedds_sys::dds_qset_type_consistency(
+            qos,
+            kind,
+            ignore_sequence_bounds,
+            ignore_string_bounds,
+            ignore_member_names,
+            prevent_type_widening,
+            force_type_validation,
+        );
+    }
+}
+
+pub fn dds_qos_set_writer_batching(qos: &mut cyclonedds_sys::dds_qos_t, batch_updates: bool) {
+    unsafe { cyclonedds_sys::dds_qset_writer_batching(qos, batch_updates) }
+}
+
+pub fn dds_qos_set_psmx_instances(
+    qos: &mut cyclonedds_sys::dds_qos_t,
+    instances: &[std::ffi::CString],
+) {
+    let n = u32::try_from(instances.len()).expect(
+        "too many PSMX instances supplied (DDS limits the number of PSMX instances to u32::MAX)",
+    );
+    let mut values: Vec<_> = instances.iter().map(|str| str.as_ptr()).collect();
+    unsafe { cyclonedds_sys::dds_qset_psmx_instances(qos, n, values.as_mut_ptr()) }
+}
+
+pub fn dds_qos_set_data_representation(
+    qos: &mut cyclonedds_sys::dds_qos_t,
+    representations: &[cyclonedds_sys::dds_data_representation_id_t],
+) {
+    let n = u32::try_from(representations.len()).expect(
+        "too many data representations supplied (DDS limits the number of data representations \
+         to u32::MAX)",
+    );
+    unsafe { cyclonedds_sys::dds_qset_data_representation(qos, n, representations.as_ptr()) }
+}
+
+pub fn dds_copy_qos(dst: &mut cyclonedds_sys::dds_qos_t, src: &cyclonedds_sys::dds_qos_t) {
+    let result = unsafe { cyclonedds_sys::dds_copy_qos(dst, src) }.into_error();
+    debug_assert!(
+        result.is_ok(),
+        "copying a QoS between valid references cannot fail: failed with {result:?}"
+    );
+}
+
+/// Copies a C allocated byte buffer into a [`Vec`] and frees the original.
+fn take_c_bytes(value: *mut std::ffi::c_void, size: usize) -> Vec<u8> {
+    if value.is_null() {
+        Vec::new()
+    } else {
+        let bytes = unsafe { std::slice::from_raw_parts(value.cast::<u8>(), size) }.to_vec();
+        unsafe { cyclonedds_sys::dds_free(value) };
+        bytes
+    }
+}
+
+/// Copies a C allocated string into a [`std::ffi::CString`] and frees the
+/// original.
+fn take_c_string(value: *mut std::ffi::c_char) -> std::ffi::CString {
+    if value.is_null() {
+        std::ffi::CString::default()
+    } else {
+        let string = unsafe { std::ffi::CStr::from_ptr(value) }.to_owned();
+        unsafe { cyclonedds_sys::dds_free(value.cast()) };
+        string
+    }
+}
+
+/// Copies a C allocated array of `n` strings into a [`Vec`] and frees the
+/// originals.
+fn take_c_string_array(n: u32, values: *mut *mut std::ffi::c_char) -> Vec<std::ffi::CString> {
+    if values.is_null() {
+        Vec::new()
+    } else {
+        let n = usize::try_from(n).expect("u32 always fits in a usize");
+        let strings = unsafe { std::slice::from_raw_parts(values, n) }
+            .iter()
+            .map(|&value| take_c_string(value))
+            .collect();
+        unsafe { cyclonedds_sys::dds_free(values.cast()) };
+        strings
+    }
+}
+
+pub fn dds_qos_get_user_data(qos: &cyclonedds_sys::dds_qos_t) -> Option<Vec<u8>> {
+    let mut value = std::ptr::null_mut();
+    let mut size = 0;
+    unsafe { cyclonedds_sys::dds_qget_userdata(qos, &raw mut value, &raw mut size) }
+        .then(|| take_c_bytes(value, size))
+}
+
+pub fn dds_qos_get_topic_data(qos: &cyclonedds_sys::dds_qos_t) -> Option<Vec<u8>> {
+    let mut value = std::ptr::null_mut();
+    let mut size = 0;
+    unsafe { cyclonedds_sys::dds_qget_topicdata(qos, &raw mut value, &raw mut size) }
+        .then(|| take_c_bytes(value, size))
+}
+
+pub fn dds_qos_get_group_data(qos: &cyclonedds_sys::dds_qos_t) -> Option<Vec<u8>> {
+    let mut value = std::ptr::null_mut();
+    let mut size = 0;
+    unsafe { cyclonedds_sys::dds_qget_groupdata(qos, &raw mut value, &raw mut size) }
+        .then(|| take_c_bytes(value, size))
+}
+
+pub fn dds_qos_get_durability(
+    qos: &cyclonedds_sys::dds_qos_t,
+) -> Option<cyclonedds_sys::dds_durability_kind_t> {
+    let mut kind = Default::default();
+    unsafe { cyclonedds_sys::dds_qget_durability(qos, &raw mut kind) }.then_some(kind)
+}
+
+pub fn dds_qos_get_durability_service(
+    qos: &cyclonedds_sys::dds_qos_t,
+) -> Option<(
+    cyclonedds_sys::dds_duration_t,
+    cyclonedds_sys::dds_history_kind_t,
+    i32,
+    i32,
+    i32,
+    i32,
+)> {
+    let mut service_cleanup_delay = Default::default();
+    let mut history_kind = Default::default();
+    let mut history_depth = Default::default();
+    let mut max_samples = Default::default();
+    let mut max_instances = Default::default();
+    let mut max_samples_per_instance = Default::default();
+    unsafe {
+        cyclonedds_sys::dds_qget_durability_service(
+            qos,
+            &raw mut service_cleanup_delay,
+            &raw mut history_kind,
+            &raw mut history_depth,
+            &raw mut max_samples,
+            &raw mut max_instances,
+            &raw mut max_samples_per_instance,
+        )
+    }
+    .then_some((
+        service_cleanup_delay,
+        history_kind,
+        history_depth,
+        max_samples,
+        max_instances,
+        max_samples_per_instance,
+    ))
+}
+
+pub fn dds_qos_get_presentation(
+    qos: &cyclonedds_sys::dds_qos_t,
+) -> Option<(
+    cyclonedds_sys::dds_presentation_access_scope_kind,
+    bool,
+    bool,
+)> {
+    let mut access_scope = Default::default();
+    let mut coherent_access = Default::default();
+    let mut ordered_access = Default::default();
+    unsafe {
+        cyclonedds_sys::dds_qget_presentation(
+            qos,
+            &raw mut access_scope,
+            &raw mut coherent_access,
+            &raw mut ordered_access,
+        )
+    }
+    .then_some((access_scope, coherent_access, ordered_access))
+}
+
+pub fn dds_qos_get_deadline(
+    qos: &cyclonedds_sys::dds_qos_t,
+) -> Option<cyclonedds_sys::dds_duration_t> {
+    let mut deadline = Default::default();
+    unsafe { cyclonedds_sys::dds_qget_deadline(qos, &raw mut deadline) }.then_some(deadline)
+}
+
+pub fn dds_qos_get_latency_budget(
+    qos: &cyclonedds_sys::dds_qos_t,
+) -> Option<cyclonedds_sys::dds_duration_t> {
+    let mut duration = Default::default();
+    unsafe { cyclonedds_sys::dds_qget_latency_budget(qos, &raw mut duration) }.then_some(duration)
+}
+
+pub fn dds_qos_get_ownership(
+    qos: &cyclonedds_sys::dds_qos_t,
+) -> Option<(cyclonedds_sys::dds_ownership_kind_t, Option<i32>)> {
+    let mut kind = Default::default();
+    let mut strength = Default::default();
+    let has_kind = unsafe { cyclonedds_sys::dds_qget_ownership(qos, &raw mut kind) };
+    let has_strength =
+        unsafe { cyclonedds_sys::dds_qget_ownership_strength(qos, &raw mut strength) };
+    has_kind.then_some((kind, has_strength.then_some(strength)))
+}
+
+pub fn dds_qos_get_liveliness(
+    qos: &cyclonedds_sys::dds_qos_t,
+) -> Option<(
+    cyclonedds_sys::dds_liveliness_kind_t,
+    cyclonedds_sys::dds_duration_t,
+)> {
+    let mut kind = Default::default();
+    let mut lease_duration = Default::default();
+    unsafe { cyclonedds_sys::dds_qget_liveliness(qos, &raw mut kind, &raw mut lease_duration) }
+        .then_some((kind, lease_duration))
+}
+
+pub fn dds_qos_get_time_based_filter(
+    qos: &cyclonedds_sys::dds_qos_t,
+) -> Option<cyclonedds_sys::dds_duration_t> {
+    let mut minimum_separation = Default::default();
+    unsafe { cyclonedds_sys::dds_qget_time_based_filter(qos, &raw mut minimum_separation) }
+        .then_some(minimum_separation)
+}
+
+pub fn dds_qos_get_partition(qos: &cyclonedds_sys::dds_qos_t) -> Option<Vec<std::ffi::CString>> {
+    let mut n = 0;
+    let mut partitions = std::ptr::null_mut();
+    unsafe { cyclonedds_sys::dds_qget_partition(qos, &raw mut n, &raw mut partitions) }
+        .then(|| take_c_string_array(n, partitions))
+}
+
+pub fn dds_qos_get_reliability(
+    qos: &cyclonedds_sys::dds_qos_t,
+) -> Option<(
+    cyclonedds_sys::dds_reliability_kind_t,
+    cyclonedds_sys::dds_duration_t,
+)> {
+    let mut kind = Default::default();
+    let mut max_blocking_time = Default::default();
+    unsafe { cyclonedds_sys::dds_qget_reliability(qos, &raw mut kind, &raw mut max_blocking_time) }
+        .then_some((kind, max_blocking_time))
+}
+
+pub fn dds_qos_get_transport_priority(qos: &cyclonedds_sys::dds_qos_t) -> Option<i32> {
+    let mut value = Default::default();
+    unsafe { cyclonedds_sys::dds_qget_transport_priority(qos, &raw mut value) }.then_some(value)
+}
+
+pub fn dds_qos_get_lifespan(
+    qos: &cyclonedds_sys::dds_qos_t,
+) -> Option<cyclonedds_sys::dds_duration_t> {
+    let mut lifespan = Default::default();
+    unsafe { cyclonedds_sys::dds_qget_lifespan(qos, &raw mut lifespan) }.then_some(lifespan)
+}
+
+pub fn dds_qos_get_destination_order(
+    qos: &cyclonedds_sys::dds_qos_t,
+) -> Option<cyclonedds_sys::dds_destination_order_kind_t> {
+    let mut kind = Default::default();
+    unsafe { cyclonedds_sys::dds_qget_destination_order(qos, &raw mut kind) }.then_some(kind)
+}
+
+pub fn dds_qos_get_history(
+    qos: &cyclonedds_sys::dds_qos_t,
+) -> Option<(cyclonedds_sys::dds_history_kind_t, i32)> {
+    let mut kind = Default::default();
+    let mut depth = Default::default();
+    unsafe { cyclonedds_sys::dds_qget_history(qos, &raw mut kind, &raw mut depth) }
+        .then_some((kind, depth))
+}
+
+pub fn dds_qos_get_resource_limits(qos: &cyclonedds_sys::dds_qos_t) -> Option<(i32, i32, i32)> {
+    let mut max_samples = Default::default();
+    let mut max_instances = Default::default();
+    let mut max_samples_per_instance = Default::default();
+    unsafe {
+        cyclonedds_sys::dds_qget_resource_limits(
+            qos,
+            &raw mut max_samples,
+            &raw mut max_instances,
+            &raw mut max_samples_per_instance,
+        )
+    }
+    .then_some((max_samples, max_instances, max_samples_per_instance))
+}
+
+pub fn dds_qos_get_writer_data_lifecycle(qos: &cyclonedds_sys::dds_qos_t) -> Option<bool> {
+    let mut autodispose = Default::default();
+    unsafe { cyclonedds_sys::dds_qget_writer_data_lifecycle(qos, &raw mut autodispose) }
+        .then_some(autodispose)
+}
+
+pub fn dds_qos_get_reader_data_lifecycle(
+    qos: &cyclonedds_sys::dds_qos_t,
+) -> Option<(
+    cyclonedds_sys::dds_duration_t,
+    cyclonedds_sys::dds_duration_t,
+)> {
+    let mut autopurge_nowriter_samples_delay = Default::default();
+    let mut autopurge_disposed_samples_delay = Default::default();
+    unsafe {
+        cyclonedds_sys::dds_qget_reader_data_lifecycle(
+            qos,
+            &raw mut autopurge_nowriter_samples_delay,
+            &raw mut autopurge_disposed_samples_delay,
+        )
+    }
+    .then_some((
+        autopurge_nowriter_samples_delay,
+        autopurge_disposed_samples_delay,
+    ))
+}
+
+pub fn dds_qos_get_entity_name(qos: &cyclonedds_sys::dds_qos_t) -> Option<std::ffi::CString> {
+    let mut name = std::ptr::null_mut();
+    unsafe { cyclonedds_sys::dds_qget_entity_name(qos, &raw mut name) }.then(|| take_c_string(name))
+}
+
+pub fn dds_qos_get_property(
+    qos: &cyclonedds_sys::dds_qos_t,
+) -> Option<(
+    Vec<(std::ffi::CString, std::ffi::CString, bool)>,
+    Vec<(std::ffi::CString, Vec<u8>, bool)>,
+)> {
+    let mut n = 0;
+    let mut names = std::ptr::null_mut();
+    let has_value = unsafe { cyclonedds_sys::dds_qget_propnames(qos, &raw mut n, &raw mut names) };
+    let value: Vec<_> = take_c_string_array(n, names)
+        .into_iter()
+        .filter_map(|name| {
+            let mut value = std::ptr::null_mut();
+            let mut propagate = false;
+            unsafe {
+                cyclonedds_sys::dds_qget_prop_propagate(
+                    qos,
+                    name.as_ptr(),
+                    &raw mut value,
+                    &raw mut propagate,
+                )
+            }
+            .then(|| (name, take_c_string(value), propagate))
+        })
+        .collect();
+
+    let mut n = 0;
+    let mut names = std::ptr::null_mut();
+    let has_binary_value =
+        unsafe { cyclonedds_sys::dds_qget_bpropnames(qos, &raw mut n, &raw mut names) };
+    let binary_value: Vec<_> = take_c_string_array(n, names)
+        .into_iter()
+        .filter_map(|name| {
+            let mut value = std::ptr::null_mut();
+            let mut size = 0;
+            let mut propagate = false;
+            unsafe {
+                cyclonedds_sys::dds_qget_bprop_propagate(
+                    qos,
+                    name.as_ptr(),
+                    &raw mut value,
+                    &raw mut size,
+                    &raw mut propagate,
+                )
+            }
+            .then(|| (name, take_c_bytes(value, size), propagate))
+        })
+        .collect();
+
+    (has_value || has_binary_value).then_some((value, binary_value))
+}
+
+pub fn dds_qos_get_ignore_local(
+    qos: &cyclonedds_sys::dds_qos_t,
+) -> Option<cyclonedds_sys::dds_ignorelocal_kind_t> {
+    let mut kind = Default::default();
+    unsafe { cyclonedds_sys::dds_qget_ignorelocal(qos, &raw mut kind) }.then_some(kind)
+}
+
+pub fn dds_qos_get_type_consistency(
+    qos: &cyclonedds_sys::dds_qos_t,
+) -> Option<(
+    cyclonedds_sys::dds_type_consistency_kind_t,
+    bool,
+    bool,
+    bool,
+    bool,
+    bool,
+)> {
+    let mut kind = Default::default();
+    let mut ignore_sequence_bounds = false;
+    let mut ignore_string_bounds = false;
+    let mut ignore_member_names = false;
+    let mut prevent_type_widening = false;
+    let mut force_type_validation = false;
+    unsafe {
+        cyclonedds_sys::dds_qget_type_consistency(
+            qos,
+            &raw mut kind,
+            &raw mut ignore_sequence_bounds,
+            &raw mut ignore_string_bounds,
+            &raw mut ignore_member_names,
+            &raw mut prevent_type_widening,
+            &raw mut force_type_validation,
+        )
+    }
+    .then_some((
+        kind,
+        ignore_sequence_bounds,
+        ignore_string_bounds,
+        ignore_member_names,
+        prevent_type_widening,
+        force_type_validation,
+    ))
+}
+
+pub fn dds_qos_get_writer_batching(qos: &cyclonedds_sys::dds_qos_t) -> Option<bool> {
+    let mut batch_updates = false;
+    unsafe { cyclonedds_sys::dds_qget_writer_batching(qos, &raw mut batch_updates) }
+        .then_some(batch_updates)
+}
+
+pub fn dds_qos_get_psmx_instances(
+    qos: &cyclonedds_sys::dds_qos_t,
+) -> Option<Vec<std::ffi::CString>> {
+    let mut n = 0;
+    let mut instances = std::ptr::null_mut();
+    unsafe { cyclonedds_sys::dds_qget_psmx_instances(qos, &raw mut n, &raw mut instances) }
+        .then(|| take_c_string_array(n, instances))
+}
+
+pub fn dds_qos_get_data_representation(
+    qos: &cyclonedds_sys::dds_qos_t,
+) -> Option<Vec<cyclonedds_sys::dds_data_representation_id_t>> {
+    let mut n = 0;
+    let mut values = std::ptr::null_mut();
+    unsafe { cyclonedds_sys::dds_qget_data_representation(qos, &raw mut n, &raw mut values) }.then(
+        || {
+            if values.is_null() {
+                Vec::new()
+            } else {
+                let n = usize::try_from(n).expect("u32 always fits in a usize");
+                let representations = unsafe { std::slice::from_raw_parts(values, n) }.to_vec();
+                unsafe { cyclonedds_sys::dds_free(values.cast()) };
+                representations
+            }
+        },
+    )
+}
+
 /// Create a participant within a domain. This is primarily used by the
 /// [`Participant`][`crate::Participant`] wrapper.
 pub fn dds_create_participant(
@@ -433,6 +1148,18 @@ pub fn dds_create_publisher(
     .into_error()
 }
 
+pub fn dds_begin_coherent(entity: cyclonedds_sys::dds_entity_t) -> Result<()> {
+    unsafe { cyclonedds_sys::dds_begin_coherent(entity) }
+        .into_error()
+        .map(|_| ())
+}
+
+pub fn dds_end_coherent(entity: cyclonedds_sys::dds_entity_t) -> Result<()> {
+    unsafe { cyclonedds_sys::dds_end_coherent(entity) }
+        .into_error()
+        .map(|_| ())
+}
+
 pub fn dds_suspend(publisher: cyclonedds_sys::dds_entity_t) -> Result<()> {
     unsafe { cyclonedds_sys::dds_suspend(publisher) }
         .into_error()
@@ -520,16 +1247,49 @@ pub fn dds_create_writer(
     .into_error()
 }
 
+/// Runs `call` for `operation` on `entity`, reporting an encoding failure
+/// recorded by one of the [`serdata_ops`] callbacks during the call as an
+/// [`Error::Serialization`](crate::Error::Serialization).
+///
+/// A sample that cannot be encoded is rejected by
+/// [`serdata_ops::from_sample`], which makes Cyclone fail the call, and the
+/// recorded failure takes precedence over the error Cyclone returns for it.
+#[track_caller]
+fn with_serialization_context(
+    operation: &'static str,
+    entity: cyclonedds_sys::dds_entity_t,
+    call: impl FnOnce() -> cyclonedds_sys::dds_return_t,
+) -> Result<cyclonedds_sys::dds_return_t> {
+    let location = std::panic::Location::caller();
+    // Discard anything left behind by a call that did not collect it.
+    let _ = serdata_ops::take_serialization_error();
+    let result = call();
+    match serdata_ops::take_serialization_error() {
+        Some(source) => {
+            let topic_name = dds_get_topic(entity).and_then(dds_get_name).ok();
+            Err(
+                crate::ErrorContext::new(operation, entity, topic_name, location, source)
+                    .into_error(),
+            )
+        }
+        None => result.into_error(),
+    }
+}
+
+#[track_caller]
 pub fn dds_write<T>(writer: cyclonedds_sys::dds_entity_t, sample: &T) -> Result<()>
 where
     T: crate::Topicable,
 {
     let sample = InternalSample::SampleRef(sample);
     let sample = (&raw const sample).cast();
-    unsafe { cyclonedds_sys::dds_write(writer, sample) }.into_error()?;
+    with_serialization_context("write", writer, || unsafe {
+        cyclonedds_sys::dds_write(writer, sample)
+    })?;
     Ok(())
 }
 
+#[track_caller]
 pub fn dds_write_with_timestamp<T>(
     writer: cyclonedds_sys::dds_entity_t,
     sample: &T,
@@ -540,15 +1300,94 @@ where
 {
     let sample = InternalSample::SampleRef(sample);
     let sample = (&raw const sample).cast();
+    with_serialization_context("write", writer, || unsafe {
+        cyclonedds_sys::dds_write_ts(writer, sample, timestamp)
+    })?;
+    Ok(())
+}
+
+pub fn dds_write_serialized<T>(
+    writer: cyclonedds_sys::dds_entity_t,
+    serialized: &[u8],
+) -> Result<()>
+where
+    T: crate::Topicable,
+{
+    let sertype = entity_sertype::<T>(writer)?;
+
+    let serdata =
+        serdata_ops::from_ser_buffer(sertype, crate::internal::serdata::Kind::Data, serialized)
+            .map_err(|_err| crate::Error::BadParameter)?;
+
+    // The payload was validated by deserializing it above, so it is forwarded
+    // as-is rather than being serialized again from the sample.
+    let _ = unsafe { &*(serdata.cast::<crate::internal::serdata::Serdata<T>>()) }
+        .serialized_sample
+        .set(serialized.to_vec());
+
+    // NOTE: `dds_writecdr` takes over the reference to the serdata, including
+    // when it fails.
+    unsafe { cyclonedds_sys::dds_writecdr(writer, serdata) }.into_error()?;
+    Ok(())
+}
+
+pub fn dds_write_dynamic(
+    writer: cyclonedds_sys::dds_entity_t,
+    sample: &crate::dynamic::DynamicData,
+) -> Result<()> {
+    let sample = InternalDynamicSample::Ref(sample);
+    let sample = (&raw const sample).cast();
+    unsafe { cyclonedds_sys::dds_write(writer, sample) }.into_error()?;
+    Ok(())
+}
+
+pub fn dds_write_dynamic_with_timestamp(
+    writer: cyclonedds_sys::dds_entity_t,
+    sample: &crate::dynamic::DynamicData,
+    timestamp: cyclonedds_sys::dds_time_t,
+) -> Result<()> {
+    let sample = InternalDynamicSample::Ref(sample);
+    let sample = (&raw const sample).cast();
     unsafe { cyclonedds_sys::dds_write_ts(writer, sample, timestamp) }.into_error()?;
     Ok(())
 }
 
+pub fn dds_write_dispose_dynamic(
+    writer: cyclonedds_sys::dds_entity_t,
+    sample: &crate::dynamic::DynamicData,
+) -> Result<()> {
+    let sample = InternalDynamicSample::Ref(sample);
+    let sample = (&raw const sample).cast();
+    unsafe { cyclonedds_sys::dds_writedispose(writer, sample) }.into_error()?;
+    Ok(())
+}
+
+pub fn dds_dispose_dynamic(
+    writer: cyclonedds_sys::dds_entity_t,
+    key: &crate::dynamic::DynamicData,
+) -> Result<()> {
+    let key = InternalDynamicSample::Ref(key);
+    let key = (&raw const key).cast();
+    unsafe { cyclonedds_sys::dds_dispose(writer, key) }.into_error()?;
+    Ok(())
+}
+
+pub fn dds_unregister_instance_dynamic(
+    writer: cyclonedds_sys::dds_entity_t,
+    key: &crate::dynamic::DynamicData,
+) -> Result<()> {
+    let key = InternalDynamicSample::Ref(key);
+    let key = (&raw const key).cast();
+    unsafe { cyclonedds_sys::dds_unregister_instance(writer, key) }.into_error()?;
+    Ok(())
+}
+
 pub fn dds_write_flush(writer: cyclonedds_sys::dds_entity_t) -> Result<()> {
     unsafe { cyclonedds_sys::dds_write_flush(writer) }.into_error()?;
     Ok(())
 }
 
+#[track_caller]
 pub fn dds_register_instance<T>(
     writer: cyclonedds_sys::dds_entity_t,
     key: &T::Key,
@@ -559,21 +1398,26 @@ where
     let key = InternalSample::<T>::KeyRef(key);
     let key = (&raw const key).cast();
     let mut instance_handle = 0;
-    unsafe { cyclonedds_sys::dds_register_instance(writer, &raw mut instance_handle, key) }
-        .into_error()?;
+    with_serialization_context("register instance", writer, || unsafe {
+        cyclonedds_sys::dds_register_instance(writer, &raw mut instance_handle, key)
+    })?;
     Ok(instance_handle)
 }
 
+#[track_caller]
 pub fn dds_unregister_instance<T>(writer: cyclonedds_sys::dds_entity_t, key: &T::Key) -> Result<()>
 where
     T: crate::Topicable,
 {
     let key = InternalSample::<T>::KeyRef(key);
     let key = (&raw const key).cast();
-    unsafe { cyclonedds_sys::dds_unregister_instance(writer, key) }.into_error()?;
+    with_serialization_context("unregister instance", writer, || unsafe {
+        cyclonedds_sys::dds_unregister_instance(writer, key)
+    })?;
     Ok(())
 }
 
+#[track_caller]
 pub fn dds_unregister_instance_with_timestamp<T>(
     writer: cyclonedds_sys::dds_entity_t,
     key: &T::Key,
@@ -584,7 +1428,9 @@ where
 {
     let key = InternalSample::<T>::KeyRef(key);
     let key = (&raw const key).cast();
-    unsafe { cyclonedds_sys::dds_unregister_instance_ts(writer, key, timestamp) }.into_error()?;
+    with_serialization_context("unregister instance", writer, || unsafe {
+        cyclonedds_sys::dds_unregister_instance_ts(writer, key, timestamp)
+    })?;
     Ok(())
 }
 
@@ -623,16 +1469,20 @@ where
     }
 }
 
+#[track_caller]
 pub fn dds_write_dispose<T>(writer: cyclonedds_sys::dds_entity_t, data: &T) -> Result<()>
 where
     T: crate::Topicable,
 {
     let sample = InternalSample::SampleRef(data);
     let sample = (&raw const sample).cast();
-    unsafe { cyclonedds_sys::dds_writedispose(writer, sample) }.into_error()?;
+    with_serialization_context("write dispose", writer, || unsafe {
+        cyclonedds_sys::dds_writedispose(writer, sample)
+    })?;
     Ok(())
 }
 
+#[track_caller]
 pub fn dds_write_dispose_with_timestamp<T>(
     writer: cyclonedds_sys::dds_entity_t,
     data: &T,
@@ -643,20 +1493,26 @@ where
 {
     let sample = InternalSample::SampleRef(data);
     let sample = (&raw const sample).cast();
-    unsafe { cyclonedds_sys::dds_writedispose_ts(writer, sample, timestamp) }.into_error()?;
+    with_serialization_context("write dispose", writer, || unsafe {
+        cyclonedds_sys::dds_writedispose_ts(writer, sample, timestamp)
+    })?;
     Ok(())
 }
 
+#[track_caller]
 pub fn dds_dispose<T>(writer: cyclonedds_sys::dds_entity_t, key: &T::Key) -> Result<()>
 where
     T: crate::Topicable,
 {
     let key = InternalSample::<T>::KeyRef(key);
     let key = (&raw const key).cast();
-    unsafe { cyclonedds_sys::dds_dispose(writer, key) }.into_error()?;
+    with_serialization_context("dispose", writer, || unsafe {
+        cyclonedds_sys::dds_dispose(writer, key)
+    })?;
     Ok(())
 }
 
+#[track_caller]
 pub fn dds_dispose_with_timestamp<T>(
     writer: cyclonedds_sys::dds_entity_t,
     key: &T::Key,
@@ -667,7 +1523,9 @@ where
 {
     let key = InternalSample::<T>::KeyRef(key);
     let key = (&raw const key).cast();
-    unsafe { cyclonedds_sys::dds_dispose_ts(writer, key, timestamp) }.into_error()?;
+    with_serialization_context("dispose", writer, || unsafe {
+        cyclonedds_sys::dds_dispose_ts(writer, key, timestamp)
+    })?;
     Ok(())
 }
 
@@ -719,6 +1577,22 @@ pub fn dds_get_matched_subscriptions(
     }
 }
 
+pub fn dds_get_matched_subscription_data(
+    writer: cyclonedds_sys::dds_entity_t,
+    handle: cyclonedds_sys::dds_instance_handle_t,
+) -> Result<crate::builtin::SubscriptionBuiltinTopicData> {
+    let endpoint = unsafe { cyclonedds_sys::dds_get_matched_subscription_data(writer, handle) };
+    if endpoint.is_null() {
+        // NOTE: the C API does not distinguish between an invalid writer and a
+        // handle that is not matched.
+        Err(crate::Error::BadParameter)
+    } else {
+        let data = dds_builtintopic_subscription_data(unsafe { &*endpoint });
+        unsafe { cyclonedds_sys::dds_builtintopic_free_endpoint(endpoint) };
+        Ok(data)
+    }
+}
+
 pub(crate) unsafe extern "C" fn dds_read_with_collector_callback<T>(
     arg: *mut std::ffi::c_void,
     info: *const cyclonedds_sys::dds_sample_info_t,
@@ -732,7 +1606,7 @@ where
 
     let info = unsafe { &*info };
     let _sertype = unsafe { &mut *(sertype as *mut crate::internal::sertype::Sertype<T>) };
-    let serdata = unsafe { &mut *(serdata.cast::<crate::internal::serdata::Serdata<T>>()) };
+    let serdata = unsafe { &*(serdata.cast::<crate::internal::serdata::Serdata<T>>()) };
 
     let valid_data = info.valid_data;
     let info: crate::sample::Info = info.into();
@@ -840,6 +1714,292 @@ where
     dds_peek_read_take::<T, read_operation::Peek>(reader_or_condition)
 }
 
+pub(crate) unsafe extern "C" fn dds_read_serialized_with_collector_callback<T>(
+    arg: *mut std::ffi::c_void,
+    info: *const cyclonedds_sys::dds_sample_info_t,
+    _sertype: *const cyclonedds_sys::ddsi_sertype,
+    serdata: *mut cyclonedds_sys::ddsi_serdata,
+) -> cyclonedds_sys::dds_return_t
+where
+    T: crate::Topicable,
+{
+    let buffer = unsafe { &mut *(arg.cast::<Vec<crate::sample::SerializedSample>>()) };
+
+    let info = unsafe { &*info };
+    let serdata = unsafe { &*(serdata.cast::<crate::internal::serdata::Serdata<T>>()) };
+
+    // NOTE: written samples are checked to encode before they reach a reader,
+    // so this only fails for a received sample that decoded but fails to
+    // encode again. Failing the whole call would lose the samples already
+    // collected (and, when taking, already removed from the reader cache), so
+    // such a sample is skipped instead.
+    match serdata.serialized() {
+        Ok(serialized) => buffer.push(crate::sample::SerializedSample {
+            serialized: serialized.to_vec(),
+            info: info.into(),
+            valid_data: info.valid_data,
+        }),
+        Err(error) => diagnostic!(
+            warn,
+            "skipping {} sample that cannot be serialized: {error}",
+            std::any::type_name::<T>()
+        ),
+    }
+    cyclonedds_sys::DDS_RETCODE_OK.cast_signed()
+}
+
+pub(crate) fn dds_peek_read_take_serialized<T, RO>(
+    reader_or_condition: cyclonedds_sys::dds_entity_t,
+) -> Result<Vec<crate::sample::SerializedSample>>
+where
+    T: crate::Topicable,
+    RO: read_operation::ReadOperation,
+{
+    let mut samples = Vec::new();
+
+    let handle = Default::default();
+    let mask = Default::default();
+    let maxs = i32::MAX as u32;
+    let len = usize::try_from(
+        unsafe {
+            RO::COLLECTOR(
+                reader_or_condition,
+                maxs,
+                handle,
+                mask,
+                Some(dds_read_serialized_with_collector_callback::<T>),
+                (&raw mut samples).cast(),
+            )
+        }
+        .into_error()?,
+    )
+    .expect("len is a non-negative i32 and so always fits in usize");
+
+    // Skipped samples are counted on the C side but not in the buffer.
+    assert!(
+        len >= samples.len(),
+        "number of samples reported from the C side is less than the final number in the buffer"
+    );
+
+    Ok(samples)
+}
+
+pub(crate) unsafe extern "C" fn dds_read_dynamic_with_collector_callback(
+    arg: *mut std::ffi::c_void,
+    info: *const cyclonedds_sys::dds_sample_info_t,
+    _sertype: *const cyclonedds_sys::ddsi_sertype,
+    serdata: *mut cyclonedds_sys::ddsi_serdata,
+) -> cyclonedds_sys::dds_return_t {
+    let buffer = unsafe { &mut *(arg.cast::<Vec<crate::dynamic::DynamicSample>>()) };
+
+    let info = unsafe { &*info };
+    let serdata = unsafe { &*(serdata.cast::<crate::internal::dynamic_serdata::DynamicSerdata>()) };
+
+    buffer.push(crate::dynamic::DynamicSample {
+        data: serdata.sample().clone(),
+        is_sample: info.valid_data,
+        info: info.into(),
+    });
+    cyclonedds_sys::DDS_RETCODE_OK.cast_signed()
+}
+
+pub(crate) fn dds_peek_read_take_dynamic<RO>(
+    reader_or_condition: cyclonedds_sys::dds_entity_t,
+) -> Result<Vec<crate::dynamic::DynamicSample>>
+where
+    RO: read_operation::ReadOperation,
+{
+    let mut samples = Vec::new();
+
+    let handle = Default::default();
+    let mask = Default::default();
+    let maxs = i32::MAX as u32;
+    let len = usize::try_from(
+        unsafe {
+            RO::COLLECTOR(
+                reader_or_condition,
+                maxs,
+                handle,
+                mask,
+                Some(dds_read_dynamic_with_collector_callback),
+                (&raw mut samples).cast(),
+            )
+        }
+        .into_error()?,
+    )
+    .expect("len is a non-negative i32 and so always fits in usize");
+
+    assert_eq!(
+        len,
+        samples.len(),
+        "number of samples reported from the C side does not match the final number in the buffer"
+    );
+
+    Ok(samples)
+}
+
+/// Copies a borrowed C string into a [`String`], replacing any invalid UTF-8.
+fn borrow_c_string(value: *const std::ffi::c_char) -> String {
+    if value.is_null() {
+        String::new()
+    } else {
+        unsafe { std::ffi::CStr::from_ptr(value) }
+            .to_string_lossy()
+            .into_owned()
+    }
+}
+
+/// Copies a borrowed C QoS object into a [`QoS`](crate::QoS).
+fn borrow_c_qos(qos: *const cyclonedds_sys::dds_qos_t) -> crate::QoS {
+    if qos.is_null() {
+        crate::QoS::new()
+    } else {
+        crate::QoS::from_ffi(unsafe { &*qos })
+    }
+}
+
+pub fn dds_builtintopic_participant_data(
+    participant: &cyclonedds_sys::dds_builtintopic_participant_t,
+) -> crate::builtin::ParticipantBuiltinTopicData {
+    crate::builtin::ParticipantBuiltinTopicData {
+        key: crate::entity::Guid {
+            inner: participant.key.v,
+        },
+        qos: borrow_c_qos(participant.qos),
+    }
+}
+
+pub fn dds_builtintopic_topic_data(
+    topic: &cyclonedds_sys::dds_builtintopic_topic_t,
+) -> crate::builtin::TopicBuiltinTopicData {
+    crate::builtin::TopicBuiltinTopicData {
+        key: crate::entity::Guid { inner: topic.key.d },
+        topic_name: borrow_c_string(topic.topic_name),
+        type_name: borrow_c_string(topic.type_name),
+        qos: borrow_c_qos(topic.qos),
+    }
+}
+
+pub fn dds_builtintopic_publication_data(
+    endpoint: &cyclonedds_sys::dds_builtintopic_endpoint_t,
+) -> crate::builtin::PublicationBuiltinTopicData {
+    crate::builtin::PublicationBuiltinTopicData {
+        key: crate::entity::Guid {
+            inner: endpoint.key.v,
+        },
+        participant_key: crate::entity::Guid {
+            inner: endpoint.participant_key.v,
+        },
+        participant_instance_handle: crate::entity::InstanceHandle {
+            inner: endpoint.participant_instance_handle,
+        },
+        topic_name: borrow_c_string(endpoint.topic_name),
+        type_name: borrow_c_string(endpoint.type_name),
+        qos: borrow_c_qos(endpoint.qos),
+    }
+}
+
+pub fn dds_builtintopic_subscription_data(
+    endpoint: &cyclonedds_sys::dds_builtintopic_endpoint_t,
+) -> crate::builtin::SubscriptionBuiltinTopicData {
+    crate::builtin::SubscriptionBuiltinTopicData {
+        key: crate::entity::Guid {
+            inner: endpoint.key.v,
+        },
+        participant_key: crate::entity::Guid {
+            inner: endpoint.participant_key.v,
+        },
+        participant_instance_handle: crate::entity::InstanceHandle {
+            inner: endpoint.participant_instance_handle,
+        },
+        topic_name: borrow_c_string(endpoint.topic_name),
+        type_name: borrow_c_string(endpoint.type_name),
+        qos: borrow_c_qos(endpoint.qos),
+    }
+}
+
+pub(crate) unsafe extern "C" fn dds_read_builtin_with_collector_callback<T>(
+    arg: *mut std::ffi::c_void,
+    info: *const cyclonedds_sys::dds_sample_info_t,
+    sertype: *const cyclonedds_sys::ddsi_sertype,
+    serdata: *mut cyclonedds_sys::ddsi_serdata,
+) -> cyclonedds_sys::dds_return_t
+where
+    T: crate::builtin::BuiltinTopic,
+{
+    let buffer = unsafe { &mut *(arg.cast::<Vec<crate::builtin::BuiltinSample<T>>>()) };
+    let info = unsafe { &*info };
+
+    // NOTE: the built-in topics use Cyclone's own sertypes so the sample is
+    // materialized into the C representation and converted from there.
+    let mut raw = T::Raw::default();
+    let converted = unsafe {
+        cyclonedds_sys::ddsi_serdata_to_sample(
+            serdata,
+            (&raw mut raw).cast(),
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+        )
+    };
+    if !converted {
+        return cyclonedds_sys::DDS_RETCODE_ERROR;
+    }
+
+    let key = T::key_from_raw(&raw);
+    let sample = info.valid_data.then(|| T::from_raw(&raw));
+
+    unsafe {
+        cyclonedds_sys::ddsi_sertype_free_sample(
+            sertype,
+            (&raw mut raw).cast(),
+            cyclonedds_sys::DDS_FREE_CONTENTS_BIT as cyclonedds_sys::dds_free_op_t,
+        );
+    }
+
+    buffer.push(crate::builtin::BuiltinSample {
+        key,
+        sample,
+        info: info.into(),
+    });
+    cyclonedds_sys::DDS_RETCODE_OK.cast_signed()
+}
+
+pub(crate) fn dds_peek_read_take_builtin<T, RO>(
+    reader: cyclonedds_sys::dds_entity_t,
+) -> Result<Vec<crate::builtin::BuiltinSample<T>>>
+where
+    T: crate::builtin::BuiltinTopic,
+    RO: read_operation::ReadOperation,
+{
+    let mut samples = Vec::new();
+
+    let handle = Default::default();
+    let mask = Default::default();
+    let maxs = i32::MAX as u32;
+    let len = usize::try_from(
+        unsafe {
+            RO::COLLECTOR(
+                reader,
+                maxs,
+                handle,
+                mask,
+                Some(dds_read_builtin_with_collector_callback::<T>),
+                (&raw mut samples).cast(),
+            )
+        }
+        .into_error()?,
+    )
+    .expect("len is a non-negative i32 and so always fits in usize");
+
+    assert_eq!(
+        len,
+        samples.len(),
+        "number of samples reported from the C side does not match the final number in the buffer"
+    );
+
+    Ok(samples)
+}
+
 pub fn dds_reader_wait_for_historical_data(
     reader: cyclonedds_sys::dds_entity_t,
     timeout: cyclonedds_sys::dds_duration_t,
@@ -878,12 +2038,103 @@ pub fn dds_get_matched_publications(
     }
 }
 
+pub fn dds_get_matched_publication_data(
+    reader: cyclonedds_sys::dds_entity_t,
+    handle: cyclonedds_sys::dds_instance_handle_t,
+) -> Result<crate::builtin::PublicationBuiltinTopicData> {
+    let endpoint = unsafe { cyclonedds_sys::dds_get_matched_publication_data(reader, handle) };
+    if endpoint.is_null() {
+        // NOTE: the C API does not distinguish between an invalid reader and a
+        // handle that is not matched.
+        Err(crate::Error::BadParameter)
+    } else {
+        let data = dds_builtintopic_publication_data(unsafe { &*endpoint });
+        unsafe { cyclonedds_sys::dds_builtintopic_free_endpoint(endpoint) };
+        Ok(data)
+    }
+}
+
 pub fn dds_get_participant(
     entity: cyclonedds_sys::dds_entity_t,
 ) -> Result<cyclonedds_sys::dds_entity_t> {
     unsafe { cyclonedds_sys::dds_get_participant(entity) }.into_error()
 }
 
+pub fn dds_get_parent(
+    entity: cyclonedds_sys::dds_entity_t,
+) -> Result<cyclonedds_sys::dds_entity_t> {
+    unsafe { cyclonedds_sys::dds_get_parent(entity) }.into_error()
+}
+
+pub fn dds_get_children(
+    entity: cyclonedds_sys::dds_entity_t,
+) -> Result<Vec<cyclonedds_sys::dds_entity_t>> {
+    let count = usize::try_from(
+        unsafe { cyclonedds_sys::dds_get_children(entity, std::ptr::null_mut(), 0) }
+            .into_error()?,
+    )
+    .expect("count is a non-negative i32 and so always fits in usize");
+
+    let mut children = vec![0; count];
+    if count > 0 {
+        unsafe { cyclonedds_sys::dds_get_children(entity, children.as_mut_ptr(), count) }
+            .into_error()
+            .map(|count| {
+                let count = usize::try_from(count)
+                    .expect("count is a non-negative i32 and so always fits in a usize");
+                // NOTE: children may have been created in between the two calls
+                // in which case only those that fit in the buffer are returned.
+                children.truncate(count);
+                children
+            })
+    } else {
+        Ok(children)
+    }
+}
+
+pub fn dds_get_domainid(
+    entity: cyclonedds_sys::dds_entity_t,
+) -> Result<cyclonedds_sys::dds_domainid_t> {
+    let mut domain_id = 0;
+    unsafe { cyclonedds_sys::dds_get_domainid(entity, &raw mut domain_id) }.into_error()?;
+    Ok(domain_id)
+}
+
+pub fn dds_get_topic(entity: cyclonedds_sys::dds_entity_t) -> Result<cyclonedds_sys::dds_entity_t> {
+    unsafe { cyclonedds_sys::dds_get_topic(entity) }.into_error()
+}
+
+/// Retrieves a string from the C side via `f` which writes into a
+/// caller-provided buffer, growing the buffer until the string fits.
+fn get_c_string_into_buffer(
+    f: impl Fn(*mut std::ffi::c_char, usize) -> cyclonedds_sys::dds_return_t,
+) -> Result<String> {
+    let mut buffer = vec![0u8; 256];
+    loop {
+        f(buffer.as_mut_ptr().cast(), buffer.len()).into_error()?;
+        let string = std::ffi::CStr::from_bytes_until_nul(&buffer)
+            .expect("the C side always nul-terminates the buffer");
+        // NOTE: a string that completely fills the buffer may have been
+        // truncated so retry with a larger buffer.
+        if string.count_bytes() + 1 < buffer.len() {
+            return Ok(string.to_string_lossy().into_owned());
+        }
+        buffer.resize(buffer.len() * 2, 0);
+    }
+}
+
+pub fn dds_get_name(topic: cyclonedds_sys::dds_entity_t) -> Result<String> {
+    get_c_string_into_buffer(|buffer, size| unsafe {
+        cyclonedds_sys::dds_get_name(topic, buffer, size)
+    })
+}
+
+pub fn dds_get_type_name(topic: cyclonedds_sys::dds_entity_t) -> Result<String> {
+    get_c_string_into_buffer(|buffer, size| unsafe {
+        cyclonedds_sys::dds_get_type_name(topic, buffer, size)
+    })
+}
+
 pub fn dds_create_readcondition(
     reader: cyclonedds_sys::dds_entity_t,
     mask: u32,
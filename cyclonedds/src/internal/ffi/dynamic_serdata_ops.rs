@@ -0,0 +1,328 @@
+//! Operations that allow Cyclone to interact with runtime-typed serialized
+//! data. These are threaded through the [`DynamicSerdata`] type.
+
+use crate::internal::dynamic_serdata::DynamicSerdata;
+use crate::internal::dynamic_sertype::DynamicSertype;
+use crate::internal::ffi::InternalDynamicSample;
+use crate::internal::ffi::serdata_ops;
+use crate::internal::key_hash::KeyHash;
+use crate::internal::serdata::Kind;
+
+/// Moves a constructed [`DynamicSerdata`] over to Cyclone, mapping a failed
+/// construction to a null pointer.
+fn into_raw(serdata: crate::Result<DynamicSerdata>) -> *mut cyclonedds_sys::ddsi_serdata {
+    serdata.map_or(std::ptr::null_mut(), |serdata| {
+        Box::into_raw(Box::new(serdata)).cast()
+    })
+}
+
+/// Compares the keys of two [`DynamicSerdata`] instances for equality.
+///
+/// ## Safety
+/// The `lhs` and `rhs` must be non-null pointers to fully-initialized
+/// [`DynamicSerdata`].
+pub unsafe extern "C" fn eqkey(
+    lhs: *const cyclonedds_sys::ddsi_serdata,
+    rhs: *const cyclonedds_sys::ddsi_serdata,
+) -> bool {
+    let lhs = unsafe { &*(lhs.cast::<DynamicSerdata>()) };
+    let rhs = unsafe { &*(rhs.cast::<DynamicSerdata>()) };
+
+    lhs.key() == rhs.key()
+}
+
+/// Returns the serialized size (in bytes) of the sample contained in the given
+/// [`DynamicSerdata`] + the size of the DDSI RTPS header.
+///
+/// ## Safety
+/// The `serdata` must be a non-null pointer to a fully-initialized
+/// [`DynamicSerdata`].
+pub unsafe extern "C" fn get_size(serdata: *const cyclonedds_sys::ddsi_serdata) -> u32 {
+    let serdata = unsafe { &*(serdata.cast::<DynamicSerdata>()) };
+
+    u32::try_from(serdata.serialized().len()).expect("serialized data out of bounds")
+}
+
+/// Construct a [`DynamicSerdata`] from a received fragment chain.
+///
+/// ## Safety
+/// - `sertype` must be a valid, non-null pointer to a heap-allocated [`DynamicSertype`].
+/// - `fragment_chain` must be null or point to a valid fragment chain holding `size` bytes.
+pub unsafe extern "C" fn from_ser(
+    sertype: *const cyclonedds_sys::ddsi_sertype,
+    kind: cyclonedds_sys::ddsi_serdata_kind,
+    fragment_chain: *const cyclonedds_sys::ddsi_rdata,
+    size: usize,
+) -> *mut cyclonedds_sys::ddsi_serdata {
+    let sertype = unsafe { &*(sertype.cast::<DynamicSertype>()) };
+
+    if fragment_chain.is_null() {
+        return std::ptr::null_mut();
+    }
+
+    Kind::try_from(kind).map_or(std::ptr::null_mut(), |kind| {
+        let fragment_chain = unsafe { &*fragment_chain };
+        serdata_ops::copy_from_fragment(fragment_chain, size)
+            .map_or(std::ptr::null_mut(), |buffer| {
+                into_raw(DynamicSerdata::from_serialized(sertype, kind, buffer))
+            })
+    })
+}
+
+/// Construct a [`DynamicSerdata`] from a serialized `ddsrt_iovec_t`.
+///
+/// ## Safety
+/// - `sertype` must be a valid, non-null pointer to a heap-allocated [`DynamicSertype`].
+/// - `containers` must point to a valid contiguous array of `ddsrt_iovec_t` structures of length
+///   `containers_len` describing at least `size` readable bytes.
+pub unsafe extern "C" fn from_ser_iov(
+    sertype: *const cyclonedds_sys::ddsi_sertype,
+    kind: cyclonedds_sys::ddsi_serdata_kind,
+    containers_len: cyclonedds_sys::ddsrt_msg_iovlen_t,
+    containers: *const cyclonedds_sys::ddsrt_iovec_t,
+    size: usize,
+) -> *mut cyclonedds_sys::ddsi_serdata {
+    let sertype = unsafe { &*(sertype.cast::<DynamicSertype>()) };
+
+    Kind::try_from(kind).map_or(std::ptr::null_mut(), |kind| {
+        unsafe { serdata_ops::copy_from_iov(containers_len, containers, size) }
+            .map_or(std::ptr::null_mut(), |buffer| {
+                into_raw(DynamicSerdata::from_serialized(sertype, kind, buffer))
+            })
+    })
+}
+
+/// Construct a key [`DynamicSerdata`] from a keyhash.
+///
+/// This is only possible when the keyhash is the big-endian CDR serialized
+/// key itself rather than its MD5 digest.
+///
+/// ## Safety
+/// - `sertype` must be a valid, non-null pointer to a heap-allocated [`DynamicSertype`].
+/// - `keyhash` must be a valid, non-null pointer.
+pub unsafe extern "C" fn from_keyhash(
+    sertype: *const cyclonedds_sys::ddsi_sertype,
+    keyhash: *const cyclonedds_sys::ddsi_keyhash,
+) -> *mut cyclonedds_sys::ddsi_serdata {
+    let sertype = unsafe { &*(sertype.cast::<DynamicSertype>()) };
+    let keyhash = unsafe { &*keyhash };
+
+    if sertype.key_type.max_serialized_cdr_size() > crate::cdr_bounds::CdrSize::Bounded(16) {
+        // The key hash is based on MD5 and so can't be reconstructed into a key.
+        return std::ptr::null_mut();
+    }
+
+    crate::dynamic::cdr::deserialize_payload(
+        &sertype.key_type,
+        &keyhash.value,
+        crate::dynamic::cdr::Endianness::Big,
+    )
+    .map_or(std::ptr::null_mut(), |key| {
+        into_raw(DynamicSerdata::from_sample(sertype, Kind::Key, &key))
+    })
+}
+
+/// Constructs a [`DynamicSerdata`] from a sample pointer, given a
+/// serialization kind.
+///
+/// Returns a null pointer if the sample does not match the
+/// [`StructType`](crate::dynamic::StructType) of the sertype.
+///
+/// ## Safety
+/// - `sertype` must be a valid, non-null pointer to a heap-allocated [`DynamicSertype`].
+/// - `sample` must be a valid, non-null pointer to an [`InternalDynamicSample`].
+pub unsafe extern "C" fn from_sample(
+    sertype: *const cyclonedds_sys::ddsi_sertype,
+    kind: cyclonedds_sys::ddsi_serdata_kind,
+    sample: *const std::ffi::c_void,
+) -> *mut cyclonedds_sys::ddsi_serdata {
+    let sertype = unsafe { &*(sertype.cast::<DynamicSertype>()) };
+    let sample = match unsafe { &*(sample.cast::<InternalDynamicSample<'_>>()) } {
+        InternalDynamicSample::None => return std::ptr::null_mut(),
+        InternalDynamicSample::Ref(sample) => *sample,
+        InternalDynamicSample::Owned(sample) => sample,
+    };
+
+    Kind::try_from(kind).map_or(std::ptr::null_mut(), |kind| {
+        into_raw(DynamicSerdata::from_sample(sertype, kind, sample))
+    })
+}
+
+/// Copy `size` bytes of the serialized sample starting at `offset` into
+/// `buffer`.
+///
+/// ## Safety
+/// - `serdata` must be a non-null pointer to a fully-initialized [`DynamicSerdata`].
+/// - `buffer` must be valid for writes of `size` bytes.
+pub unsafe extern "C" fn to_ser(
+    serdata: *const cyclonedds_sys::ddsi_serdata,
+    offset: usize,
+    size: usize,
+    buffer: *mut std::ffi::c_void,
+) {
+    let serdata = unsafe { &*(serdata.cast::<DynamicSerdata>()) };
+    let buffer = unsafe { std::slice::from_raw_parts_mut(buffer.cast::<u8>(), size) };
+
+    if let Some(serialized) = serdata.serialized().get(offset..offset + size) {
+        buffer.copy_from_slice(serialized);
+    }
+}
+
+/// Write the serialized bytes of a sample to the provided
+/// [`cyclonedds_sys::ddsrt_iovec_t`].
+///
+/// This increments the `serdata` reference counter.
+///
+/// ## Safety
+/// - `serdata` must be a non-null pointer to a fully-initialized [`DynamicSerdata`].
+/// - `container` must be a non-null pointer to an [`cyclonedds_sys::ddsrt_iovec_t`].
+pub unsafe extern "C" fn to_ser_ref(
+    serdata: *const cyclonedds_sys::ddsi_serdata,
+    offset: usize,
+    size: usize,
+    container: *mut cyclonedds_sys::ddsrt_iovec_t,
+) -> *mut cyclonedds_sys::ddsi_serdata {
+    let serdata = unsafe { &*(serdata.cast::<DynamicSerdata>()) };
+    let container = unsafe { &mut *container };
+
+    serdata
+        .serialized()
+        .get(offset..)
+        .and_then(|slice| {
+            let slice = slice.get(..size).unwrap_or(slice);
+            container.iov_base = slice.as_ptr() as *mut _;
+
+            let iov_len = slice.len();
+            // `ddsrt_iov_len_t` is a `usize` for every platform except Windows.
+            #[cfg(target_os = "windows")]
+            let iov_len = cyclonedds_sys::ddsrt_iov_len_t::try_from(iov_len).ok()?;
+            container.iov_len = iov_len;
+            Some(())
+        })
+        .map(|()| unsafe { cyclonedds_sys::ddsi_serdata_ref(&raw const serdata.inner) })
+        .unwrap_or_default()
+}
+
+/// Relinquish the reference handed out by [`to_ser_ref`].
+///
+/// This decrements the `serdata` reference counter.
+///
+/// ## Safety
+/// - `serdata` must be a non-null pointer to a fully-initialized [`DynamicSerdata`].
+pub unsafe extern "C" fn to_ser_unref(
+    serdata: *mut cyclonedds_sys::ddsi_serdata,
+    _: *const cyclonedds_sys::ddsrt_iovec_t,
+) {
+    let serdata = unsafe { &mut *(serdata.cast::<DynamicSerdata>()) };
+
+    crate::internal::ffi::ddsi_serdata_unref(&mut serdata.inner);
+}
+
+/// Copies the sample (or key) from a [`DynamicSerdata`] into a provided
+/// `sample` pointer.
+///
+/// ## Safety
+/// - `serdata` must be a non-null pointer to a fully-initialized [`DynamicSerdata`].
+/// - `sample` must be null or a valid pointer to an [`InternalDynamicSample`].
+pub unsafe extern "C" fn to_sample(
+    serdata: *const cyclonedds_sys::ddsi_serdata,
+    sample: *mut std::ffi::c_void,
+    _buffer: *mut *mut std::ffi::c_void,
+    _buffer_limit: *mut std::ffi::c_void,
+) -> bool {
+    if sample.is_null() {
+        false
+    } else {
+        let serdata = unsafe { &*(serdata.cast::<DynamicSerdata>()) };
+        let sample = sample.cast::<InternalDynamicSample<'_>>();
+
+        unsafe { sample.write(InternalDynamicSample::Owned(serdata.sample().clone())) };
+        true
+    }
+}
+
+/// Create an untyped (key-only) [`DynamicSerdata`] from the provided one.
+///
+/// ## Safety
+/// - `serdata` must be a non-null pointer to a fully-initialized [`DynamicSerdata`].
+pub unsafe extern "C" fn to_untyped(
+    serdata: *const cyclonedds_sys::ddsi_serdata,
+) -> *mut cyclonedds_sys::ddsi_serdata {
+    let serdata = unsafe { &*(serdata.cast::<DynamicSerdata>()) };
+    let sertype = unsafe { &*(serdata.inner.type_.cast::<DynamicSertype>()) };
+
+    match DynamicSerdata::from_sample(sertype, Kind::Key, serdata.sample()) {
+        Ok(mut untyped_serdata) => {
+            untyped_serdata.inner.type_ = std::ptr::null_mut();
+            Box::into_raw(Box::new(untyped_serdata)).cast()
+        }
+        Err(_) => std::ptr::null_mut(),
+    }
+}
+
+/// Copies the key from an untyped [`DynamicSerdata`] into a provided
+/// `sample` pointer.
+///
+/// ## Safety
+/// - `serdata` must be a non-null pointer to a fully-initialized [`DynamicSerdata`].
+/// - `sample` must be null or a valid pointer to an [`InternalDynamicSample`].
+pub unsafe extern "C" fn untyped_to_sample(
+    _sertype: *const cyclonedds_sys::ddsi_sertype,
+    serdata: *const cyclonedds_sys::ddsi_serdata,
+    sample: *mut std::ffi::c_void,
+    buffer: *mut *mut std::ffi::c_void,
+    buffer_limit: *mut std::ffi::c_void,
+) -> bool {
+    unsafe { to_sample(serdata, sample, buffer, buffer_limit) }
+}
+
+/// Deallocate a [`DynamicSerdata`].
+///
+/// ## Safety
+/// - `serdata` must be a non-null pointer to a previously allocated [`DynamicSerdata`].
+/// - Cyclone DDS must call this only after the [`ddsi_serdata`][cyclonedds_sys::ddsi_serdata]
+///   reference count has reached zero.
+pub unsafe extern "C" fn free(serdata: *mut cyclonedds_sys::ddsi_serdata) {
+    let serdata = unsafe { Box::from_raw(serdata.cast::<DynamicSerdata>()) };
+
+    drop(serdata);
+}
+
+/// Writes the debug representation of the sample held by a
+/// [`DynamicSerdata`] into the provided buffer.
+///
+/// ## Safety
+/// - `serdata` must be a non-null pointer to a fully-initialized [`DynamicSerdata`].
+/// - `buffer` must be non-null, valid for writes of `length` bytes, and `length` must be greater
+///   than zero.
+pub unsafe extern "C" fn print(
+    _sertype: *const cyclonedds_sys::ddsi_sertype,
+    serdata: *const cyclonedds_sys::ddsi_serdata,
+    buffer: *mut std::ffi::c_char,
+    length: usize,
+) -> usize {
+    let serdata = unsafe { &*(serdata.cast::<DynamicSerdata>()) };
+
+    unsafe { serdata_ops::print_debug(serdata.sample(), buffer, length) }
+}
+
+/// Compute the keyhash of a [`DynamicSerdata`].
+///
+/// ## Safety
+/// - `serdata` must be a non-null pointer to a fully-initialized [`DynamicSerdata`].
+/// - `keyhash` must be a valid, non-null pointer.
+pub unsafe extern "C" fn get_keyhash(
+    serdata: *const cyclonedds_sys::ddsi_serdata,
+    keyhash: *mut cyclonedds_sys::ddsi_keyhash,
+    force_md5: bool,
+) {
+    let serdata = unsafe { &*(serdata.cast::<DynamicSerdata>()) };
+    let keyhash = unsafe { &mut *keyhash };
+
+    keyhash.value = KeyHash::from_serialized_key(
+        serdata.key().to_vec(),
+        serdata.key_max_serialized_cdr_size(),
+        force_md5,
+    )
+    .0;
+}
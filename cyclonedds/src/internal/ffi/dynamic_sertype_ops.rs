@@ -0,0 +1,194 @@
+//! Operations that allow Cyclone to interact with runtime-typed data
+//! structures. These are threaded through the [`DynamicSertype`] type.
+
+use std::ffi::CStr;
+use std::hash::{Hash, Hasher};
+
+use crate::dynamic::cdr::{self, Endianness};
+use crate::internal::dynamic_sertype::DynamicSertype;
+use crate::internal::ffi::InternalDynamicSample;
+use crate::internal::ffi::sertype_ops::{self, EmptySample};
+use crate::internal::serdata::Kind;
+
+impl EmptySample for InternalDynamicSample<'_> {
+    fn empty() -> Self {
+        InternalDynamicSample::None
+    }
+}
+
+/// Free a [`DynamicSertype`] created on the Rust side of the FFI.
+///
+/// ## Safety
+/// The provided `sertype` pointer must be from the pointer embedded in a
+/// [`DynamicSertype`] that was created via [`DynamicSertype::new`].
+pub unsafe extern "C" fn free(sertype: *mut cyclonedds_sys::ddsi_sertype) {
+    let sertype = unsafe { Box::from_raw(sertype.cast::<DynamicSertype>()) };
+    drop(sertype);
+}
+
+/// Zero out samples.
+pub unsafe extern "C" fn zero_samples(
+    _sertype: *const cyclonedds_sys::ddsi_sertype,
+    samples: *mut std::ffi::c_void,
+    count: usize,
+) {
+    unsafe { sertype_ops::zero_samples_of::<InternalDynamicSample<'_>>(samples, count) };
+}
+
+/// Realloc the sample buffer.
+pub unsafe extern "C" fn realloc_samples(
+    pointers: *mut *mut std::ffi::c_void,
+    _sertype: *const cyclonedds_sys::ddsi_sertype,
+    old_samples: *mut std::ffi::c_void,
+    old_count: usize,
+    new_count: usize,
+) {
+    unsafe {
+        sertype_ops::realloc_samples_of::<InternalDynamicSample<'_>>(
+            pointers,
+            old_samples,
+            old_count,
+            new_count,
+        );
+    }
+}
+
+/// Free previously allocated samples.
+///
+/// ## Safety
+/// `pointers` must be non-null and must point to a valid pointer that was
+/// allocated via a `Box<InternalDynamicSample>`.
+pub unsafe extern "C" fn free_samples(
+    _sertype: *const cyclonedds_sys::ddsi_sertype,
+    pointers: *mut *mut std::ffi::c_void,
+    count: usize,
+    operation: cyclonedds_sys::dds_free_op_t,
+) {
+    unsafe {
+        sertype_ops::free_samples_of::<InternalDynamicSample<'_>>(pointers, count, operation);
+    };
+}
+
+/// Compares two [`DynamicSertype`] instances for equality.
+///
+/// Two dynamic sertypes are only equal if they share both the type name and
+/// the full type description, so that a topic can't be recreated with a
+/// different layout under the same name.
+///
+/// # Safety
+/// The `lhs` and `rhs` must point to `DynamicSertype`s previously constructed
+/// by the Rust API.
+pub unsafe extern "C" fn equal(
+    lhs: *const cyclonedds_sys::ddsi_sertype,
+    rhs: *const cyclonedds_sys::ddsi_sertype,
+) -> bool {
+    let lhs = unsafe { &*(lhs.cast::<DynamicSertype>()) };
+    let rhs = unsafe { &*(rhs.cast::<DynamicSertype>()) };
+
+    unsafe { CStr::from_ptr(lhs.inner.type_name) == CStr::from_ptr(rhs.inner.type_name) }
+    &&lhs.struct_type == rhs.struct_type
+}
+
+/// Compute a hash for a runtime-typed DDS data type.
+///
+/// # Safety
+/// The provided `sertype` must be a valid sertype created through
+/// [`DynamicSertype::new`].
+pub unsafe extern "C" fn hash(sertype: *const cyclonedds_sys::ddsi_sertype) -> u32 {
+    let sertype = unsafe { &*(sertype.cast::<DynamicSertype>()) };
+
+    let name = unsafe { CStr::from_ptr(sertype.inner.type_name) };
+
+    let mut hasher = std::hash::DefaultHasher::new();
+    name.hash(&mut hasher);
+    sertype.struct_type.hash(&mut hasher);
+    let hash: u64 = hasher.finish();
+
+    // NOTE: the truncation to 32 bits is intentional and because of the fold
+    // no entropy should be lost.
+    #[allow(clippy::cast_possible_truncation)]
+    let hash: u32 = (hash ^ (hash >> 32)) as u32;
+
+    hash
+}
+
+/// Serialize the sample (or key) held by an [`InternalDynamicSample`] with
+/// the CDR header prepended.
+fn serialize(
+    sertype: &DynamicSertype,
+    serdata_kind: cyclonedds_sys::ddsi_serdata_kind,
+    sample: *const std::ffi::c_void,
+) -> Option<Vec<u8>> {
+    if sample.is_null() {
+        return None;
+    }
+
+    let sample = match unsafe { &*(sample.cast::<InternalDynamicSample<'_>>()) } {
+        InternalDynamicSample::None => return None,
+        InternalDynamicSample::Ref(sample) => *sample,
+        InternalDynamicSample::Owned(sample) => sample,
+    };
+
+    match Kind::try_from(serdata_kind).ok()? {
+        Kind::Data => cdr::serialize(&sertype.struct_type, sample, Endianness::NATIVE).ok(),
+        Kind::Key => sertype
+            .struct_type
+            .key_of(sample)
+            .and_then(|key| cdr::serialize(&sertype.key_type, &key, Endianness::NATIVE).ok()),
+    }
+}
+
+/// Compute the size of the serialized form of a sample including the CDR
+/// header.
+///
+/// # Safety
+/// - `sertype` must be a valid sertype created through [`DynamicSertype::new`].
+/// - `sample` must be null or point to an [`InternalDynamicSample`].
+/// - `size` and `encoding_identifier` must be non-null.
+pub unsafe extern "C" fn get_serialized_size(
+    sertype: *const cyclonedds_sys::ddsi_sertype,
+    serdata_kind: cyclonedds_sys::ddsi_serdata_kind,
+    sample: *const std::ffi::c_void,
+    size: *mut usize,
+    encoding_identifier: *mut u16,
+) -> i32 {
+    let sertype = unsafe { &*(sertype.cast::<DynamicSertype>()) };
+
+    match serialize(sertype, serdata_kind, sample).as_deref() {
+        Some(serialized @ [first, second, ..]) => {
+            unsafe {
+                size.write(serialized.len());
+                encoding_identifier.write(u16::from_be_bytes([*first, *second]));
+            }
+            cyclonedds_sys::DDS_RETCODE_OK.cast_signed()
+        }
+        _ => cyclonedds_sys::DDS_RETCODE_BAD_PARAMETER,
+    }
+}
+
+/// Serialize a sample including the CDR header into the provided buffer.
+///
+/// # Safety
+/// - `sertype` must be a valid sertype created through [`DynamicSertype::new`].
+/// - `sample` must be null or point to an [`InternalDynamicSample`].
+/// - `destination_buffer` must be valid for writes of
+///   `destination_buffer_length` bytes.
+pub unsafe extern "C" fn serialize_into(
+    sertype: *const cyclonedds_sys::ddsi_sertype,
+    serdata_kind: cyclonedds_sys::ddsi_serdata_kind,
+    sample: *const std::ffi::c_void,
+    destination_buffer: *mut std::ffi::c_void,
+    destination_buffer_length: usize,
+) -> bool {
+    let sertype = unsafe { &*(sertype.cast::<DynamicSertype>()) };
+    let buffer = unsafe {
+        std::slice::from_raw_parts_mut(destination_buffer.cast(), destination_buffer_length)
+    };
+
+    serialize(sertype, serdata_kind, sample).is_some_and(|serialized| {
+        buffer
+            .get_mut(..serialized.len())
+            .map(|buffer| buffer.copy_from_slice(&serialized))
+            .is_some()
+    })
+}
@@ -1,9 +1,10 @@
 //! Operations that allow Cyclone to interact with Rust allocated data
 //! structures. These are threaded through the [`Serdata`] type.
 
-use std::io::Write;
+use std::fmt::Write;
 
 use crate::cdr_bounds::{CdrBounds, CdrSize};
+use crate::dynamic::cdr::{Endianness, SizeBuffer, Version};
 use crate::internal::ffi::InternalSample;
 use crate::internal::key_hash::KeyHash;
 use crate::internal::serdata::Serdata;
@@ -13,6 +14,53 @@ use crate::sample::SampleOrKeyInner as SampleOrKey;
 /// The size of the RTPS header in bytes.
 pub const DDSI_RTPS_HEADER_SIZE: usize = 4;
 
+thread_local! {
+    /// The reason the last serialization in a callback on this thread failed.
+    ///
+    /// Callbacks such as [`from_sample`] can only report a failure to Cyclone
+    /// by returning null, so the encoding error is parked here for the calling
+    /// wrapper to pick up. Cyclone invokes [`from_sample`] on the thread
+    /// performing the operation before the operation returns, which makes a
+    /// thread-local sufficient.
+    static SERIALIZATION_ERROR: std::cell::RefCell<Option<cdr_encoding::Error>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+/// Takes the error recorded by the last failed serialization on this thread.
+pub(crate) fn take_serialization_error() -> Option<cdr_encoding::Error> {
+    SERIALIZATION_ERROR.take()
+}
+
+/// Records a failed serialization for the calling wrapper to pick up with
+/// [`take_serialization_error`].
+pub(crate) fn set_serialization_error(error: cdr_encoding::Error) {
+    diagnostic!(warn, "unable to serialize sample: {error}");
+    SERIALIZATION_ERROR.set(Some(error));
+}
+
+/// The encoding handed to Cyclone in place of a sample that could not be
+/// serialized: an encapsulation header without a body, which readers discard
+/// as undeserializable.
+///
+/// Written samples are checked by [`from_sample`], so this only stands in for
+/// a received sample that decoded but fails to encode again.
+const UNSERIALIZABLE: [u8; DDSI_RTPS_HEADER_SIZE] = [0x00, 0x01, 0x00, 0x00];
+
+/// Returns the serialized sample of `serdata` for a callback that has to hand
+/// Cyclone an encoding, recording the error and substituting
+/// [`UNSERIALIZABLE`] if the sample cannot be serialized.
+fn serialized_or_placeholder<T>(serdata: &Serdata<T>, size: usize) -> &[u8]
+where
+    T: crate::Topicable,
+{
+    serdata
+        .serialized_with_size_hint(size)
+        .unwrap_or_else(|error| {
+            set_serialization_error(error);
+            &UNSERIALIZABLE
+        })
+}
+
 /// This exists to ensure that initializing `cyclonedds_sys::ddsi_serdata_ops`
 /// will work in const contexts even if more fields are added down the line to
 /// Cyclone DDS.
@@ -34,8 +82,8 @@ pub unsafe extern "C" fn eqkey<T>(
 where
     T: crate::Topicable,
 {
-    let lhs = unsafe { &mut *(lhs as *mut Serdata<T>) };
-    let rhs = unsafe { &mut *(rhs as *mut Serdata<T>) };
+    let lhs = unsafe { &*(lhs.cast::<Serdata<T>>()) };
+    let rhs = unsafe { &*(rhs.cast::<Serdata<T>>()) };
 
     lhs.key() == rhs.key()
 }
@@ -50,66 +98,92 @@ pub unsafe extern "C" fn get_size<T>(serdata: *const cyclonedds_sys::ddsi_serdat
 where
     T: crate::Topicable,
 {
-    let serdata = unsafe { &mut *(serdata as *mut Serdata<T>) };
+    let serdata = unsafe { &*(serdata.cast::<Serdata<T>>()) };
 
-    u32::try_from(
-        serdata
-            .serialized()
-            .expect("unable to serialize data")
-            .len(),
-    )
-    .expect("serialized data out of bounds")
+    let serialized = serialized_or_placeholder(serdata, 0);
+    u32::try_from(serialized.len()).expect("serialized data out of bounds")
+}
+
+/// Deserializes a CDR-encoded value preceded by its encapsulation header, using
+/// the endianness and version indicated by the header.
+pub(crate) fn deserialize<T: serde::de::DeserializeOwned>(
+    buffer: &[u8],
+) -> Result<(T, usize), cdr_encoding::Error> {
+    match buffer.split_at_checked(DDSI_RTPS_HEADER_SIZE) {
+        Some((header, bytes)) => match (
+            Version::from_cdr_header(header),
+            Endianness::from_cdr_header(header),
+        ) {
+            (Some(Version::Xcdr1), Some(Endianness::Big)) => {
+                cdr_encoding::from_bytes::<T, byteorder::BigEndian>(bytes)
+            }
+            (Some(Version::Xcdr1), Some(Endianness::Little)) => {
+                cdr_encoding::from_bytes::<T, byteorder::LittleEndian>(bytes)
+            }
+            (Some(Version::Xcdr2), Some(endianness)) => {
+                crate::internal::cdr::from_bytes(bytes, endianness, Version::Xcdr2)
+            }
+            _ => Err(cdr_encoding::Error::Message(format!(
+                "could not determine endianness from CDR header: {header:?}"
+            ))),
+        },
+        None => Err(cdr_encoding::Error::Message(format!(
+            "deserialization failed: byteslice too short to contain valid CDR header: \
+             {buffer:?}"
+        ))),
+    }
 }
 
 pub(crate) fn from_ser_buffer<T>(
     sertype: &crate::internal::sertype::Sertype<T>,
     kind: crate::internal::serdata::Kind,
     buffer: &[u8],
-) -> *mut cyclonedds_sys::ddsi_serdata
+) -> Result<*mut cyclonedds_sys::ddsi_serdata, cdr_encoding::Error>
 where
     T: crate::Topicable,
 {
-    fn deserialize<'a, T: serde::Deserialize<'a>>(
-        buffer: &[u8],
-    ) -> Result<(T, usize), cdr_encoding::Error> {
-        match buffer.split_at_checked(DDSI_RTPS_HEADER_SIZE) {
-            Some((header, bytes)) => match header {
-                [0x0, 0x0, ..] => cdr_encoding::from_bytes::<T, byteorder::BigEndian>(bytes),
-                [0x0, 0x1, ..] => cdr_encoding::from_bytes::<T, byteorder::LittleEndian>(bytes),
-                _ => Err(cdr_encoding::Error::Message(format!(
-                    "could not determine endianness from CDR header: {header:?}"
-                ))),
-            },
-            None => Err(cdr_encoding::Error::Message(format!(
-                "deserialization failed: byteslice too short to contain valid CDR header: \
-                 {buffer:?}"
-            ))),
-        }
-    }
-
-    match kind {
+    let sample = match kind {
         crate::internal::serdata::Kind::Key => {
-            if let Ok((key, _)) = deserialize::<T::Key>(buffer) {
-                let key = SampleOrKey::new_key(key);
-                let serdata = Box::new(crate::internal::serdata::Serdata::new(sertype, key));
-                Box::into_raw(serdata).cast()
-            } else {
-                std::ptr::null_mut()
-            }
+            let (key, _) = deserialize::<T::Key>(buffer)?;
+            SampleOrKey::new_key(key)
         }
         crate::internal::serdata::Kind::Data => {
-            if let Ok((data, _)) = deserialize::<T>(buffer) {
-                let sample = SampleOrKey::new_sample(data);
-                let serdata = Box::new(crate::internal::serdata::Serdata::new(sertype, sample));
-                Box::into_raw(serdata).cast()
-            } else {
-                std::ptr::null_mut()
-            }
+            let (data, _) = deserialize::<T>(buffer)?;
+            SampleOrKey::new_sample(data)
         }
-    }
+    };
+    let serdata = Box::new(crate::internal::serdata::Serdata::new(sertype, sample));
+    Ok(Box::into_raw(serdata).cast())
+}
+
+/// Constructs a [`Serdata`] from a buffer received from the network.
+///
+/// A buffer that does not decode into `T` is dropped, but unlike a lost
+/// sample it is counted, logged and handed to the readers' callbacks so that
+/// a type mismatch between applications does not go unnoticed.
+fn from_received_buffer<T>(
+    sertype: &crate::internal::sertype::Sertype<T>,
+    kind: crate::internal::serdata::Kind,
+    buffer: &[u8],
+) -> *mut cyclonedds_sys::ddsi_serdata
+where
+    T: crate::Topicable,
+{
+    from_ser_buffer(sertype, kind, buffer).unwrap_or_else(|error| {
+        diagnostic!(
+            warn,
+            "serdata_ops::from_ser<{}>: dropping undeserializable {kind:?} sample: {error}",
+            std::any::type_name::<T>()
+        );
+        sertype.undeserializable.record(buffer, &error);
+        std::ptr::null_mut()
+    })
 }
 
-fn copy_from_fragment(fragment_chain: &cyclonedds_sys::ddsi_rdata, size: usize) -> Option<Vec<u8>> {
+pub(crate) fn copy_from_fragment(
+    fragment_chain: &cyclonedds_sys::ddsi_rdata,
+    size: usize,
+) -> Option<Vec<u8>> {
     if fragment_chain.min != 0 {
         return None;
     }
@@ -176,7 +250,7 @@ where
     crate::internal::serdata::Kind::try_from(kind).map_or(std::ptr::null_mut(), |kind| {
         let fragment_chain = unsafe { &*fragment_chain };
         copy_from_fragment(fragment_chain, size).map_or(std::ptr::null_mut(), |buffer| {
-            from_ser_buffer(sertype, kind, &buffer)
+            from_received_buffer(sertype, kind, &buffer)
         })
     })
 }
@@ -207,39 +281,52 @@ where
     let sertype = unsafe { &mut *(sertype as *mut Sertype<T>) };
 
     crate::internal::serdata::Kind::try_from(kind).map_or(std::ptr::null_mut(), |kind| {
-        let mut buffer: Vec<u8> = Vec::with_capacity(size);
+        unsafe { copy_from_iov(containers_len, containers, size) }
+            .map_or(std::ptr::null_mut(), |buffer| {
+                from_received_buffer(sertype, kind, &buffer)
+            })
+    })
+}
 
-        // `ddsrt_msg_iovlen_t` is already a `usize` under Linux
-        #[cfg(not(target_os = "linux"))]
-        let Ok(containers_len) = usize::try_from(containers_len) else {
-            return std::ptr::null_mut();
-        };
+/// Gather up to `size` bytes from the `containers` into a contiguous buffer.
+///
+/// ## Safety
+/// `containers` must point to a valid contiguous array of `ddsrt_iovec_t`
+/// structures of length `containers_len` whose `iov_base` pointers are valid
+/// for reads of `iov_len` bytes.
+pub(crate) unsafe fn copy_from_iov(
+    containers_len: cyclonedds_sys::ddsrt_msg_iovlen_t,
+    containers: *const cyclonedds_sys::ddsrt_iovec_t,
+    size: usize,
+) -> Option<Vec<u8>> {
+    let mut buffer: Vec<u8> = Vec::with_capacity(size);
 
-        let containers = unsafe { std::slice::from_raw_parts(containers, containers_len) };
+    // `ddsrt_msg_iovlen_t` is already a `usize` under Linux
+    #[cfg(not(target_os = "linux"))]
+    let containers_len = usize::try_from(containers_len).ok()?;
 
-        let mut offset = 0;
-        for container in containers {
-            let container_iov_len = container.iov_len;
+    let containers = unsafe { std::slice::from_raw_parts(containers, containers_len) };
 
-            // `ddsrt_iov_len_t` is a `usize` for every platform except Windows.
-            #[cfg(target_os = "windows")]
-            let Ok(container_iov_len) = usize::try_from(container_iov_len) else {
-                return std::ptr::null_mut();
-            };
+    let mut offset = 0;
+    for container in containers {
+        let container_iov_len = container.iov_len;
 
-            let len = if container_iov_len + offset > size {
-                size - offset
-            } else {
-                container_iov_len
-            };
+        // `ddsrt_iov_len_t` is a `usize` for every platform except Windows.
+        #[cfg(target_os = "windows")]
+        let container_iov_len = usize::try_from(container_iov_len).ok()?;
 
-            let container =
-                unsafe { std::slice::from_raw_parts(container.iov_base as *const u8, len) };
-            buffer.extend_from_slice(container);
-            offset += len;
-        }
-        from_ser_buffer(sertype, kind, &buffer)
-    })
+        let len = if container_iov_len + offset > size {
+            size - offset
+        } else {
+            container_iov_len
+        };
+
+        let container = unsafe { std::slice::from_raw_parts(container.iov_base as *const u8, len) };
+        buffer.extend_from_slice(container);
+        offset += len;
+    }
+
+    Some(buffer)
 }
 
 pub(crate) fn from_keyhash_with_mode<T>(
@@ -282,6 +369,31 @@ where
     from_keyhash_with_mode::<T>(sertype, keyhash, force_md5)
 }
 
+/// Encodes the sample or key without keeping the encoding, to check that it
+/// can be encoded at all.
+fn check_serializable<T>(sample: &SampleOrKey<T>) -> Result<(), cdr_encoding::Error>
+where
+    T: crate::Topicable,
+{
+    // The key is encoded big-endian on its own to compute the keyhash, which
+    // is also where its bounds are enforced.
+    cdr_encoding::to_writer::<_, byteorder::BigEndian, _>(std::io::sink(), sample.key())?;
+    match sample {
+        SampleOrKey::Sample { sample, .. } if T::OPTIONAL_MEMBERS => {
+            crate::internal::cdr::to_writer(
+                &mut SizeBuffer::default(),
+                sample,
+                Endianness::NATIVE,
+                Version::Xcdr2,
+            )
+        }
+        SampleOrKey::Sample { sample, .. } => {
+            cdr_encoding::to_writer::<_, byteorder::NativeEndian, _>(std::io::sink(), sample)
+        }
+        SampleOrKey::Key { .. } => Ok(()),
+    }
+}
+
 /// Constructs a [`Serdata`] from a sample pointer, given a serialization kind.
 ///
 /// ## Safety
@@ -295,41 +407,36 @@ where
     T: crate::Topicable,
 {
     let sample = unsafe { &*(sample.cast::<InternalSample<'_, T>>()) };
-    match (crate::internal::serdata::Kind::try_from(kind), sample) {
+    let sample = match (crate::internal::serdata::Kind::try_from(kind), sample) {
         (Ok(crate::internal::serdata::Kind::Data), InternalSample::SampleRef(sample)) => {
-            let sertype = unsafe { &mut *(sertype as *mut Sertype<T>) };
-
-            let sample = SampleOrKey::new_sample((*sample).clone());
-            let serdata = Box::new(Serdata::new(sertype, sample));
-
-            Box::into_raw(serdata).cast()
+            SampleOrKey::new_sample((*sample).clone())
         }
         (Ok(crate::internal::serdata::Kind::Data), InternalSample::Sample(sample)) => {
-            let sertype = unsafe { &mut *(sertype as *mut Sertype<T>) };
-
-            let sample = SampleOrKey::new_sample(sample.clone());
-            let serdata = Box::new(Serdata::new(sertype, sample));
-
-            Box::into_raw(serdata).cast()
+            SampleOrKey::new_sample(sample.clone())
         }
         (Ok(crate::internal::serdata::Kind::Key), InternalSample::KeyRef(key)) => {
-            let sertype = unsafe { &mut *(sertype as *mut Sertype<T>) };
-
-            let key = SampleOrKey::new_key((*key).clone());
-            let serdata = Box::new(Serdata::new(sertype, key));
-
-            Box::into_raw(serdata).cast()
+            SampleOrKey::new_key((*key).clone())
         }
         (Ok(crate::internal::serdata::Kind::Key), InternalSample::Key(key)) => {
-            let sertype = unsafe { &mut *(sertype as *mut Sertype<T>) };
-
-            let key = SampleOrKey::new_key(key.clone());
-            let serdata = Box::new(Serdata::new(sertype, key));
-
-            Box::into_raw(serdata).cast()
+            SampleOrKey::new_key(key.clone())
         }
-        _ => std::ptr::null_mut(),
+        _ => return std::ptr::null_mut(),
+    };
+
+    // The sample is only serialized once Cyclone needs its encoding, as
+    // samples delivered to readers in the same process never do. Whether it
+    // can be encoded is checked up front though, so that a sample that cannot
+    // be sent (e.g. exceeding its bounds) fails the write rather than some
+    // later delivery.
+    if let Err(error) = check_serializable(&sample) {
+        set_serialization_error(error);
+        return std::ptr::null_mut();
     }
+
+    let sertype = unsafe { &*(sertype.cast::<Sertype<T>>()) };
+    let serdata = Box::new(Serdata::new(sertype, sample));
+
+    Box::into_raw(serdata).cast()
 }
 
 /// TODO Unimplemented.
@@ -341,7 +448,8 @@ pub unsafe extern "C" fn to_ser<T>(
     c: *mut std::ffi::c_void,
 ) {
     let args = (serdata, a, b, c);
-    eprintln!(
+    diagnostic!(
+        warn,
         "serdata_ops::to_ser<{}>({args:?})",
         std::any::type_name::<T>()
     );
@@ -364,14 +472,12 @@ pub unsafe extern "C" fn to_ser_ref<T>(
 where
     T: crate::Topicable,
 {
-    let serdata = unsafe { &mut *(serdata as *mut Serdata<T>) };
+    let serdata = unsafe { &*(serdata.cast::<Serdata<T>>()) };
     let container = unsafe { &mut *container };
 
-    serdata
-        .serialized_with_size_hint(size)
-        .ok()
-        .and_then(|serialized| {
-            let slice = serialized.get(offset..)?;
+    serialized_or_placeholder(serdata, size)
+        .get(offset..)
+        .and_then(|slice| {
             container.iov_base = slice.as_ptr() as *mut _;
 
             let iov_len = slice.len();
@@ -422,7 +528,7 @@ where
     if sample.is_null() {
         false
     } else {
-        let serdata = unsafe { &mut *(serdata as *mut Serdata<T>) };
+        let serdata = unsafe { &*(serdata.cast::<Serdata<T>>()) };
 
         let sample = sample.cast::<InternalSample<'_, T>>();
         match serdata.kind() {
@@ -454,7 +560,7 @@ pub unsafe extern "C" fn to_untyped<T>(
 where
     T: crate::Topicable,
 {
-    let serdata = unsafe { &mut *(serdata as *mut Serdata<T>) };
+    let serdata = unsafe { &*(serdata.cast::<Serdata<T>>()) };
 
     let sertype = unsafe { &mut *(serdata.inner.type_ as *mut Sertype<T>) };
 
@@ -482,7 +588,7 @@ where
     if sample.is_null() {
         false
     } else {
-        let serdata = unsafe { &mut *(serdata as *mut Serdata<T>) };
+        let serdata = unsafe { &*(serdata.cast::<Serdata<T>>()) };
 
         let sample = sample.cast::<InternalSample<'_, T>>();
         match serdata.kind() {
@@ -516,9 +622,11 @@ where
 /// Writes the debug representation of a [`Serdata`] into the provided buffer.
 ///
 /// This output relies on `T`’s [`std::fmt::Debug`] implementation to display
-/// the sample held by the [`Serdata`]. At most `length` bytes are written. If
-/// the formatted string is longer, the result is truncated. The buffer is
-/// always null-terminated.
+/// the sample held by the [`Serdata`] (or `T::Key`'s for a key-only
+/// [`Serdata`]) on a single line, which is what Cyclone embeds in its traces.
+/// At most `length` bytes are written. If the formatted string is longer, the
+/// result is truncated at a character boundary. The buffer is always
+/// null-terminated.
 ///
 /// the return value is the number of bytes written to the buffer, **excluding**
 /// the null terminator.
@@ -527,8 +635,7 @@ where
 /// - `_sertype` must be either the [`Sertype<T>`] for `serdata` or the compatible type supplied by
 ///   Cyclone DDS when printing untyped serdata.
 /// - `serdata` must be a non-null pointer to a fully-initialized [`Serdata<T>`].
-/// - `buffer` must be non-null, valid for writes of `length` bytes, and `length` must be greater
-///   than zero so the required trailing NULL terminator can be written.
+/// - `buffer` must be null or valid for writes of `length` bytes.
 pub unsafe extern "C" fn print<T>(
     _sertype: *const cyclonedds_sys::ddsi_sertype,
     serdata: *const cyclonedds_sys::ddsi_serdata,
@@ -538,30 +645,73 @@ pub unsafe extern "C" fn print<T>(
 where
     T: crate::Topicable,
 {
-    let serdata = unsafe { &mut *(serdata as *mut Serdata<T>) };
+    let serdata = unsafe { &*(serdata.cast::<Serdata<T>>()) };
 
-    let buffer = unsafe { std::slice::from_raw_parts_mut(buffer.cast(), length) };
-    let mut cursor = std::io::Cursor::new(&mut *buffer);
+    match serdata.sample.as_ref() {
+        SampleOrKey::Sample { sample, .. } => unsafe { print_debug(sample, buffer, length) },
+        SampleOrKey::Key { key, .. } => unsafe { print_debug(key, buffer, length) },
+    }
+}
 
-    // The formatting here is best-effort so ignore a potential error when
-    // writing.
-    let _ = write!(cursor, "{:#?}", &*serdata);
+/// Writes `{value:?}` into `buffer`, truncating and null-terminating it.
+///
+/// Nothing is written if `buffer` is null or `length` is zero.
+///
+/// ## Safety
+/// `buffer` must be null or valid for writes of `length` bytes.
+pub(crate) unsafe fn print_debug(
+    value: &dyn std::fmt::Debug,
+    buffer: *mut std::ffi::c_char,
+    length: usize,
+) -> usize {
+    if buffer.is_null() || length == 0 {
+        return 0;
+    }
+    let buffer = unsafe { std::slice::from_raw_parts_mut(buffer.cast::<u8>(), length) };
+    let mut writer = TruncatingWriter { buffer, written: 0 };
 
-    // Ensure that whatever was written is null-terminated.
-    let written = cursor
-        .position()
-        .try_into()
-        .unwrap_or(length)
-        .min(length.saturating_sub(1));
+    // The formatting stops with an error once the buffer is full, which is
+    // exactly the truncation wanted here.
+    let _ = write!(writer, "{value:?}");
 
     #[allow(clippy::indexing_slicing)]
-    // `written` is guaranteed to be in bounds of the buffer because the
-    // cursor written comes from was a cursor  into that self-same buffer.
+    // `written` never exceeds `length - 1` as the writer always leaves room
+    // for the null terminator.
     {
-        buffer[written] = 0;
+        writer.buffer[writer.written] = 0;
     }
 
-    written
+    writer.written
+}
+
+/// A [`std::fmt::Write`] into a fixed-size buffer that keeps its last byte free
+/// for a null terminator and only ever writes whole characters, failing once
+/// the next one no longer fits.
+struct TruncatingWriter<'buffer> {
+    buffer: &'buffer mut [u8],
+    written: usize,
+}
+
+impl std::fmt::Write for TruncatingWriter<'_> {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        let remaining = self.buffer.len().saturating_sub(self.written + 1);
+        let fits = (0..=s.len().min(remaining))
+            .rev()
+            .find(|&index| s.is_char_boundary(index))
+            .unwrap_or(0);
+
+        let (_, buffer) = self.buffer.split_at_mut(self.written);
+        let (buffer, _) = buffer.split_at_mut(fits);
+        let (bytes, _) = s.as_bytes().split_at(fits);
+        buffer.copy_from_slice(bytes);
+        self.written += fits;
+
+        if fits == s.len() {
+            Ok(())
+        } else {
+            Err(std::fmt::Error)
+        }
+    }
 }
 
 ///
@@ -573,11 +723,19 @@ pub unsafe extern "C" fn get_keyhash<T>(
 ) where
     T: crate::Topicable,
 {
-    let serdata = unsafe { &mut *(serdata as *mut Serdata<T>) };
+    let serdata = unsafe { &*(serdata.cast::<Serdata<T>>()) };
     let keyhash = unsafe { &mut *keyhash };
 
-    KeyHash::from_key::<T>(serdata.key(), force_md5)
-        .inspect(|serdata_keyhash| keyhash.value.copy_from_slice(&serdata_keyhash.0));
+    // NOTE: keys exceeding their bounds are rejected by `from_sample`, so this
+    // only fails for such a key received from a remote writer, which is then
+    // given an all-zero key hash.
+    keyhash.value = KeyHash::from_key::<T>(serdata.key(), force_md5).map_or_else(
+        |error| {
+            set_serialization_error(error);
+            [0; 16]
+        },
+        |serdata_keyhash| serdata_keyhash.0,
+    );
 }
 
 /// TODO Unimplemented.
@@ -593,7 +751,8 @@ where
     T: crate::Topicable,
 {
     let args = (sertype, a, b, c, d);
-    eprintln!(
+    diagnostic!(
+        warn,
         "serdata_ops::from_loaned_sample<{}>({args:?})",
         std::any::type_name::<T>()
     );
@@ -611,7 +770,8 @@ where
 {
     let args = (sertype, loan);
 
-    eprintln!(
+    diagnostic!(
+        warn,
         "serdata_ops::from_psmx<{}>({args:?})",
         std::any::type_name::<T>()
     );
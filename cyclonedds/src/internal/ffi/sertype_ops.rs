@@ -4,10 +4,10 @@
 use std::ffi::CStr;
 use std::hash::{Hash, Hasher};
 
+use crate::dynamic::cdr::{Endianness, SliceBuffer, Version};
 use crate::internal::ffi::InternalSample;
 use crate::internal::ffi::serdata_ops::DDSI_RTPS_HEADER_SIZE;
 use crate::internal::sertype::Sertype;
-use crate::internal::traits::CdrHeader;
 
 /// A version to allow Cyclone DDS to ensure backwards compatibility if breaking
 /// changes to the sertype API are introduced.
@@ -84,6 +84,22 @@ where
     drop(sertype);
 }
 
+/// A sample that can live in a buffer managed through the sertype's sample
+/// operations.
+pub(crate) trait EmptySample {
+    /// The placeholder written into freshly allocated or zeroed slots.
+    fn empty() -> Self;
+}
+
+impl<T> EmptySample for InternalSample<'_, T>
+where
+    T: crate::Topicable,
+{
+    fn empty() -> Self {
+        InternalSample::None
+    }
+}
+
 /// Zero out samples.
 ///
 /// This is used for generating samples from just a key value and in cleaning up
@@ -95,12 +111,19 @@ pub unsafe extern "C" fn zero_samples<T>(
 ) where
     T: crate::Topicable,
 {
-    let samples = samples.cast::<InternalSample<'_, T>>();
+    unsafe { zero_samples_of::<InternalSample<'_, T>>(samples, count) };
+}
+
+pub(crate) unsafe fn zero_samples_of<S>(samples: *mut std::ffi::c_void, count: usize)
+where
+    S: EmptySample,
+{
+    let samples = samples.cast::<S>();
 
     for i in 0..count {
         unsafe {
             let pointer = samples.add(i);
-            pointer.write(InternalSample::None);
+            pointer.write(S::empty());
         }
     }
 }
@@ -115,8 +138,21 @@ pub unsafe extern "C" fn realloc_samples<T>(
 ) where
     T: crate::Topicable,
 {
-    let pointers = pointers.cast::<*mut InternalSample<'_, T>>();
-    let old_samples = old_samples.cast::<InternalSample<'_, T>>();
+    unsafe {
+        realloc_samples_of::<InternalSample<'_, T>>(pointers, old_samples, old_count, new_count);
+    }
+}
+
+pub(crate) unsafe fn realloc_samples_of<S>(
+    pointers: *mut *mut std::ffi::c_void,
+    old_samples: *mut std::ffi::c_void,
+    old_count: usize,
+    new_count: usize,
+) where
+    S: EmptySample,
+{
+    let pointers = pointers.cast::<*mut S>();
+    let old_samples = old_samples.cast::<S>();
 
     let pointers = unsafe { std::slice::from_raw_parts_mut(pointers, new_count) };
 
@@ -147,12 +183,12 @@ pub unsafe extern "C" fn realloc_samples<T>(
 
         // Allocate new samples for additional slots
         for pointer in remaining_slots {
-            *pointer = Box::into_raw(Box::new(InternalSample::None));
+            *pointer = Box::into_raw(Box::new(S::empty()));
         }
     } else {
         // Allocate new samples for additional slots
         for pointer in pointers {
-            *pointer = Box::into_raw(Box::new(InternalSample::None));
+            *pointer = Box::into_raw(Box::new(S::empty()));
         }
     }
 }
@@ -173,6 +209,14 @@ pub unsafe extern "C" fn free_samples<T>(
 ) where
     T: crate::Topicable,
 {
+    unsafe { free_samples_of::<InternalSample<'_, T>>(pointers, count, operation) };
+}
+
+pub(crate) unsafe fn free_samples_of<S>(
+    pointers: *mut *mut std::ffi::c_void,
+    count: usize,
+    operation: cyclonedds_sys::dds_free_op_t,
+) {
     // NOTE: `operation` is an enum with a different backing type under Windows
     // (i32) and the rest of the operating systems (u32). For the Rust API I've
     // chosen to standardize its internal representation against u32 and so the
@@ -183,7 +227,7 @@ pub unsafe extern "C" fn free_samples<T>(
 
     let free_all = operation & cyclonedds_sys::DDS_FREE_ALL_BIT != 0;
     let free_contents = operation & cyclonedds_sys::DDS_FREE_CONTENTS_BIT != 0;
-    let samples = pointers.cast::<*mut InternalSample<'_, T>>();
+    let samples = pointers.cast::<*mut S>();
 
     if free_all {
         for i in 0..count {
@@ -261,7 +305,8 @@ where
     T: crate::Topicable,
 {
     let args = (sertype, kind);
-    eprintln!(
+    diagnostic!(
+        warn,
         "sertype_ops::type_id<{}>({args:?})",
         std::any::type_name::<T>()
     );
@@ -279,7 +324,8 @@ where
     T: crate::Topicable,
 {
     let args = sertype;
-    eprintln!(
+    diagnostic!(
+        warn,
         "sertype_ops::type_map<{}>({args:?})",
         std::any::type_name::<T>()
     );
@@ -296,7 +342,8 @@ where
     T: crate::Topicable,
 {
     let args = sertype;
-    eprintln!(
+    diagnostic!(
+        warn,
         "sertype_ops::type_info<{}>({args:?})",
         std::any::type_name::<T>()
     );
@@ -319,7 +366,8 @@ where
         data_representation,
         type_consistency_enforcement_qos,
     );
-    eprintln!(
+    diagnostic!(
+        warn,
         "sertype_ops::derive_sertype<{}>({args:?})",
         std::any::type_name::<T>()
     );
@@ -340,7 +388,8 @@ where
     T: crate::Topicable,
 {
     let args = (sertype, serdata_kind, sample, size, encoding_identifier);
-    eprintln!(
+    diagnostic!(
+        warn,
         "sertype_ops::get_serialized_size<{}>({args:?})",
         std::any::type_name::<T>()
     );
@@ -363,8 +412,14 @@ where
         std::slice::from_raw_parts_mut(destination_buffer.cast(), destination_buffer_length)
     };
 
+    // Samples of types with optional members are encoded in XCDR2, everything
+    // else, keys included, in XCDR1.
+    let version = match crate::internal::serdata::Kind::try_from(serdata_kind) {
+        Ok(crate::internal::serdata::Kind::Data) if T::OPTIONAL_MEMBERS => Version::Xcdr2,
+        _ => Version::Xcdr1,
+    };
     if let Some(header) = buffer.get_mut(0..DDSI_RTPS_HEADER_SIZE) {
-        header.copy_from_slice(&byteorder::NativeEndian::cdr_header());
+        header.copy_from_slice(&Endianness::NATIVE.cdr_header(version));
     } else {
         return false;
     }
@@ -377,6 +432,21 @@ where
             crate::internal::serdata::Kind::try_from(serdata_kind),
             sample,
         ) {
+            (Ok(crate::internal::serdata::Kind::Data), InternalSample::Sample(sample))
+                if T::OPTIONAL_MEMBERS =>
+            {
+                buffer
+                    .get_mut(DDSI_RTPS_HEADER_SIZE..)
+                    .is_some_and(|bytes| {
+                        crate::internal::cdr::to_writer(
+                            &mut SliceBuffer::new(bytes),
+                            sample,
+                            Endianness::NATIVE,
+                            Version::Xcdr2,
+                        )
+                        .is_ok()
+                    })
+            }
             (Ok(crate::internal::serdata::Kind::Data), InternalSample::Sample(sample)) => buffer
                 .get_mut(DDSI_RTPS_HEADER_SIZE..)
                 .is_some_and(|bytes| {
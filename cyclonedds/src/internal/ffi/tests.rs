@@ -274,7 +274,7 @@ fn test_serdata_ops_from_keyhash() {
     let result = serdata_ops::from_keyhash_with_mode::<Data>(&sertype, &keyhash, false);
     assert_ne!(result, std::ptr::null_mut());
 
-    let mut serdata =
+    let serdata =
         unsafe { Box::from_raw(result.cast::<crate::internal::serdata::Serdata<Data>>()) };
     assert_eq!(serdata.kind(), crate::internal::serdata::Kind::Key);
     assert_eq!(serdata.key(), &key);
@@ -285,7 +285,7 @@ fn test_serdata_ops_from_keyhash() {
         unsafe { serdata_ops::from_keyhash::<Data>(&raw const sertype.inner, &raw const keyhash) };
     assert_ne!(result, std::ptr::null_mut());
 
-    let mut serdata =
+    let serdata =
         unsafe { Box::from_raw(result.cast::<crate::internal::serdata::Serdata<Data>>()) };
     assert_eq!(serdata.kind(), crate::internal::serdata::Kind::Key);
     assert_eq!(serdata.key(), &key);
@@ -473,6 +473,88 @@ fn test_serdata_ops_to_sample() {
     let _ = Box::into_raw(sertype);
 }
 
+#[test]
+fn test_serdata_ops_print() {
+    let type_name =
+        std::ffi::CString::new(crate::tests::topic::Data::dds_type_name().as_ref()).unwrap();
+    let topic_has_key = crate::tests::topic::Data::IS_KEYED;
+    let mut sertype = Box::new(
+        crate::internal::sertype::Sertype::<crate::tests::topic::Data>::new(
+            &type_name,
+            topic_has_key,
+        ),
+    );
+
+    let sample = crate::tests::topic::Data {
+        x: 101,
+        y: 202,
+        message: "héllo".to_string(),
+    };
+    let serdata = crate::internal::serdata::Serdata::new(
+        &sertype,
+        crate::sample::SampleOrKeyInner::new_sample(sample.clone()),
+    );
+    let print = |length: usize| {
+        let mut buffer = vec![0x7f_u8; length];
+        let written = unsafe {
+            serdata_ops::print::<crate::tests::topic::Data>(
+                &raw const sertype.inner,
+                &raw const serdata.inner,
+                buffer.as_mut_ptr().cast(),
+                length,
+            )
+        };
+        let terminator = buffer.iter().position(|&byte| byte == 0).unwrap();
+        assert_eq!(written, terminator);
+        String::from_utf8(buffer[..written].to_vec()).unwrap()
+    };
+
+    // The whole sample fits.
+    let expected = format!("{sample:?}");
+    assert_eq!(print(256), expected);
+    assert_eq!(print(expected.len() + 1), expected);
+
+    // The sample is truncated to leave room for the null terminator.
+    assert_eq!(print(expected.len()), expected[..expected.len() - 1]);
+    assert_eq!(print(1), "");
+
+    // Truncation never splits a multi-byte character.
+    let split = expected.find('é').unwrap() + 1;
+    assert_eq!(print(split + 1), expected[..split - 1]);
+
+    // Nothing is written to an empty or missing buffer.
+    let written = unsafe {
+        serdata_ops::print::<crate::tests::topic::Data>(
+            &raw const sertype.inner,
+            &raw const serdata.inner,
+            std::ptr::null_mut(),
+            16,
+        )
+    };
+    assert_eq!(written, 0);
+
+    // A key-only serdata prints just the key.
+    let key = sample.as_key();
+    let serdata = crate::internal::serdata::Serdata::new(
+        &sertype,
+        crate::sample::SampleOrKeyInner::new_key(key),
+    );
+    let mut buffer = [0_u8; 64];
+    let written = unsafe {
+        serdata_ops::print::<crate::tests::topic::Data>(
+            &raw const sertype.inner,
+            &raw const serdata.inner,
+            buffer.as_mut_ptr().cast(),
+            buffer.len(),
+        )
+    };
+    assert_eq!(&buffer[..written], format!("{key:?}").as_bytes());
+    assert_eq!(buffer[written], 0);
+
+    crate::internal::ffi::ddsi_sertype_unref(&mut sertype.inner);
+    let _ = Box::into_raw(sertype);
+}
+
 #[test]
 fn test_serdata_ops_from_sample() {
     let type_name =
@@ -615,6 +697,85 @@ fn test_serdata_ops_from_sample() {
     let _ = Box::into_raw(sertype);
 }
 
+#[test]
+fn test_serdata_ops_unserializable_sample() {
+    #[derive(Debug, Clone, serde::Deserialize)]
+    struct Unserializable;
+
+    impl serde::Serialize for Unserializable {
+        fn serialize<S: serde::Serializer>(&self, _: S) -> Result<S::Ok, S::Error> {
+            Err(serde::ser::Error::custom("unserializable"))
+        }
+    }
+
+    impl Topicable for Unserializable {
+        type Key = ();
+
+        fn from_key((): &Self::Key) -> Self {
+            Self
+        }
+
+        fn as_key(&self) -> Self::Key {}
+    }
+
+    let type_name = std::ffi::CString::new(Unserializable::dds_type_name().as_ref()).unwrap();
+    let mut sertype = Box::new(crate::internal::sertype::Sertype::<Unserializable>::new(
+        &type_name,
+        Unserializable::IS_KEYED,
+    ));
+
+    // A sample that cannot be encoded is rejected, failing the write.
+    let _ = serdata_ops::take_serialization_error();
+    let sample = crate::internal::ffi::InternalSample::<Unserializable>::Sample(Unserializable);
+    let serdata = unsafe {
+        serdata_ops::from_sample::<Unserializable>(
+            &raw const sertype.inner,
+            crate::internal::serdata::Kind::Data.into(),
+            (&raw const sample).cast(),
+        )
+    };
+    assert_eq!(serdata, std::ptr::null_mut());
+    let error = serdata_ops::take_serialization_error().unwrap();
+    assert!(error.to_string().contains("unserializable"));
+
+    // A sample that did not go through `from_sample`, e.g. a received one,
+    // is only serialized once its encoding is needed.
+    let serdata = Box::into_raw(Box::new(crate::internal::serdata::Serdata::new(
+        &sertype,
+        SampleOrKeyInner::new_sample(Unserializable),
+    )))
+    .cast::<cyclonedds_sys::ddsi_serdata>();
+
+    // Cyclone is handed an empty body, with the error kept for the caller.
+    let size = unsafe { serdata_ops::get_size::<Unserializable>(serdata) };
+    assert_eq!(size as usize, DDSI_RTPS_HEADER_SIZE);
+    let error = serdata_ops::take_serialization_error().unwrap();
+    assert!(error.to_string().contains("unserializable"));
+
+    let mut container = cyclonedds_sys::ddsrt_iovec_t {
+        iov_base: std::ptr::null_mut(),
+        iov_len: 0,
+    };
+    let reference = unsafe {
+        serdata_ops::to_ser_ref::<Unserializable>(
+            serdata,
+            0,
+            DDSI_RTPS_HEADER_SIZE,
+            &raw mut container,
+        )
+    };
+    assert_ne!(reference, std::ptr::null_mut());
+    assert_eq!(container.iov_len as usize, DDSI_RTPS_HEADER_SIZE);
+    assert!(serdata_ops::take_serialization_error().is_some());
+    unsafe { serdata_ops::to_ser_unref::<Unserializable>(reference, &raw const container) };
+
+    let serdata =
+        unsafe { &mut *(serdata.cast::<crate::internal::serdata::Serdata<Unserializable>>()) };
+    crate::internal::ffi::ddsi_serdata_unref(&mut serdata.inner);
+    crate::internal::ffi::ddsi_sertype_unref(&mut sertype.inner);
+    let _ = Box::into_raw(sertype);
+}
+
 #[test]
 fn test_serdata_ops_from_ser() {
     let type_name =
@@ -6,33 +6,44 @@ use crate::cdr_bounds::{CdrBounds, CdrSize};
 pub struct KeyHash(pub(crate) [u8; 16]);
 
 impl KeyHash {
-    pub fn from_key<T>(key: &T::Key, force_md5: bool) -> Option<KeyHash>
+    pub fn from_key<T>(key: &T::Key, force_md5: bool) -> Result<KeyHash, cdr_encoding::Error>
     where
         T: crate::Topicable,
     {
-        cdr_encoding::to_vec::<_, byteorder::BigEndian>(&key)
-            .ok()
-            .and_then(|mut serialized| {
-                let max_possible_serialized_size = T::Key::max_serialized_cdr_size();
-
-                let key_hash = if force_md5 || max_possible_serialized_size > CdrSize::Bounded(16) {
-                    // The key hash should be computed via MD5.
-                    let mut hasher = md5::Md5::new();
-                    hasher.update(serialized);
-                    let hash = hasher.finalize();
-                    hash.into()
-                } else {
-                    // The CDR serialized form fits and can be used as the key hash but
-                    // it must be padded to 16 bytes and those padding bytes must be zeroed.
-                    serialized.resize(16.max(serialized.len()), 0);
-                    // This should only fail if `max_serialized_cdr_size()` is incorrect, e.g. it
-                    // reports a maximum size of 16 bytes or less while the serialized key is
-                    // actually larger than 16 bytes.
-                    serialized.try_into().ok()?
-                };
-
-                Some(KeyHash(key_hash))
-            })
+        cdr_encoding::to_vec::<_, byteorder::BigEndian>(&key).map(|serialized| {
+            Self::from_serialized_key(serialized, T::Key::max_serialized_cdr_size(), force_md5)
+        })
+    }
+
+    /// Compute the key hash from the big-endian CDR serialized form of a key
+    /// whose serialized size is at most `max_possible_serialized_size`.
+    ///
+    /// A key that does not fit in 16 bytes despite its reported maximum size
+    /// is hashed with MD5 as well, so a key hash is always produced.
+    pub fn from_serialized_key(
+        mut serialized: Vec<u8>,
+        max_possible_serialized_size: CdrSize,
+        force_md5: bool,
+    ) -> KeyHash {
+        let key_hash = if force_md5
+            || max_possible_serialized_size > CdrSize::Bounded(16)
+            || serialized.len() > 16
+        {
+            // The key hash should be computed via MD5.
+            let mut hasher = md5::Md5::new();
+            hasher.update(serialized);
+            let hash = hasher.finalize();
+            hash.into()
+        } else {
+            // The CDR serialized form fits and can be used as the key hash but
+            // it must be padded to 16 bytes and those padding bytes must be zeroed.
+            let mut key_hash = [0; 16];
+            serialized.resize(16, 0);
+            key_hash.copy_from_slice(&serialized);
+            key_hash
+        };
+
+        KeyHash(key_hash)
     }
 }
 
@@ -97,15 +108,15 @@ mod tests {
         assert_eq!(sample, Data::from_key(&deserialized_key));
 
         let key = DataKey { x: vec![0; 32] };
-        // Check that since the serialized form would be over the 16-byte bound
-        // the result was None.
-        let cdr_key_hash = KeyHash::from_key::<Data>(&key, false);
-        assert_eq!(cdr_key_hash, None);
-
         // Check that even with the invalid serialization limit the keyhash under md5
         // still succeeds.
         let md5_key_hash_01 = KeyHash::from_key::<Data>(&key, true).unwrap();
 
+        // Check that since the serialized form would be over the 16-byte bound
+        // the keyhash falls back to md5.
+        let cdr_key_hash = KeyHash::from_key::<Data>(&key, false).unwrap();
+        assert_eq!(cdr_key_hash, md5_key_hash_01);
+
         *MOCKED_MAX_SERIALIZED_CDR_SIZE.lock().unwrap() = 4 * 32 + 4;
 
         // Check that with a serialization limit over the bound the keyhash
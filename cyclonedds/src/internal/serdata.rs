@@ -1,6 +1,7 @@
 //! The [`Serdata`] represents the extension point for language-bindings to
 //! interact with serialized sample data in Cyclone.
 
+use crate::dynamic::cdr::{Endianness, Version};
 use crate::internal::ffi;
 use crate::internal::sertype::Sertype;
 use crate::internal::traits::{CdrHeader, Hash32};
@@ -14,7 +15,7 @@ where
     T: crate::Topicable,
 {
     pub(crate) inner: cyclonedds_sys::ddsi_serdata,
-    pub(crate) serialized_sample: std::cell::OnceCell<Vec<u8>>,
+    pub(crate) serialized_sample: std::sync::OnceLock<Vec<u8>>,
     pub(crate) sample: std::sync::Arc<SampleOrKey<T>>,
 }
 
@@ -36,53 +37,30 @@ where
         Self {
             inner,
             sample: std::sync::Arc::new(sample),
-            serialized_sample: std::cell::OnceCell::new(),
+            serialized_sample: std::sync::OnceLock::new(),
         }
     }
 
     /// Get a reference to the sample stored by the [`Serdata`].
-    pub fn sample(&mut self) -> &T {
+    pub fn sample(&self) -> &T {
         self.sample.as_ref().sample()
     }
 
-    pub fn key(&mut self) -> &T::Key {
+    pub fn key(&self) -> &T::Key {
         self.sample.as_ref().key()
     }
 
-    pub fn serialized(&mut self) -> Result<&[u8], cdr_encoding::Error> {
-        // NOTE: this initially used self.serialized_sample.get_or_init() but since the
-        // serialization can fail this has to be done as follows.
-        //
-        // TODO: swap this out for `get_or_try_init()` when that is available.
-        if let Some(serialized_sample) = self.serialized_sample.get() {
-            Ok(serialized_sample)
-        } else {
-            let mut serialized: Vec<_> =
-                byteorder::NativeEndian::cdr_header().into_iter().collect();
-            match self.sample.as_ref() {
-                SampleOrKey::Sample { sample, .. } => cdr_encoding::to_writer::<
-                    _,
-                    byteorder::NativeEndian,
-                    _,
-                >(&mut serialized, sample),
-                SampleOrKey::Key { key, .. } => {
-                    cdr_encoding::to_writer::<_, byteorder::NativeEndian, _>(&mut serialized, key)
-                }
-            }
-            .map(|()| {
-                // SAFETY: guaranteed because in this branch the serialized sample is unset.
-                self.serialized_sample.set(serialized).unwrap();
-                // SAFETY: guaranteed because the set call was successful by here.
-                self.serialized_sample.get().unwrap().as_ref()
-            })
-        }
+    pub fn serialized(&self) -> Result<&[u8], cdr_encoding::Error> {
+        self.serialized_with_size_hint(0)
     }
 
-    pub fn serialized_with_size_hint(&mut self, size: usize) -> Result<&[u8], cdr_encoding::Error> {
-        if let Some(serialized_sample) = self.serialized_sample.get_mut() {
-            serialized_sample.resize(size.max(serialized_sample.len()), 0);
-        }
-
+    /// Get the serialized sample, padding it with zeroes up to `size` bytes if
+    /// this is the call that serializes it.
+    ///
+    /// A [`Serdata`] is shared between Cyclone's threads, so once serialized
+    /// the bytes are never modified again as other threads may hold references
+    /// into them.
+    pub fn serialized_with_size_hint(&self, size: usize) -> Result<&[u8], cdr_encoding::Error> {
         // NOTE: this initially used self.serialized_sample.get_or_init() but since the
         // serialization can fail this has to be done as follows.
         //
@@ -91,22 +69,34 @@ where
             Ok(serialized_sample)
         } else {
             let mut serialized = Vec::with_capacity(size);
-            serialized.extend(&byteorder::NativeEndian::cdr_header());
             match self.sample.as_ref() {
-                SampleOrKey::Sample { sample, .. } => cdr_encoding::to_writer::<
-                    _,
-                    byteorder::NativeEndian,
-                    _,
-                >(&mut serialized, sample),
+                SampleOrKey::Sample { sample, .. } if T::OPTIONAL_MEMBERS => {
+                    serialized.extend(&Endianness::NATIVE.cdr_header(Version::Xcdr2));
+                    crate::internal::cdr::to_writer(
+                        &mut serialized,
+                        sample,
+                        Endianness::NATIVE,
+                        Version::Xcdr2,
+                    )
+                }
+                SampleOrKey::Sample { sample, .. } => {
+                    serialized.extend(&byteorder::NativeEndian::cdr_header());
+                    cdr_encoding::to_writer::<_, byteorder::NativeEndian, _>(
+                        &mut serialized,
+                        sample,
+                    )
+                }
                 SampleOrKey::Key { key, .. } => {
+                    serialized.extend(&byteorder::NativeEndian::cdr_header());
                     cdr_encoding::to_writer::<_, byteorder::NativeEndian, _>(&mut serialized, key)
                 }
             }
             .map(|()| {
                 serialized.resize(size.max(serialized.len()), 0);
-                // SAFETY: guaranteed because in this branch the serialized sample is unset.
-                self.serialized_sample.set(serialized).unwrap();
-                // SAFETY: guaranteed because the set call was successful by here.
+                // Another thread may have raced this one to serialize the
+                // sample, in which case its (identical) serialization is kept.
+                let _ = self.serialized_sample.set(serialized);
+                // SAFETY: guaranteed because the serialized sample is set by here.
                 self.serialized_sample.get().unwrap().as_ref()
             })
         }
@@ -204,8 +194,8 @@ mod tests {
             Default::default(),
         );
 
-        let mut sample_serdata = Serdata::new(&sertype, sample);
-        let mut key_serdata = Serdata::new(&sertype, key);
+        let sample_serdata = Serdata::new(&sertype, sample);
+        let key_serdata = Serdata::new(&sertype, key);
 
         let serialized_01: Vec<_> = sample_serdata
             .serialized_with_size_hint(20)
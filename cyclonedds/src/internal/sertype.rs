@@ -15,9 +15,119 @@ where
     T: crate::Topicable,
 {
     pub(crate) inner: cyclonedds_sys::ddsi_sertype,
+    pub(crate) undeserializable: UndeserializableSamples,
     phantom: std::marker::PhantomData<T>,
 }
 
+/// A reader's [`UndeserializableCallback`](crate::status::UndeserializableCallback),
+/// shared so that it can be invoked without holding the lock.
+type SharedUndeserializableCallback =
+    std::sync::Arc<dyn Fn(&[u8], &dyn std::error::Error) + Send + Sync>;
+
+/// Bookkeeping for received samples that could not be deserialized into `T`.
+///
+/// Cyclone deserializes a sample once per sertype rather than once per reader
+/// and the sertype does not know which topic or readers the sample was meant
+/// for, so this is a per-type counter: a failure counts once for the sertype,
+/// which is shared by every topic of the type in the domain. Each registered
+/// reader only keeps the count it last retrieved (for the `delta`) and its
+/// callback, which is invoked for every failure of the type.
+#[derive(Debug, Default)]
+pub(crate) struct UndeserializableSamples {
+    state: std::sync::Mutex<UndeserializableState>,
+}
+
+#[derive(Debug, Default)]
+struct UndeserializableState {
+    count: u32,
+    readers: std::collections::HashMap<cyclonedds_sys::dds_entity_t, ReaderUndeserializable>,
+}
+
+/// The undeserializable sample state of a single reader.
+#[derive(Default)]
+struct ReaderUndeserializable {
+    count_at_last_take: u32,
+    callback: Option<SharedUndeserializableCallback>,
+}
+
+impl std::fmt::Debug for ReaderUndeserializable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReaderUndeserializable")
+            .field("count_at_last_take", &self.count_at_last_take)
+            .field("callback", &self.callback.is_some())
+            .finish()
+    }
+}
+
+impl UndeserializableSamples {
+    fn state(&self) -> std::sync::MutexGuard<'_, UndeserializableState> {
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Starts reporting undeserializable samples of the type to `reader`.
+    pub(crate) fn register(&self, reader: cyclonedds_sys::dds_entity_t) {
+        self.state().readers.entry(reader).or_default();
+    }
+
+    /// Stops reporting undeserializable samples of the type to `reader`.
+    pub(crate) fn unregister(&self, reader: cyclonedds_sys::dds_entity_t) {
+        self.state().readers.remove(&reader);
+    }
+
+    /// Accounts for a sample that failed to deserialize with `error` and
+    /// forwards it to the registered callbacks, if any.
+    pub(crate) fn record(&self, serialized: &[u8], error: &cdr_encoding::Error) {
+        // NOTE: the callbacks are collected and invoked after the lock is
+        // released so that a callback can query the status of its reader.
+        let callbacks = {
+            let mut state = self.state();
+            state.count = state.count.wrapping_add(1);
+            state
+                .readers
+                .values()
+                .filter_map(|reader| reader.callback.clone())
+                .collect::<Vec<_>>()
+        };
+        for callback in callbacks {
+            callback(serialized, error);
+        }
+    }
+
+    /// Returns the running count of the type and resets the delta of
+    /// `reader`, or `None` if `reader` is not registered.
+    pub(crate) fn take_status(
+        &self,
+        reader: cyclonedds_sys::dds_entity_t,
+    ) -> Option<crate::status::SampleUndeserializable> {
+        let mut state = self.state();
+        let count = state.count;
+        let reader = state.readers.get_mut(&reader)?;
+        let previous = std::mem::replace(&mut reader.count_at_last_take, count);
+        Some(crate::status::SampleUndeserializable {
+            total: crate::status::Counter {
+                count,
+                delta: i32::try_from(count.wrapping_sub(previous)).unwrap_or(i32::MAX),
+            },
+        })
+    }
+
+    /// Sets the callback of `reader`, returning `false` if `reader` is not
+    /// registered.
+    pub(crate) fn set_callback(
+        &self,
+        reader: cyclonedds_sys::dds_entity_t,
+        callback: Option<crate::status::UndeserializableCallback>,
+    ) -> bool {
+        self.state()
+            .readers
+            .get_mut(&reader)
+            .map(|reader| reader.callback = callback.map(Into::into))
+            .is_some()
+    }
+}
+
 impl<T> Sertype<T>
 where
     T: crate::Topicable,
@@ -88,6 +198,7 @@ where
 
         Sertype {
             inner,
+            undeserializable: UndeserializableSamples::default(),
             phantom: std::marker::PhantomData,
         }
     }
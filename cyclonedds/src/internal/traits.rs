@@ -31,6 +31,14 @@ pub(crate) trait AsFfi {
     fn as_ffi(&self) -> Self::Target<'_>;
 }
 
+pub(crate) trait FromFfi: Sized {
+    type Source;
+
+    /// Converts the raw FFI representation back into the Rust type, returning
+    /// [`None`] if the representation holds a value unknown to the binding.
+    fn from_ffi(source: Self::Source) -> Option<Self>;
+}
+
 pub trait CdrHeader {
     fn cdr_header() -> [u8; 4];
 }
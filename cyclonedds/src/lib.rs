@@ -62,7 +62,7 @@
 //! [`WaitSets`](WaitSet): to allow you to block until a particular status
 //! occurs on a DDS entity. [`Listeners`](Listener): to notify applications of a
 //! change in the status of a particular entity.
-//! [`GuardConditions`](GuardCondition), `StatusConditions`,
+//! [`GuardConditions`](GuardCondition), [`StatusConditions`](StatusCondition),
 //! [`ReadConditions`](ReadCondition), and [`QueryConditions`](QueryCondition):
 //! Mechanisms to trigger the condition associated with a waitset.
 //!
@@ -146,6 +146,16 @@
 //! # Ok::<_, cyclonedds::Error>(())
 //! ```
 //!
+//! # Thread safety
+//!
+//! Cyclone DDS is thread-safe, so all entities ([`Participant`], [`Topic`],
+//! [`Writer`], [`Reader`], etc.), [`QoS`], and received
+//! [`samples`](sample::SampleOrKey) are [`Send`] + [`Sync`]. A single writer
+//! can be shared by many threads writing concurrently, and a reader can be
+//! taken from by one thread while another waits on it. The borrowed lifetimes
+//! still tie each entity to its parent; see [`owned`] for handles that can be
+//! moved into threads freely.
+//!
 //! For further reading, see the [Cyclone DDS
 //! documentation](https://cyclonedds.io), the [OMG DDS
 //! specification](https://www.omg.org/spec/DDS/), and the
@@ -168,13 +178,20 @@
     )
 )]
 
+pub mod bounded;
+pub mod bridge;
+pub mod builtin;
 pub mod cdr_bounds;
 mod domain;
 mod duration;
+pub mod dynamic;
 pub mod entity;
 mod error;
 mod guard_condition;
 pub mod listener;
+#[cfg(feature = "log")]
+pub mod logging;
+pub mod owned;
 mod participant;
 mod publisher;
 pub mod qos;
@@ -184,6 +201,7 @@ mod reader;
 pub mod sample;
 pub mod state;
 pub mod status;
+mod status_condition;
 mod subscriber;
 mod time;
 mod topic;
@@ -193,27 +211,37 @@ mod writer;
 
 pub use cyclonedds_macros::Topicable;
 pub use domain::Domain;
+pub use domain::config::DomainConfig;
 pub use duration::Duration;
-pub use error::{Error, Result};
+pub use error::{Error, ErrorContext, Result};
 pub use guard_condition::GuardCondition;
 pub use listener::{
     Listener, PublisherListener, ReaderListener, SubscriberListener, TopicListener, WriterListener,
 };
 pub use participant::Participant;
-pub use publisher::Publisher;
+pub use publisher::{CoherentSet, Publisher};
 pub use qos::QoS;
 pub use query_condition::QueryCondition;
 pub use read_condition::ReadCondition;
 pub use reader::Reader;
 pub use state::State;
 pub use status::bitflags::Status;
-pub use subscriber::Subscriber;
+pub use status_condition::StatusCondition;
+pub use subscriber::{GroupAccess, Subscriber};
 pub use time::Time;
 pub use topic::Topic;
 pub use topicable::{Key, Topicable};
 pub use waitset::WaitSet;
 pub use writer::Writer;
 
+pub mod config {
+    //! Types for configuring a [`Domain`](crate::Domain) through a
+    //! [`DomainConfig`](crate::DomainConfig).
+    pub use crate::domain::config::{
+        ConfigError, Multicast, NetworkInterface, SharedMemory, TracingOutput, Verbosity,
+    };
+}
+
 pub mod builder {
     //! Builder types for constructing DDS entities with custom `QoS` and
     //! listeners.
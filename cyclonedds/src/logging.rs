@@ -0,0 +1,158 @@
+//! Integration with the [`log`] crate.
+//!
+//! By default Cyclone DDS writes its log and trace output to standard error or
+//! the file configured through
+//! [`DomainConfig::with_tracing_output`](crate::DomainConfig::with_tracing_output).
+//! After calling [`init`], that output is instead emitted as [`log`] records so
+//! that it ends up wherever the application's logger sends it. The binding's
+//! own diagnostics are always emitted through [`log`] when this feature is
+//! enabled.
+//!
+//! Cyclone's categories are mapped onto levels as follows:
+//!
+//! | Category                                  | Level             |
+//! |-------------------------------------------|-------------------|
+//! | `fatal`, `error`                          | [`Error`]         |
+//! | `warning`                                 | [`Warn`]          |
+//! | `info`, `config`                          | [`Info`]          |
+//! | `discovery`, `topic`                      | [`Debug`]         |
+//! | everything else (e.g. `data`, `traffic`)  | [`Trace`]         |
+//!
+//! Records are emitted under the `cyclonedds` target, with a suffix for the
+//! noisier categories (e.g. `cyclonedds::discovery`) so they can be filtered
+//! independently. Which trace categories Cyclone produces in the first place
+//! is still controlled by
+//! [`DomainConfig::with_tracing_verbosity`](crate::DomainConfig::with_tracing_verbosity).
+//!
+//! This module is only available with the `log` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use cyclonedds::config::Verbosity;
+//! use cyclonedds::{Domain, DomainConfig};
+//!
+//! // Install a logger first (e.g. `env_logger::init()`), then:
+//! cyclonedds::logging::init();
+//!
+//! let config = DomainConfig::new().with_tracing_verbosity(Verbosity::Config);
+//! let domain = Domain::new_with_config(0, &config)?;
+//! # Ok::<_, cyclonedds::config::ConfigError>(())
+//! ```
+//!
+//! [`Error`]: log::Level::Error
+//! [`Warn`]: log::Level::Warn
+//! [`Info`]: log::Level::Info
+//! [`Debug`]: log::Level::Debug
+//! [`Trace`]: log::Level::Trace
+
+use crate::internal::ffi;
+
+/// Routes Cyclone's log and trace output to the [`log`] crate.
+///
+/// The sinks are process-wide and apply to every domain, including those
+/// created before this call.
+pub fn init() {
+    ffi::dds_set_log_and_trace_sink(true);
+}
+
+/// Restores Cyclone's default log and trace output, undoing [`init`].
+pub fn reset() {
+    ffi::dds_set_log_and_trace_sink(false);
+}
+
+/// Maps a Cyclone log `category` to a [`log::Level`].
+const fn level(category: u32) -> log::Level {
+    if category & (cyclonedds_sys::DDS_LC_FATAL | cyclonedds_sys::DDS_LC_ERROR) != 0 {
+        log::Level::Error
+    } else if category & cyclonedds_sys::DDS_LC_WARNING != 0 {
+        log::Level::Warn
+    } else if category & (cyclonedds_sys::DDS_LC_INFO | cyclonedds_sys::DDS_LC_CONFIG) != 0 {
+        log::Level::Info
+    } else if category & (cyclonedds_sys::DDS_LC_DISCOVERY | cyclonedds_sys::DDS_LC_TOPIC) != 0 {
+        log::Level::Debug
+    } else {
+        log::Level::Trace
+    }
+}
+
+/// Maps a Cyclone log `category` to a [`log`] target.
+const fn target(category: u32) -> &'static str {
+    if category & (cyclonedds_sys::DDS_LC_DISCOVERY | cyclonedds_sys::DDS_LC_TOPIC) != 0 {
+        "cyclonedds::discovery"
+    } else if category & cyclonedds_sys::DDS_LC_CONFIG != 0 {
+        "cyclonedds::config"
+    } else if category & (cyclonedds_sys::DDS_LC_DATA | cyclonedds_sys::DDS_LC_CONTENT) != 0 {
+        "cyclonedds::data"
+    } else if category
+        & (cyclonedds_sys::DDS_LC_TRAFFIC
+            | cyclonedds_sys::DDS_LC_TCP
+            | cyclonedds_sys::DDS_LC_THROTTLE)
+        != 0
+    {
+        "cyclonedds::traffic"
+    } else {
+        "cyclonedds"
+    }
+}
+
+/// Emits a single Cyclone log message as a [`log::Record`].
+pub(crate) fn forward(category: u32, message: &str, file: Option<&str>, line: u32) {
+    let level = level(category);
+    let target = target(category);
+    let logger = log::logger();
+    let metadata = log::Metadata::builder().level(level).target(target).build();
+    if level > log::max_level() || !logger.enabled(&metadata) {
+        return;
+    }
+
+    logger.log(
+        &log::Record::builder()
+            .metadata(metadata)
+            .file(file)
+            .line(Some(line))
+            .args(format_args!("{}", message.trim_end()))
+            .build(),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_category_level_mapping() {
+        assert_eq!(level(cyclonedds_sys::DDS_LC_FATAL), log::Level::Error);
+        assert_eq!(level(cyclonedds_sys::DDS_LC_ERROR), log::Level::Error);
+        assert_eq!(level(cyclonedds_sys::DDS_LC_WARNING), log::Level::Warn);
+        assert_eq!(level(cyclonedds_sys::DDS_LC_INFO), log::Level::Info);
+        assert_eq!(level(cyclonedds_sys::DDS_LC_CONFIG), log::Level::Info);
+        assert_eq!(level(cyclonedds_sys::DDS_LC_DISCOVERY), log::Level::Debug);
+        assert_eq!(level(cyclonedds_sys::DDS_LC_DATA), log::Level::Trace);
+        assert_eq!(level(cyclonedds_sys::DDS_LC_TRAFFIC), log::Level::Trace);
+        assert_eq!(
+            level(cyclonedds_sys::DDS_LC_DISCOVERY | cyclonedds_sys::DDS_LC_WARNING),
+            log::Level::Warn
+        );
+    }
+
+    #[test]
+    fn test_category_target_mapping() {
+        assert_eq!(target(cyclonedds_sys::DDS_LC_ERROR), "cyclonedds");
+        assert_eq!(
+            target(cyclonedds_sys::DDS_LC_DISCOVERY),
+            "cyclonedds::discovery"
+        );
+        assert_eq!(target(cyclonedds_sys::DDS_LC_CONFIG), "cyclonedds::config");
+        assert_eq!(target(cyclonedds_sys::DDS_LC_DATA), "cyclonedds::data");
+        assert_eq!(target(cyclonedds_sys::DDS_LC_TCP), "cyclonedds::traffic");
+    }
+
+    #[test]
+    fn test_init_and_reset() {
+        init();
+        let domain_id = crate::tests::domain::unique_id();
+        let _domain = crate::Domain::new(domain_id).unwrap();
+        reset();
+    }
+}
@@ -0,0 +1,797 @@
+//! Reference-counted entity handles without borrowed lifetimes.
+//!
+//! The regular entity types borrow their parents (a
+//! [`Writer<'domain, 'participant, 'topic, T>`](crate::Writer) borrows its
+//! [`Topic`](crate::Topic), which borrows its
+//! [`Participant`](crate::Participant), which borrows its
+//! [`Domain`](crate::Domain)). This makes the deletion order explicit but also
+//! makes it hard to store entities in long-lived structs, move them into
+//! threads, or hold them across `.await` points.
+//!
+//! The types in this module instead keep their parent alive through an
+//! [`Arc`]:
+//!
+//! - [`OwnedParticipant`] holds an `Arc<Domain>`.
+//! - [`OwnedTopic<T>`] holds an `Arc<OwnedParticipant>`.
+//! - [`OwnedWriter<T>`] and [`OwnedReader<T>`] hold an `Arc<OwnedTopic<T>>`.
+//!
+//! Each handle deletes its own entity before releasing its parent, so an
+//! entity is always deleted before the entity it was created under, no matter
+//! in which order the handles are dropped.
+//!
+//! Every owned handle dereferences to the corresponding borrowed entity type so
+//! the entity API remains available. Only shared access is handed out, as a
+//! mutable reference would allow the entity to be swapped out of its owner;
+//! the few methods that take `&mut self` (e.g. setting a listener) are
+//! forwarded by the owned handles instead.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::sync::Arc;
+//!
+//! use cyclonedds::Domain;
+//! use cyclonedds::owned::{OwnedParticipant, OwnedReader, OwnedTopic, OwnedWriter};
+//! # #[derive(cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default)]
+//! # struct Data {
+//! #     x: i32,
+//! # }
+//!
+//! struct Node {
+//!     writer: OwnedWriter<Data>,
+//!     reader: OwnedReader<Data>,
+//! }
+//!
+//! let participant = OwnedParticipant::new(Arc::new(Domain::default()))?;
+//! let topic = OwnedTopic::<Data>::new(&participant, "MyTopic")?;
+//! let node = Node {
+//!     writer: OwnedWriter::new(&topic)?,
+//!     reader: OwnedReader::new(&topic)?,
+//! };
+//!
+//! // The node keeps the topic, participant, and domain alive.
+//! drop(topic);
+//! drop(participant);
+//!
+//! node.writer.write(&Data { x: 1 })?;
+//! # Ok::<_, cyclonedds::Error>(())
+//! ```
+
+use std::sync::Arc;
+
+use crate::{Domain, Participant, Reader, Result, Topic, Writer};
+
+/// A [`Participant`] that keeps its [`Domain`] alive.
+///
+/// Created via [`OwnedParticipant::new`] or [`OwnedParticipant::builder`] and
+/// always handed out behind an [`Arc`] so that [`OwnedTopics`](OwnedTopic) can
+/// share it.
+#[derive(Debug)]
+pub struct OwnedParticipant {
+    // NOTE: fields are dropped in declaration order, so the participant is
+    // deleted before the last reference to its domain is released.
+    participant: Participant<'static>,
+    domain: Arc<Domain>,
+}
+
+/// Builder for [`OwnedParticipant`] (accessible via
+/// [`OwnedParticipant::builder`]).
+#[derive(Debug)]
+pub struct OwnedParticipantBuilder<'qos> {
+    domain: Arc<Domain>,
+    qos: Option<&'qos crate::QoS>,
+    listener: Option<crate::Listener>,
+}
+
+impl<'q> OwnedParticipantBuilder<'q> {
+    /// Creates a new [`OwnedParticipantBuilder`] for the given
+    /// [`Domain`].
+    #[must_use]
+    pub const fn new(domain: Arc<Domain>) -> Self {
+        Self {
+            domain,
+            qos: None,
+            listener: None,
+        }
+    }
+
+    /// Sets the [`QoS`](crate::QoS) for this participant builder.
+    #[must_use]
+    pub const fn with_qos(mut self, qos: &'q crate::QoS) -> Self {
+        self.qos = Some(qos);
+        self
+    }
+
+    /// Sets the [`Listener`](crate::Listener) on this participant builder.
+    #[must_use]
+    pub fn with_listener<L>(mut self, listener: L) -> Self
+    where
+        L: AsRef<crate::Listener>,
+    {
+        self.listener = Some(*listener.as_ref());
+        self
+    }
+
+    /// Builds the [`OwnedParticipant`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the participant failed to create.
+    pub fn build(self) -> Result<Arc<OwnedParticipant>> {
+        let mut builder = Participant::builder(&self.domain);
+        if let Some(qos) = self.qos {
+            builder = builder.with_qos(qos);
+        }
+        if let Some(listener) = self.listener {
+            builder = builder.with_listener(listener);
+        }
+        // The borrowed participant is only used to create the entity, which is
+        // then re-wrapped without the borrow of the domain.
+        let participant = std::mem::ManuallyDrop::new(builder.build()?);
+        Ok(Arc::new(OwnedParticipant {
+            participant: std::mem::ManuallyDrop::into_inner(Participant::from_existing(
+                participant.inner,
+            )),
+            domain: self.domain,
+        }))
+    }
+}
+
+impl OwnedParticipant {
+    /// Creates a new participant in the given [`Domain`] with default
+    /// [`QoS`](crate::QoS) and no [`listener`](crate::listener::Listener).
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the participant fails to create.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    ///
+    /// use cyclonedds::Domain;
+    /// use cyclonedds::owned::OwnedParticipant;
+    ///
+    /// let participant = OwnedParticipant::new(Arc::new(Domain::default()))?;
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn new(domain: Arc<Domain>) -> Result<Arc<Self>> {
+        Self::builder(domain).build()
+    }
+
+    /// Returns an [`OwnedParticipantBuilder`] for constructing a participant
+    /// with custom [`QoS`](crate::QoS) or a
+    /// [`listener`](crate::listener::Listener).
+    #[must_use]
+    pub const fn builder<'q>(domain: Arc<Domain>) -> OwnedParticipantBuilder<'q> {
+        OwnedParticipantBuilder::new(domain)
+    }
+
+    /// Returns the [`Domain`] this participant was created in.
+    #[must_use]
+    pub const fn domain(&self) -> &Arc<Domain> {
+        &self.domain
+    }
+}
+
+impl std::ops::Deref for OwnedParticipant {
+    type Target = Participant<'static>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.participant
+    }
+}
+
+/// A [`Topic`] that keeps its [`OwnedParticipant`] alive.
+///
+/// Created via [`OwnedTopic::new`] or [`OwnedTopic::builder`] and always handed
+/// out behind an [`Arc`] so that [`OwnedWriters`](OwnedWriter) and
+/// [`OwnedReaders`](OwnedReader) can share it.
+#[derive(Debug)]
+pub struct OwnedTopic<T>
+where
+    T: crate::Topicable,
+{
+    topic: Topic<'static, 'static, T>,
+    participant: Arc<OwnedParticipant>,
+}
+
+/// Builder for [`OwnedTopic<T>`] (accessible via [`OwnedTopic::builder`]).
+#[derive(Debug)]
+pub struct OwnedTopicBuilder<'qos, 'name, T>
+where
+    T: crate::Topicable,
+{
+    participant: Arc<OwnedParticipant>,
+    topic_name: &'name str,
+    qos: Option<&'qos crate::QoS>,
+    listener: Option<crate::TopicListener<T>>,
+}
+
+impl<'q, 'n, T> OwnedTopicBuilder<'q, 'n, T>
+where
+    T: crate::Topicable,
+{
+    /// Creates a new [`OwnedTopicBuilder`] for the given
+    /// [`OwnedParticipant`] and topic name.
+    #[must_use]
+    pub fn new(participant: &Arc<OwnedParticipant>, topic_name: &'n str) -> Self {
+        Self {
+            participant: Arc::clone(participant),
+            topic_name,
+            qos: None,
+            listener: None,
+        }
+    }
+
+    /// Sets the [`QoS`](crate::QoS) for this topic builder.
+    #[must_use]
+    pub const fn with_qos(mut self, qos: &'q crate::QoS) -> Self {
+        self.qos = Some(qos);
+        self
+    }
+
+    /// Sets the [`TopicListener`](crate::TopicListener) on this topic builder.
+    #[must_use]
+    pub fn with_listener<L>(mut self, listener: L) -> Self
+    where
+        L: AsRef<crate::TopicListener<T>>,
+    {
+        self.listener = Some(listener.as_ref().clone());
+        self
+    }
+
+    /// Builds the [`OwnedTopic`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the topic failed to create.
+    pub fn build(self) -> Result<Arc<OwnedTopic<T>>> {
+        let mut builder = Topic::builder(&self.participant.participant, self.topic_name);
+        if let Some(qos) = self.qos {
+            builder = builder.with_qos(qos);
+        }
+        if let Some(listener) = self.listener {
+            builder = builder.with_listener(listener);
+        }
+        let topic = std::mem::ManuallyDrop::new(builder.build()?);
+        Ok(Arc::new(OwnedTopic {
+            topic: std::mem::ManuallyDrop::into_inner(Topic::from_existing(topic.inner)),
+            participant: self.participant,
+        }))
+    }
+}
+
+impl<T> OwnedTopic<T>
+where
+    T: crate::Topicable,
+{
+    /// Creates a new topic named `topic_name` in the given
+    /// [`OwnedParticipant`] with default [`QoS`](crate::QoS) and no
+    /// [`listener`](crate::listener::TopicListener).
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the topic fails to create.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    ///
+    /// use cyclonedds::Domain;
+    /// use cyclonedds::owned::{OwnedParticipant, OwnedTopic};
+    /// # #[derive(
+    /// #     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+    /// # )]
+    /// # struct Data {
+    /// #     x: i32,
+    /// # }
+    ///
+    /// let participant = OwnedParticipant::new(Arc::new(Domain::default()))?;
+    /// let topic = OwnedTopic::<Data>::new(&participant, "MyTopic")?;
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn new(participant: &Arc<OwnedParticipant>, topic_name: &str) -> Result<Arc<Self>> {
+        Self::builder(participant, topic_name).build()
+    }
+
+    /// Returns an [`OwnedTopicBuilder`] for constructing a topic with custom
+    /// [`QoS`](crate::QoS) or a [`listener`](crate::listener::TopicListener).
+    #[must_use]
+    pub fn builder<'q, 'n>(
+        participant: &Arc<OwnedParticipant>,
+        topic_name: &'n str,
+    ) -> OwnedTopicBuilder<'q, 'n, T> {
+        OwnedTopicBuilder::new(participant, topic_name)
+    }
+
+    /// Returns the [`OwnedParticipant`] this topic was created in.
+    #[must_use]
+    pub const fn participant(&self) -> &Arc<OwnedParticipant> {
+        &self.participant
+    }
+}
+
+impl<T> std::ops::Deref for OwnedTopic<T>
+where
+    T: crate::Topicable,
+{
+    type Target = Topic<'static, 'static, T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.topic
+    }
+}
+
+/// A [`Writer`] that keeps its [`OwnedTopic`] alive.
+///
+/// Owned writers are created under the participant's implicit publisher.
+#[derive(Debug)]
+pub struct OwnedWriter<T>
+where
+    T: crate::Topicable,
+{
+    writer: Writer<'static, 'static, 'static, T>,
+    topic: Arc<OwnedTopic<T>>,
+}
+
+/// Builder for [`OwnedWriter<T>`] (accessible via [`OwnedWriter::builder`]).
+#[derive(Debug)]
+pub struct OwnedWriterBuilder<'qos, T>
+where
+    T: crate::Topicable,
+{
+    topic: Arc<OwnedTopic<T>>,
+    qos: Option<&'qos crate::QoS>,
+    listener: Option<crate::WriterListener<T>>,
+}
+
+impl<'q, T> OwnedWriterBuilder<'q, T>
+where
+    T: crate::Topicable,
+{
+    /// Creates a new [`OwnedWriterBuilder`] for the given [`OwnedTopic`].
+    #[must_use]
+    pub fn new(topic: &Arc<OwnedTopic<T>>) -> Self {
+        Self {
+            topic: Arc::clone(topic),
+            qos: None,
+            listener: None,
+        }
+    }
+
+    /// Sets the [`QoS`](crate::QoS) for this writer builder.
+    #[must_use]
+    pub const fn with_qos(mut self, qos: &'q crate::QoS) -> Self {
+        self.qos = Some(qos);
+        self
+    }
+
+    /// Sets the [`WriterListener`](crate::WriterListener) on this writer
+    /// builder.
+    #[must_use]
+    pub fn with_listener<L>(mut self, listener: L) -> Self
+    where
+        L: AsRef<crate::WriterListener<T>>,
+    {
+        self.listener = Some(listener.as_ref().clone());
+        self
+    }
+
+    /// Builds the [`OwnedWriter`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the writer failed to create.
+    pub fn build(self) -> Result<OwnedWriter<T>> {
+        let mut builder = Writer::builder(&self.topic.topic);
+        if let Some(qos) = self.qos {
+            builder = builder.with_qos(qos);
+        }
+        if let Some(listener) = self.listener {
+            builder = builder.with_listener(listener);
+        }
+        let writer = std::mem::ManuallyDrop::new(builder.build()?);
+        Ok(OwnedWriter {
+            writer: std::mem::ManuallyDrop::into_inner(Writer::from_existing(writer.inner)),
+            topic: self.topic,
+        })
+    }
+}
+
+impl<T> OwnedWriter<T>
+where
+    T: crate::Topicable,
+{
+    /// Creates a new writer for the given [`OwnedTopic`] with default
+    /// [`QoS`](crate::QoS) and no
+    /// [`listener`](crate::listener::WriterListener).
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the writer fails to create.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    ///
+    /// use cyclonedds::Domain;
+    /// use cyclonedds::owned::{OwnedParticipant, OwnedTopic, OwnedWriter};
+    /// # #[derive(
+    /// #     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+    /// # )]
+    /// # struct Data {
+    /// #     x: i32,
+    /// # }
+    ///
+    /// let participant = OwnedParticipant::new(Arc::new(Domain::default()))?;
+    /// let topic = OwnedTopic::<Data>::new(&participant, "MyTopic")?;
+    /// let writer = OwnedWriter::new(&topic)?;
+    ///
+    /// std::thread::spawn(move || writer.write(&Data { x: 1 }))
+    ///     .join()
+    ///     .unwrap()?;
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn new(topic: &Arc<OwnedTopic<T>>) -> Result<Self> {
+        Self::builder(topic).build()
+    }
+
+    /// Returns an [`OwnedWriterBuilder`] for constructing a writer with custom
+    /// [`QoS`](crate::QoS) or a [`listener`](crate::listener::WriterListener).
+    #[must_use]
+    pub fn builder<'q>(topic: &Arc<OwnedTopic<T>>) -> OwnedWriterBuilder<'q, T> {
+        OwnedWriterBuilder::new(topic)
+    }
+
+    /// Returns the [`OwnedTopic`] this writer was created for.
+    #[must_use]
+    pub const fn topic(&self) -> &Arc<OwnedTopic<T>> {
+        &self.topic
+    }
+
+    /// Sets the [`WriterListener`](crate::WriterListener) on this writer, see
+    /// [`Writer::set_listener`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the writer fails to set the
+    /// listener.
+    pub fn set_listener<L>(&mut self, listener: L) -> Result<()>
+    where
+        L: AsRef<crate::WriterListener<T>>,
+    {
+        self.writer.set_listener(listener)
+    }
+
+    /// Removes the listener from this writer, see [`Writer::unset_listener`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the writer fails to unset the
+    /// listener.
+    pub fn unset_listener(&mut self) -> Result<()> {
+        self.writer.unset_listener()
+    }
+}
+
+impl<T> std::ops::Deref for OwnedWriter<T>
+where
+    T: crate::Topicable,
+{
+    type Target = Writer<'static, 'static, 'static, T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.writer
+    }
+}
+
+/// A [`Reader`] that keeps its [`OwnedTopic`] alive.
+///
+/// Owned readers are created under the participant's implicit subscriber.
+#[derive(Debug)]
+pub struct OwnedReader<T>
+where
+    T: crate::Topicable,
+{
+    reader: Reader<'static, 'static, 'static, T>,
+    topic: Arc<OwnedTopic<T>>,
+}
+
+/// Builder for [`OwnedReader<T>`] (accessible via [`OwnedReader::builder`]).
+#[derive(Debug)]
+pub struct OwnedReaderBuilder<'qos, T>
+where
+    T: crate::Topicable,
+{
+    topic: Arc<OwnedTopic<T>>,
+    qos: Option<&'qos crate::QoS>,
+    listener: Option<crate::ReaderListener<T>>,
+}
+
+impl<'q, T> OwnedReaderBuilder<'q, T>
+where
+    T: crate::Topicable,
+{
+    /// Creates a new [`OwnedReaderBuilder`] for the given [`OwnedTopic`].
+    #[must_use]
+    pub fn new(topic: &Arc<OwnedTopic<T>>) -> Self {
+        Self {
+            topic: Arc::clone(topic),
+            qos: None,
+            listener: None,
+        }
+    }
+
+    /// Sets the [`QoS`](crate::QoS) for this reader builder.
+    #[must_use]
+    pub const fn with_qos(mut self, qos: &'q crate::QoS) -> Self {
+        self.qos = Some(qos);
+        self
+    }
+
+    /// Sets the [`ReaderListener`](crate::ReaderListener) on this reader
+    /// builder.
+    #[must_use]
+    pub fn with_listener<L>(mut self, listener: L) -> Self
+    where
+        L: AsRef<crate::ReaderListener<T>>,
+    {
+        self.listener = Some(listener.as_ref().clone());
+        self
+    }
+
+    /// Builds the [`OwnedReader`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the reader failed to create.
+    pub fn build(self) -> Result<OwnedReader<T>> {
+        let mut builder = Reader::builder(&self.topic.topic);
+        if let Some(qos) = self.qos {
+            builder = builder.with_qos(qos);
+        }
+        if let Some(listener) = self.listener {
+            builder = builder.with_listener(listener);
+        }
+        let reader = std::mem::ManuallyDrop::new(builder.build()?);
+        Ok(OwnedReader {
+            reader: std::mem::ManuallyDrop::into_inner(Reader::from_existing(reader.inner)),
+            topic: self.topic,
+        })
+    }
+}
+
+impl<T> OwnedReader<T>
+where
+    T: crate::Topicable,
+{
+    /// Creates a new reader for the given [`OwnedTopic`] with default
+    /// [`QoS`](crate::QoS) and no
+    /// [`listener`](crate::listener::ReaderListener).
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the reader fails to create.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    ///
+    /// use cyclonedds::Domain;
+    /// use cyclonedds::owned::{OwnedParticipant, OwnedReader, OwnedTopic};
+    /// # #[derive(
+    /// #     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+    /// # )]
+    /// # struct Data {
+    /// #     x: i32,
+    /// # }
+    ///
+    /// let participant = OwnedParticipant::new(Arc::new(Domain::default()))?;
+    /// let topic = OwnedTopic::<Data>::new(&participant, "MyTopic")?;
+    /// let reader = OwnedReader::new(&topic)?;
+    /// let samples = reader.take()?;
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn new(topic: &Arc<OwnedTopic<T>>) -> Result<Self> {
+        Self::builder(topic).build()
+    }
+
+    /// Returns an [`OwnedReaderBuilder`] for constructing a reader with custom
+    /// [`QoS`](crate::QoS) or a [`listener`](crate::listener::ReaderListener).
+    #[must_use]
+    pub fn builder<'q>(topic: &Arc<OwnedTopic<T>>) -> OwnedReaderBuilder<'q, T> {
+        OwnedReaderBuilder::new(topic)
+    }
+
+    /// Returns the [`OwnedTopic`] this reader was created for.
+    #[must_use]
+    pub const fn topic(&self) -> &Arc<OwnedTopic<T>> {
+        &self.topic
+    }
+
+    /// Sets the [`ReaderListener`](crate::ReaderListener) on this reader, see
+    /// [`Reader::set_listener`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the reader fails to set the
+    /// listener.
+    pub fn set_listener<L>(&mut self, listener: L) -> Result<()>
+    where
+        L: AsRef<crate::ReaderListener<T>>,
+    {
+        self.reader.set_listener(listener)
+    }
+
+    /// Removes the listener from this reader, see [`Reader::unset_listener`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the reader fails to unset the
+    /// listener.
+    pub fn unset_listener(&mut self) -> Result<()> {
+        self.reader.unset_listener()
+    }
+}
+
+impl<T> std::ops::Deref for OwnedReader<T>
+where
+    T: crate::Topicable,
+{
+    type Target = Reader<'static, 'static, 'static, T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.reader
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::Entity;
+
+    #[test]
+    fn test_owned_entities_create() {
+        let domain_id = crate::tests::domain::unique_id();
+        let domain = Arc::new(Domain::new(domain_id).unwrap());
+        let topic_name = crate::tests::topic::unique_name();
+
+        let participant = OwnedParticipant::new(Arc::clone(&domain)).unwrap();
+        let topic =
+            OwnedTopic::<crate::tests::topic::Data>::new(&participant, &topic_name).unwrap();
+        let writer = OwnedWriter::new(&topic).unwrap();
+        let reader = OwnedReader::new(&topic).unwrap();
+
+        assert!(Arc::ptr_eq(participant.domain(), &domain));
+        assert!(Arc::ptr_eq(topic.participant(), &participant));
+        assert!(Arc::ptr_eq(writer.topic(), &topic));
+        assert!(Arc::ptr_eq(reader.topic(), &topic));
+        assert_eq!(writer.participant().unwrap(), participant.id());
+        assert_eq!(reader.topic_name().unwrap(), topic_name);
+    }
+
+    #[test]
+    fn test_owned_entities_keep_parents_alive() {
+        let domain_id = crate::tests::domain::unique_id();
+        let topic_name = crate::tests::topic::unique_name();
+
+        let participant = OwnedParticipant::new(Arc::new(Domain::new(domain_id).unwrap())).unwrap();
+        let topic =
+            OwnedTopic::<crate::tests::topic::Data>::new(&participant, &topic_name).unwrap();
+        let writer = OwnedWriter::new(&topic).unwrap();
+        let reader = OwnedReader::new(&topic).unwrap();
+        drop(topic);
+        drop(participant);
+
+        let sample = crate::tests::topic::Data {
+            x: 1,
+            y: 2,
+            message: String::from("message"),
+        };
+        writer.write(&sample).unwrap();
+        let samples = reader.take().unwrap();
+        assert_eq!(samples.len(), 1);
+        assert_eq!(*samples[0], sample);
+
+        // The writer and reader are the last handles to the topic, participant,
+        // and domain, so dropping them in either order must delete each entity
+        // before its parent.
+        drop(reader);
+        drop(writer);
+    }
+
+    #[test]
+    fn test_owned_writer_move_to_thread() {
+        let domain_id = crate::tests::domain::unique_id();
+        let topic_name = crate::tests::topic::unique_name();
+
+        let participant = OwnedParticipant::new(Arc::new(Domain::new(domain_id).unwrap())).unwrap();
+        let topic =
+            OwnedTopic::<crate::tests::topic::Data>::new(&participant, &topic_name).unwrap();
+        let reader = OwnedReader::new(&topic).unwrap();
+        let writer = OwnedWriter::new(&topic).unwrap();
+        drop(topic);
+        drop(participant);
+
+        std::thread::spawn(move || {
+            writer
+                .write(&crate::tests::topic::Data {
+                    x: 1,
+                    ..Default::default()
+                })
+                .unwrap();
+        })
+        .join()
+        .unwrap();
+
+        let samples = reader.take().unwrap();
+        assert_eq!(samples.len(), 1);
+        assert_eq!(samples[0].x, 1);
+    }
+
+    #[test]
+    fn test_owned_entities_with_qos() {
+        let domain_id = crate::tests::domain::unique_id();
+        let topic_name = crate::tests::topic::unique_name();
+        let qos = crate::QoS::new().with_durability(crate::qos::policy::Durability::TransientLocal);
+
+        let participant = OwnedParticipant::builder(Arc::new(Domain::new(domain_id).unwrap()))
+            .with_qos(&qos)
+            .build()
+            .unwrap();
+        let topic = OwnedTopic::<crate::tests::topic::Data>::builder(&participant, &topic_name)
+            .with_qos(&qos)
+            .build()
+            .unwrap();
+        let writer = OwnedWriter::builder(&topic).with_qos(&qos).build().unwrap();
+        writer
+            .write(&crate::tests::topic::Data {
+                x: 1,
+                ..Default::default()
+            })
+            .unwrap();
+
+        // A late-joining transient-local reader receives the historical sample.
+        let reader = OwnedReader::builder(&topic).with_qos(&qos).build().unwrap();
+        let samples = reader.take().unwrap();
+        assert_eq!(samples.len(), 1);
+        assert_eq!(samples[0].x, 1);
+    }
+
+    #[test]
+    fn test_owned_entities_set_listener() {
+        static AVAILABLE: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+        let domain_id = crate::tests::domain::unique_id();
+        let topic_name = crate::tests::topic::unique_name();
+
+        let participant = OwnedParticipant::new(Arc::new(Domain::new(domain_id).unwrap())).unwrap();
+        let topic =
+            OwnedTopic::<crate::tests::topic::Data>::new(&participant, &topic_name).unwrap();
+        let mut writer = OwnedWriter::new(&topic).unwrap();
+        let mut reader = OwnedReader::new(&topic).unwrap();
+
+        writer.set_listener(crate::WriterListener::new()).unwrap();
+        writer.unset_listener().unwrap();
+        reader
+            .set_listener(crate::ReaderListener::new().with_data_available(|_| {
+                AVAILABLE.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            }))
+            .unwrap();
+
+        writer
+            .write(&crate::tests::topic::Data {
+                x: 1,
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(AVAILABLE.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+        reader.unset_listener().unwrap();
+    }
+}
@@ -168,6 +168,15 @@ impl<'d> Participant<'d> {
         ParticipantBuilder::new(domain)
     }
 
+    pub(crate) const fn from_existing(
+        inner: cyclonedds_sys::dds_entity_t,
+    ) -> std::mem::ManuallyDrop<Self> {
+        std::mem::ManuallyDrop::new(Self {
+            inner,
+            phantom: std::marker::PhantomData,
+        })
+    }
+
     /// Sets the [`Listener`](crate::Listener) on this participant, replacing
     /// any previously set listener.
     ///
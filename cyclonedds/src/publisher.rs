@@ -330,6 +330,62 @@ impl<'d, 'p> Publisher<'d, 'p> {
         ffi::dds_wait_for_acks(self.inner, timeout.inner)
     }
 
+    /// Begins a coherent set of changes on all writers belonging to this
+    /// publisher.
+    ///
+    /// Samples written while the returned [`CoherentSet`] is alive are
+    /// delivered to readers as a single unit once the set ends, either
+    /// explicitly via [`CoherentSet::end`] or implicitly when the guard is
+    /// dropped. Readers never observe a partial set.
+    ///
+    /// Coherent sets only have an effect if the publisher's
+    /// [`Presentation`](crate::qos::policy::Presentation) policy enables
+    /// `coherent_access`. The access scope (instance, topic, or group)
+    /// determines whether the set can span multiple writers.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the coherent set could not be
+    /// started (e.g. one is already in progress).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclonedds::qos::policy::Presentation;
+    /// use cyclonedds::{QoS, Topic, Writer};
+    /// # use cyclonedds::{Domain, Participant, Publisher};
+    /// # let domain = Domain::default();
+    /// # let participant = Participant::new(&domain)?;
+    /// # #[derive(
+    /// #     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+    /// # )]
+    /// # struct Data {
+    /// #     x: i32,
+    /// #     y: i32,
+    /// # }
+    /// let qos = QoS::new().with_presentation(Presentation::Group {
+    ///     coherent_access: true,
+    ///     ordered_access: false,
+    /// });
+    /// let publisher = Publisher::builder(&participant).with_qos(&qos).build()?;
+    ///
+    /// let topic01 = Topic::<Data>::new(&participant, "MyTopic01")?;
+    /// let topic02 = Topic::<Data>::new(&participant, "MyTopic02")?;
+    /// let writer01 = Writer::builder(&topic01).with_publisher(&publisher).build()?;
+    /// let writer02 = Writer::builder(&topic02).with_publisher(&publisher).build()?;
+    ///
+    /// let coherent_set = publisher.begin_coherent()?;
+    /// writer01.write(&Data { x: 0, y: 1 })?;
+    /// writer02.write(&Data { x: 2, y: 3 })?;
+    /// // Both samples become visible to readers at the same time.
+    /// coherent_set.end()?;
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn begin_coherent(&self) -> Result<CoherentSet<'_, 'd, 'p>> {
+        ffi::dds_begin_coherent(self.inner)?;
+        Ok(CoherentSet { publisher: self })
+    }
+
     #[allow(unused)]
     pub(crate) const fn from_existing(
         inner: cyclonedds_sys::dds_entity_t,
@@ -419,6 +475,42 @@ impl<'d, 'p> Publisher<'d, 'p> {
     }
 }
 
+/// A coherent set of changes in progress on a [`Publisher`] (created via
+/// [`Publisher::begin_coherent`]).
+///
+/// The coherent set ends when this guard is dropped. Use
+/// [`end`](CoherentSet::end) to end it explicitly and observe any error.
+#[derive(Debug)]
+#[must_use = "the coherent set ends immediately if the guard is not held"]
+pub struct CoherentSet<'publisher, 'domain, 'participant> {
+    publisher: &'publisher Publisher<'domain, 'participant>,
+}
+
+impl CoherentSet<'_, '_, '_> {
+    /// Ends the coherent set, making all changes written as part of it visible
+    /// to readers.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the coherent set could not be
+    /// ended.
+    pub fn end(self) -> Result<()> {
+        let this = std::mem::ManuallyDrop::new(self);
+        ffi::dds_end_coherent(this.publisher.inner)
+    }
+}
+
+impl Drop for CoherentSet<'_, '_, '_> {
+    fn drop(&mut self) {
+        let result = ffi::dds_end_coherent(self.publisher.inner);
+        debug_assert!(
+            result.is_ok(),
+            "unable to end coherent set on {:?}: failed with {result:?}",
+            self.publisher
+        );
+    }
+}
+
 impl Drop for Publisher<'_, '_> {
     fn drop(&mut self) {
         let result = ffi::dds_delete(self.inner);
@@ -561,4 +653,42 @@ mod tests {
         assert_eq!(result, crate::Error::BadParameter);
         publisher.inner = publisher_id;
     }
+
+    #[test]
+    fn test_publisher_begin_end_coherent() {
+        let domain_id = crate::tests::domain::unique_id();
+        let domain = crate::Domain::new(domain_id).unwrap();
+        let participant = Participant::new(&domain).unwrap();
+        let qos = crate::QoS::new().with_presentation(crate::qos::policy::Presentation::Topic {
+            coherent_access: true,
+            ordered_access: false,
+        });
+        let publisher = Publisher::builder(&participant)
+            .with_qos(&qos)
+            .build()
+            .unwrap();
+
+        let coherent_set = publisher.begin_coherent().unwrap();
+        coherent_set.end().unwrap();
+
+        // Dropping the guard also ends the set so another can begin.
+        {
+            let _coherent_set = publisher.begin_coherent().unwrap();
+        }
+        let _coherent_set = publisher.begin_coherent().unwrap();
+    }
+
+    #[test]
+    fn test_publisher_begin_coherent_on_invalid_publisher() {
+        let domain_id = crate::tests::domain::unique_id();
+        let domain = crate::Domain::new(domain_id).unwrap();
+        let participant = Participant::new(&domain).unwrap();
+        let mut publisher = Publisher::new(&participant).unwrap();
+        let publisher_id = publisher.inner;
+        publisher.inner = 0;
+
+        let result = publisher.begin_coherent().unwrap_err();
+        assert_eq!(result, crate::Error::BadParameter);
+        publisher.inner = publisher_id;
+    }
 }
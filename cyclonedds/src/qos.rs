@@ -20,7 +20,7 @@
 pub mod policy;
 
 use crate::internal::ffi;
-use crate::internal::traits::AsFfi;
+use crate::internal::traits::{AsFfi, FromFfi};
 
 /// A set of Quality of Service [`policies`](policy) applied to a DDS entity.
 ///
@@ -53,6 +53,12 @@ pub struct QoS {
     writer_data_lifecycle: Option<policy::WriterDataLifecycle>,
     reader_data_lifecycle: Option<policy::ReaderDataLifecycle>,
     entity_name: Option<policy::EntityName>,
+    ignore_local: Option<policy::IgnoreLocal>,
+    type_consistency: Option<policy::TypeConsistency>,
+    writer_batching: Option<policy::WriterBatching>,
+    psmx_instances: Option<policy::PsmxInstances>,
+    data_representation: Option<policy::DataRepresentation>,
+    property: Option<policy::Property>,
 }
 
 impl std::default::Default for QoS {
@@ -85,6 +91,12 @@ impl std::default::Default for QoS {
             writer_data_lifecycle: Option::default(),
             reader_data_lifecycle: Option::default(),
             entity_name: Option::default(),
+            ignore_local: Option::default(),
+            type_consistency: Option::default(),
+            writer_batching: Option::default(),
+            psmx_instances: Option::default(),
+            data_representation: Option::default(),
+            property: Option::default(),
         }
     }
 }
@@ -586,6 +598,394 @@ impl QoS {
         self.entity_name = Some(entity_name);
         self
     }
+
+    /// Sets the [`IgnoreLocal`](policy::IgnoreLocal) policy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclonedds::QoS;
+    /// use cyclonedds::qos::policy;
+    ///
+    /// let qos = QoS::new().with_ignore_local(policy::IgnoreLocal::Participant);
+    /// ```
+    #[must_use]
+    pub fn with_ignore_local(mut self, ignore_local: policy::IgnoreLocal) -> Self {
+        ffi::dds_qos_set_ignore_local(&mut self.inner, ignore_local.as_ffi());
+        self.ignore_local = Some(ignore_local);
+        self
+    }
+
+    /// Sets the [`TypeConsistency`](policy::TypeConsistency) policy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclonedds::QoS;
+    /// use cyclonedds::qos::policy;
+    ///
+    /// let qos = QoS::new().with_type_consistency(policy::TypeConsistency::AllowTypeCoercion {
+    ///     ignore_sequence_bounds: true,
+    ///     ignore_string_bounds: true,
+    ///     ignore_member_names: false,
+    ///     prevent_type_widening: false,
+    ///     force_type_validation: false,
+    /// });
+    /// ```
+    #[must_use]
+    pub fn with_type_consistency(mut self, type_consistency: policy::TypeConsistency) -> Self {
+        let (
+            kind,
+            ignore_sequence_bounds,
+            ignore_string_bounds,
+            ignore_member_names,
+            prevent_type_widening,
+            force_type_validation,
+        ) = type_consistency.as_ffi();
+        ffi::dds_qos_set_type_consistency(
+            &mut self.inner,
+            kind,
+            ignore_sequence_bounds,
+            ignore_string_bounds,
+            ignore_member_names,
+            prevent_type_widening,
+            force_type_validation,
+        );
+        self.type_consistency = Some(type_consistency);
+        self
+    }
+
+    /// Sets the [`WriterBatching`](policy::WriterBatching) policy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclonedds::QoS;
+    /// use cyclonedds::qos::policy;
+    ///
+    /// let qos = QoS::new().with_writer_batching(policy::WriterBatching {
+    ///     batch_updates: true,
+    /// });
+    /// ```
+    #[must_use]
+    pub fn with_writer_batching(mut self, writer_batching: policy::WriterBatching) -> Self {
+        ffi::dds_qos_set_writer_batching(&mut self.inner, writer_batching.as_ffi());
+        self.writer_batching = Some(writer_batching);
+        self
+    }
+
+    /// Sets the [`PsmxInstances`](policy::PsmxInstances) policy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclonedds::QoS;
+    /// use cyclonedds::qos::policy;
+    ///
+    /// let qos = QoS::new().with_psmx_instances(policy::PsmxInstances {
+    ///     instances: vec!["iox".to_string()],
+    /// });
+    /// ```
+    #[must_use]
+    pub fn with_psmx_instances(mut self, psmx_instances: policy::PsmxInstances) -> Self {
+        let instances = psmx_instances.as_ffi();
+        ffi::dds_qos_set_psmx_instances(&mut self.inner, &instances);
+        self.psmx_instances = Some(psmx_instances);
+        self
+    }
+
+    /// Sets the [`DataRepresentation`](policy::DataRepresentation) policy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclonedds::QoS;
+    /// use cyclonedds::qos::policy;
+    ///
+    /// let qos = QoS::new().with_data_representation(policy::DataRepresentation {
+    ///     representations: vec![policy::DataRepresentationKind::Xcdr1],
+    /// });
+    /// ```
+    #[must_use]
+    pub fn with_data_representation(
+        mut self,
+        data_representation: policy::DataRepresentation,
+    ) -> Self {
+        let representations = data_representation.as_ffi();
+        ffi::dds_qos_set_data_representation(&mut self.inner, &representations);
+        self.data_representation = Some(data_representation);
+        self
+    }
+
+    /// Sets the [`Property`](policy::Property) policy.
+    ///
+    /// Like the other setters this replaces the policy, so the properties of a
+    /// previous call are removed rather than merged with `property`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclonedds::QoS;
+    /// use cyclonedds::qos::policy;
+    ///
+    /// let qos = QoS::new().with_property(policy::Property {
+    ///     value: vec![policy::StringProperty {
+    ///         name: "role".to_string(),
+    ///         value: "sensor".to_string(),
+    ///         propagate: true,
+    ///     }],
+    ///     binary_value: vec![policy::BinaryProperty {
+    ///         name: "token".to_string(),
+    ///         value: vec![0xDE, 0xAD],
+    ///         propagate: false,
+    ///     }],
+    /// });
+    /// ```
+    #[must_use]
+    pub fn with_property(mut self, property: policy::Property) -> Self {
+        if let Some(previous) = self.property.take() {
+            let (value, binary_value) = previous.as_ffi();
+            for (name, _, _) in &value {
+                ffi::dds_qos_unset_property(&mut self.inner, name);
+            }
+            for (name, _, _) in &binary_value {
+                ffi::dds_qos_unset_binary_property(&mut self.inner, name);
+            }
+        }
+
+        let (value, binary_value) = property.as_ffi();
+        for (name, value, propagate) in &value {
+            ffi::dds_qos_set_property(&mut self.inner, name, value, *propagate);
+        }
+        for (name, value, propagate) in &binary_value {
+            ffi::dds_qos_set_binary_property(&mut self.inner, name, value, *propagate);
+        }
+        self.property = Some(property);
+        self
+    }
+
+    /// Creates a [`QoS`] holding a copy of the policies set on a raw
+    /// [`cyclonedds_sys::dds_qos_t`], e.g. as received through discovery.
+    pub(crate) fn from_ffi(qos: &cyclonedds_sys::dds_qos_t) -> Self {
+        let mut inner = cyclonedds_sys::dds_qos_t::default();
+        ffi::dds_copy_qos(&mut inner, qos);
+
+        Self {
+            inner,
+            user_data: ffi::dds_qos_get_user_data(qos).and_then(policy::UserData::from_ffi),
+            topic_data: ffi::dds_qos_get_topic_data(qos).and_then(policy::TopicData::from_ffi),
+            group_data: ffi::dds_qos_get_group_data(qos).and_then(policy::GroupData::from_ffi),
+            durability: ffi::dds_qos_get_durability(qos).and_then(policy::Durability::from_ffi),
+            durability_service: ffi::dds_qos_get_durability_service(qos)
+                .and_then(policy::DurabilityService::from_ffi),
+            presentation: ffi::dds_qos_get_presentation(qos)
+                .and_then(policy::Presentation::from_ffi),
+            deadline: ffi::dds_qos_get_deadline(qos).and_then(policy::Deadline::from_ffi),
+            latency_budget: ffi::dds_qos_get_latency_budget(qos)
+                .and_then(policy::LatencyBudget::from_ffi),
+            ownership: ffi::dds_qos_get_ownership(qos).and_then(policy::Ownership::from_ffi),
+            liveliness: ffi::dds_qos_get_liveliness(qos).and_then(policy::Liveliness::from_ffi),
+            time_based_filter: ffi::dds_qos_get_time_based_filter(qos)
+                .and_then(policy::TimeBasedFilter::from_ffi),
+            partition: ffi::dds_qos_get_partition(qos).and_then(policy::Partition::from_ffi),
+            reliability: ffi::dds_qos_get_reliability(qos).and_then(policy::Reliability::from_ffi),
+            transport_priority: ffi::dds_qos_get_transport_priority(qos)
+                .and_then(policy::TransportPriority::from_ffi),
+            lifespan: ffi::dds_qos_get_lifespan(qos).and_then(policy::Lifespan::from_ffi),
+            destination_order: ffi::dds_qos_get_destination_order(qos)
+                .and_then(policy::DestinationOrder::from_ffi),
+            history: ffi::dds_qos_get_history(qos).and_then(policy::History::from_ffi),
+            resource_limits: ffi::dds_qos_get_resource_limits(qos)
+                .and_then(policy::ResourceLimits::from_ffi),
+            writer_data_lifecycle: ffi::dds_qos_get_writer_data_lifecycle(qos)
+                .and_then(policy::WriterDataLifecycle::from_ffi),
+            reader_data_lifecycle: ffi::dds_qos_get_reader_data_lifecycle(qos)
+                .and_then(policy::ReaderDataLifecycle::from_ffi),
+            entity_name: ffi::dds_qos_get_entity_name(qos).and_then(policy::EntityName::from_ffi),
+            ignore_local: ffi::dds_qos_get_ignore_local(qos)
+                .and_then(policy::IgnoreLocal::from_ffi),
+            type_consistency: ffi::dds_qos_get_type_consistency(qos)
+                .and_then(policy::TypeConsistency::from_ffi),
+            writer_batching: ffi::dds_qos_get_writer_batching(qos)
+                .and_then(policy::WriterBatching::from_ffi),
+            psmx_instances: ffi::dds_qos_get_psmx_instances(qos)
+                .and_then(policy::PsmxInstances::from_ffi),
+            data_representation: ffi::dds_qos_get_data_representation(qos)
+                .and_then(policy::DataRepresentation::from_ffi),
+            property: ffi::dds_qos_get_property(qos).and_then(policy::Property::from_ffi),
+            // NOTE: Cyclone has no getter for the entity factory policy and it is never
+            // propagated through discovery so it is left unset.
+            entity_factory: None,
+        }
+    }
+
+    /// Returns the [`UserData`](policy::UserData) policy if it has been set.
+    #[must_use]
+    pub const fn user_data(&self) -> Option<&policy::UserData> {
+        self.user_data.as_ref()
+    }
+
+    /// Returns the [`TopicData`](policy::TopicData) policy if it has been set.
+    #[must_use]
+    pub const fn topic_data(&self) -> Option<&policy::TopicData> {
+        self.topic_data.as_ref()
+    }
+
+    /// Returns the [`GroupData`](policy::GroupData) policy if it has been set.
+    #[must_use]
+    pub const fn group_data(&self) -> Option<&policy::GroupData> {
+        self.group_data.as_ref()
+    }
+
+    /// Returns the [`Durability`](policy::Durability) policy if it has been set.
+    #[must_use]
+    pub const fn durability(&self) -> Option<policy::Durability> {
+        self.durability
+    }
+
+    /// Returns the [`DurabilityService`](policy::DurabilityService) policy if it has been set.
+    #[must_use]
+    pub const fn durability_service(&self) -> Option<policy::DurabilityService> {
+        self.durability_service
+    }
+
+    /// Returns the [`Presentation`](policy::Presentation) policy if it has been set.
+    #[must_use]
+    pub const fn presentation(&self) -> Option<policy::Presentation> {
+        self.presentation
+    }
+
+    /// Returns the [`Deadline`](policy::Deadline) policy if it has been set.
+    #[must_use]
+    pub const fn deadline(&self) -> Option<policy::Deadline> {
+        self.deadline
+    }
+
+    /// Returns the [`LatencyBudget`](policy::LatencyBudget) policy if it has been set.
+    #[must_use]
+    pub const fn latency_budget(&self) -> Option<policy::LatencyBudget> {
+        self.latency_budget
+    }
+
+    /// Returns the [`Ownership`](policy::Ownership) policy if it has been set.
+    #[must_use]
+    pub const fn ownership(&self) -> Option<policy::Ownership> {
+        self.ownership
+    }
+
+    /// Returns the [`Liveliness`](policy::Liveliness) policy if it has been set.
+    #[must_use]
+    pub const fn liveliness(&self) -> Option<policy::Liveliness> {
+        self.liveliness
+    }
+
+    /// Returns the [`TimeBasedFilter`](policy::TimeBasedFilter) policy if it has been set.
+    #[must_use]
+    pub const fn time_based_filter(&self) -> Option<policy::TimeBasedFilter> {
+        self.time_based_filter
+    }
+
+    /// Returns the [`Partition`](policy::Partition) policy if it has been set.
+    #[must_use]
+    pub const fn partition(&self) -> Option<&policy::Partition> {
+        self.partition.as_ref()
+    }
+
+    /// Returns the [`Reliability`](policy::Reliability) policy if it has been set.
+    #[must_use]
+    pub const fn reliability(&self) -> Option<policy::Reliability> {
+        self.reliability
+    }
+
+    /// Returns the [`TransportPriority`](policy::TransportPriority) policy if it has been set.
+    #[must_use]
+    pub const fn transport_priority(&self) -> Option<policy::TransportPriority> {
+        self.transport_priority
+    }
+
+    /// Returns the [`Lifespan`](policy::Lifespan) policy if it has been set.
+    #[must_use]
+    pub const fn lifespan(&self) -> Option<policy::Lifespan> {
+        self.lifespan
+    }
+
+    /// Returns the [`DestinationOrder`](policy::DestinationOrder) policy if it has been set.
+    #[must_use]
+    pub const fn destination_order(&self) -> Option<policy::DestinationOrder> {
+        self.destination_order
+    }
+
+    /// Returns the [`History`](policy::History) policy if it has been set.
+    #[must_use]
+    pub const fn history(&self) -> Option<policy::History> {
+        self.history
+    }
+
+    /// Returns the [`ResourceLimits`](policy::ResourceLimits) policy if it has been set.
+    #[must_use]
+    pub const fn resource_limits(&self) -> Option<policy::ResourceLimits> {
+        self.resource_limits
+    }
+
+    /// Returns the [`EntityFactory`](policy::EntityFactory) policy if it has been set.
+    #[must_use]
+    pub const fn entity_factory(&self) -> Option<policy::EntityFactory> {
+        self.entity_factory
+    }
+
+    /// Returns the [`WriterDataLifecycle`](policy::WriterDataLifecycle) policy if it has been set.
+    #[must_use]
+    pub const fn writer_data_lifecycle(&self) -> Option<policy::WriterDataLifecycle> {
+        self.writer_data_lifecycle
+    }
+
+    /// Returns the [`ReaderDataLifecycle`](policy::ReaderDataLifecycle) policy if it has been set.
+    #[must_use]
+    pub const fn reader_data_lifecycle(&self) -> Option<policy::ReaderDataLifecycle> {
+        self.reader_data_lifecycle
+    }
+
+    /// Returns the [`EntityName`](policy::EntityName) policy if it has been set.
+    #[must_use]
+    pub const fn entity_name(&self) -> Option<&policy::EntityName> {
+        self.entity_name.as_ref()
+    }
+
+    /// Returns the [`IgnoreLocal`](policy::IgnoreLocal) policy if it has been set.
+    #[must_use]
+    pub const fn ignore_local(&self) -> Option<policy::IgnoreLocal> {
+        self.ignore_local
+    }
+
+    /// Returns the [`TypeConsistency`](policy::TypeConsistency) policy if it has been set.
+    #[must_use]
+    pub const fn type_consistency(&self) -> Option<policy::TypeConsistency> {
+        self.type_consistency
+    }
+
+    /// Returns the [`WriterBatching`](policy::WriterBatching) policy if it has been set.
+    #[must_use]
+    pub const fn writer_batching(&self) -> Option<policy::WriterBatching> {
+        self.writer_batching
+    }
+
+    /// Returns the [`PsmxInstances`](policy::PsmxInstances) policy if it has been set.
+    #[must_use]
+    pub const fn psmx_instances(&self) -> Option<&policy::PsmxInstances> {
+        self.psmx_instances.as_ref()
+    }
+
+    /// Returns the [`DataRepresentation`](policy::DataRepresentation) policy if it has been set.
+    #[must_use]
+    pub const fn data_representation(&self) -> Option<&policy::DataRepresentation> {
+        self.data_representation.as_ref()
+    }
+
+    /// Returns the [`Property`](policy::Property) policy if it has been set.
+    #[must_use]
+    pub const fn property(&self) -> Option<&policy::Property> {
+        self.property.as_ref()
+    }
 }
 
 impl Drop for QoS {
@@ -594,6 +994,20 @@ impl Drop for QoS {
     }
 }
 
+// SAFETY: `dds_qos_t` is plain data apart from raw pointers to the values of
+// the variable-length policies (user data, partitions, properties, ...). Those
+// allocations are exclusively owned by this `QoS`: they are made by the
+// `dds_qset_*` setters or `dds_copy_qos`, never aliased by another `QoS` or
+// entity (Cyclone copies the policies when an entity is created), and released
+// by `dds_reset_qos` on drop. Cyclone's allocator is not tied to the
+// allocating thread, so the `QoS` can be moved to and dropped on any thread.
+unsafe impl Send for QoS {}
+
+// SAFETY: every method on `&QoS` only reads through those pointers (the getters
+// and Cyclone copying the policies into a new entity), and all mutation goes
+// through `&mut self` or `self`, so sharing a `QoS` between threads cannot race.
+unsafe impl Sync for QoS {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -664,6 +1078,23 @@ mod tests {
         let entity_name = policy::EntityName {
             name: String::default(),
         };
+        let ignore_local = policy::IgnoreLocal::Process;
+        let type_consistency = policy::TypeConsistency::DisallowTypeCoercion {
+            force_type_validation: false,
+        };
+        let writer_batching = policy::WriterBatching {
+            batch_updates: false,
+        };
+        let psmx_instances = policy::PsmxInstances {
+            instances: Vec::default(),
+        };
+        let data_representation = policy::DataRepresentation {
+            representations: vec![policy::DataRepresentationKind::Xcdr1],
+        };
+        let property = policy::Property {
+            value: Vec::default(),
+            binary_value: Vec::default(),
+        };
 
         let qos = QoS::new()
             .with_user_data(user_data.clone())
@@ -687,7 +1118,13 @@ mod tests {
             .with_entity_factory(entity_factory)
             .with_writer_data_lifecycle(writer_data_lifecycle)
             .with_reader_data_lifecycle(reader_data_lifecycle)
-            .with_entity_name(entity_name.clone());
+            .with_entity_name(entity_name.clone())
+            .with_ignore_local(ignore_local)
+            .with_type_consistency(type_consistency)
+            .with_writer_batching(writer_batching)
+            .with_psmx_instances(psmx_instances.clone())
+            .with_data_representation(data_representation.clone())
+            .with_property(property.clone());
 
         assert_eq!(qos.user_data, Some(user_data));
         assert_eq!(qos.topic_data, Some(topic_data));
@@ -711,6 +1148,12 @@ mod tests {
         assert_eq!(qos.writer_data_lifecycle, Some(writer_data_lifecycle));
         assert_eq!(qos.reader_data_lifecycle, Some(reader_data_lifecycle));
         assert_eq!(qos.entity_name, Some(entity_name));
+        assert_eq!(qos.ignore_local, Some(ignore_local));
+        assert_eq!(qos.type_consistency, Some(type_consistency));
+        assert_eq!(qos.writer_batching, Some(writer_batching));
+        assert_eq!(qos.psmx_instances, Some(psmx_instances));
+        assert_eq!(qos.data_representation, Some(data_representation));
+        assert_eq!(qos.property, Some(property));
     }
 
     #[test]
@@ -740,6 +1183,27 @@ mod tests {
         assert_eq!(qos.group_data, Some(group_data));
     }
 
+    #[test]
+    fn test_qos_set_user_topic_and_group_data_separately() {
+        let qos = QoS::new()
+            .with_user_data(policy::UserData { value: vec![1] })
+            .with_topic_data(policy::TopicData { value: vec![2] })
+            .with_group_data(policy::GroupData { value: vec![3] });
+        assert_eq!(ffi::dds_qos_get_user_data(&qos.inner), Some(vec![1]));
+        assert_eq!(ffi::dds_qos_get_topic_data(&qos.inner), Some(vec![2]));
+        assert_eq!(ffi::dds_qos_get_group_data(&qos.inner), Some(vec![3]));
+
+        let qos = QoS::new().with_topic_data(policy::TopicData { value: vec![2] });
+        assert_eq!(ffi::dds_qos_get_user_data(&qos.inner), None);
+        assert_eq!(ffi::dds_qos_get_topic_data(&qos.inner), Some(vec![2]));
+        assert_eq!(ffi::dds_qos_get_group_data(&qos.inner), None);
+
+        let qos = QoS::new().with_group_data(policy::GroupData { value: vec![3] });
+        assert_eq!(ffi::dds_qos_get_user_data(&qos.inner), None);
+        assert_eq!(ffi::dds_qos_get_topic_data(&qos.inner), None);
+        assert_eq!(ffi::dds_qos_get_group_data(&qos.inner), Some(vec![3]));
+    }
+
     #[test]
     fn test_qos_set_durability() {
         let durability = policy::Durability::Volatile;
@@ -978,4 +1442,305 @@ mod tests {
         };
         let _ = QoS::new().with_entity_name(entity_name);
     }
+
+    #[test]
+    fn test_qos_set_ignore_local() {
+        let ignore_local = policy::IgnoreLocal::Nothing;
+        let qos = QoS::new().with_ignore_local(ignore_local);
+        assert_eq!(qos.ignore_local, Some(ignore_local));
+
+        let ignore_local = policy::IgnoreLocal::Participant;
+        let qos = QoS::new().with_ignore_local(ignore_local);
+        assert_eq!(qos.ignore_local, Some(ignore_local));
+
+        let ignore_local = policy::IgnoreLocal::Process;
+        let qos = QoS::new().with_ignore_local(ignore_local);
+        assert_eq!(qos.ignore_local, Some(ignore_local));
+    }
+
+    #[test]
+    fn test_qos_set_type_consistency() {
+        let type_consistency = policy::TypeConsistency::DisallowTypeCoercion {
+            force_type_validation: true,
+        };
+        let qos = QoS::new().with_type_consistency(type_consistency);
+        assert_eq!(qos.type_consistency, Some(type_consistency));
+
+        let type_consistency = policy::TypeConsistency::AllowTypeCoercion {
+            ignore_sequence_bounds: true,
+            ignore_string_bounds: false,
+            ignore_member_names: true,
+            prevent_type_widening: false,
+            force_type_validation: true,
+        };
+        let qos = QoS::new().with_type_consistency(type_consistency);
+        assert_eq!(qos.type_consistency, Some(type_consistency));
+    }
+
+    #[test]
+    fn test_qos_set_writer_batching() {
+        let writer_batching = policy::WriterBatching {
+            batch_updates: true,
+        };
+        let qos = QoS::new().with_writer_batching(writer_batching);
+        assert_eq!(qos.writer_batching, Some(writer_batching));
+    }
+
+    #[test]
+    fn test_qos_set_psmx_instances() {
+        let psmx_instances = policy::PsmxInstances {
+            instances: vec!["A".to_string(), "B".to_string()],
+        };
+        let qos = QoS::new().with_psmx_instances(psmx_instances.clone());
+        assert_eq!(qos.psmx_instances, Some(psmx_instances));
+    }
+
+    #[test]
+    #[should_panic = "unable to safely create std::ffi::CString from PSMX instance name"]
+    fn test_qos_set_psmx_instances_with_invalid_name() {
+        let psmx_instances = policy::PsmxInstances {
+            instances: vec!["A".to_string(), "\0".to_string()],
+        };
+        let _ = QoS::new().with_psmx_instances(psmx_instances);
+    }
+
+    #[test]
+    fn test_qos_set_data_representation() {
+        let data_representation = policy::DataRepresentation {
+            representations: vec![
+                policy::DataRepresentationKind::Xcdr2,
+                policy::DataRepresentationKind::Xcdr1,
+            ],
+        };
+        let qos = QoS::new().with_data_representation(data_representation.clone());
+        assert_eq!(qos.data_representation, Some(data_representation));
+
+        let data_representation = policy::DataRepresentation {
+            representations: vec![policy::DataRepresentationKind::Xml],
+        };
+        let qos = QoS::new().with_data_representation(data_representation.clone());
+        assert_eq!(qos.data_representation, Some(data_representation));
+    }
+
+    #[test]
+    fn test_qos_set_property() {
+        let property = policy::Property {
+            value: vec![
+                policy::StringProperty {
+                    name: "A".to_string(),
+                    value: "a".to_string(),
+                    propagate: true,
+                },
+                policy::StringProperty {
+                    name: "B".to_string(),
+                    value: "b".to_string(),
+                    propagate: false,
+                },
+            ],
+            binary_value: vec![policy::BinaryProperty {
+                name: "C".to_string(),
+                value: vec![0, 1, 2],
+                propagate: true,
+            }],
+        };
+        let qos = QoS::new().with_property(property.clone());
+        assert_eq!(qos.property, Some(property));
+    }
+
+    #[test]
+    fn test_qos_set_property_replaces_previous() {
+        let first = policy::Property {
+            value: vec![policy::StringProperty {
+                name: "A".to_string(),
+                value: "a".to_string(),
+                propagate: true,
+            }],
+            binary_value: vec![policy::BinaryProperty {
+                name: "B".to_string(),
+                value: vec![1],
+                propagate: false,
+            }],
+        };
+        let second = policy::Property {
+            value: vec![policy::StringProperty {
+                name: "C".to_string(),
+                value: "c".to_string(),
+                propagate: false,
+            }],
+            binary_value: Vec::default(),
+        };
+        let qos = QoS::new()
+            .with_property(first)
+            .with_property(second.clone());
+        assert_eq!(qos.property(), Some(&second));
+        assert_eq!(QoS::from_ffi(&qos.inner).property(), Some(&second));
+    }
+
+    #[test]
+    #[should_panic = "unable to safely create std::ffi::CString from property name"]
+    fn test_qos_set_property_with_invalid_name() {
+        let property = policy::Property {
+            value: Vec::default(),
+            binary_value: vec![policy::BinaryProperty {
+                name: "\0".to_string(),
+                value: Vec::default(),
+                propagate: false,
+            }],
+        };
+        let _ = QoS::new().with_property(property);
+    }
+
+    #[test]
+    #[should_panic = "unable to safely create std::ffi::CString from property value"]
+    fn test_qos_set_property_with_invalid_value() {
+        let property = policy::Property {
+            value: vec![policy::StringProperty {
+                name: "A".to_string(),
+                value: "\0".to_string(),
+                propagate: false,
+            }],
+            binary_value: Vec::default(),
+        };
+        let _ = QoS::new().with_property(property);
+    }
+
+    #[test]
+    fn test_qos_from_ffi_round_trip() {
+        let qos = QoS::new()
+            .with_user_data(policy::UserData {
+                value: b"user".to_vec(),
+            })
+            .with_topic_data(policy::TopicData {
+                value: b"topic".to_vec(),
+            })
+            .with_group_data(policy::GroupData {
+                value: b"group".to_vec(),
+            })
+            .with_durability(policy::Durability::TransientLocal)
+            .with_durability_service(policy::DurabilityService {
+                service_cleanup_delay: crate::Duration::from_secs(1),
+                history: policy::History::KeepLast { depth: 3 },
+                resource_limits: policy::ResourceLimits {
+                    max_samples: policy::ResourceLimit::Limited(10),
+                    max_instances: policy::ResourceLimit::Unlimited,
+                    max_samples_per_instance: policy::ResourceLimit::Limited(5),
+                },
+            })
+            .with_presentation(policy::Presentation::Topic {
+                coherent_access: true,
+                ordered_access: false,
+            })
+            .with_deadline(policy::Deadline {
+                period: crate::Duration::from_millis(100),
+            })
+            .with_latency_budget(policy::LatencyBudget {
+                duration: crate::Duration::from_millis(10),
+            })
+            .with_ownership(policy::Ownership::Exclusive { strength: 7 })
+            .with_liveliness(policy::Liveliness::ManualByTopic {
+                lease_duration: crate::Duration::from_secs(2),
+            })
+            .with_time_based_filter(policy::TimeBasedFilter {
+                minimum_separation: crate::Duration::from_millis(5),
+            })
+            .with_partition(policy::Partition {
+                partitions: vec!["A".to_string(), "B".to_string()],
+            })
+            .with_reliability(policy::Reliability::Reliable {
+                max_blocking_time: crate::Duration::from_millis(50),
+            })
+            .with_transport_priority(policy::TransportPriority { priority: 3 })
+            .with_lifespan(policy::Lifespan {
+                duration: crate::Duration::from_secs(60),
+            })
+            .with_destination_order(policy::DestinationOrder::BySourceTimestamp)
+            .with_history(policy::History::KeepAll)
+            .with_resource_limits(policy::ResourceLimits {
+                max_samples: policy::ResourceLimit::Limited(100),
+                max_instances: policy::ResourceLimit::Limited(10),
+                max_samples_per_instance: policy::ResourceLimit::Unlimited,
+            })
+            .with_writer_data_lifecycle(policy::WriterDataLifecycle {
+                autodispose_unregistered_instances: false,
+            })
+            .with_reader_data_lifecycle(policy::ReaderDataLifecycle {
+                autopurge_nowriter_samples_delay: crate::Duration::from_secs(1),
+                autopurge_disposed_samples_delay: crate::Duration::INFINITE,
+            })
+            .with_entity_name(policy::EntityName {
+                name: "entity".to_string(),
+            })
+            .with_ignore_local(policy::IgnoreLocal::Participant)
+            .with_type_consistency(policy::TypeConsistency::AllowTypeCoercion {
+                ignore_sequence_bounds: true,
+                ignore_string_bounds: false,
+                ignore_member_names: true,
+                prevent_type_widening: false,
+                force_type_validation: true,
+            })
+            .with_writer_batching(policy::WriterBatching {
+                batch_updates: true,
+            })
+            .with_psmx_instances(policy::PsmxInstances {
+                instances: vec!["cdds".to_string()],
+            })
+            .with_data_representation(policy::DataRepresentation {
+                representations: vec![
+                    policy::DataRepresentationKind::Xcdr2,
+                    policy::DataRepresentationKind::Xcdr1,
+                ],
+            })
+            .with_property(policy::Property {
+                value: vec![policy::StringProperty {
+                    name: "A".to_string(),
+                    value: "a".to_string(),
+                    propagate: true,
+                }],
+                binary_value: vec![policy::BinaryProperty {
+                    name: "B".to_string(),
+                    value: vec![1, 2, 3],
+                    propagate: false,
+                }],
+            });
+
+        let copy = QoS::from_ffi(&qos.inner);
+
+        assert_eq!(copy.user_data(), qos.user_data());
+        assert_eq!(copy.topic_data(), qos.topic_data());
+        assert_eq!(copy.group_data(), qos.group_data());
+        assert_eq!(copy.durability(), qos.durability());
+        assert_eq!(copy.durability_service(), qos.durability_service());
+        assert_eq!(copy.presentation(), qos.presentation());
+        assert_eq!(copy.deadline(), qos.deadline());
+        assert_eq!(copy.latency_budget(), qos.latency_budget());
+        assert_eq!(copy.ownership(), qos.ownership());
+        assert_eq!(copy.liveliness(), qos.liveliness());
+        assert_eq!(copy.time_based_filter(), qos.time_based_filter());
+        assert_eq!(copy.partition(), qos.partition());
+        assert_eq!(copy.reliability(), qos.reliability());
+        assert_eq!(copy.transport_priority(), qos.transport_priority());
+        assert_eq!(copy.lifespan(), qos.lifespan());
+        assert_eq!(copy.destination_order(), qos.destination_order());
+        assert_eq!(copy.history(), qos.history());
+        assert_eq!(copy.resource_limits(), qos.resource_limits());
+        assert_eq!(copy.writer_data_lifecycle(), qos.writer_data_lifecycle());
+        assert_eq!(copy.reader_data_lifecycle(), qos.reader_data_lifecycle());
+        assert_eq!(copy.entity_name(), qos.entity_name());
+        assert_eq!(copy.ignore_local(), qos.ignore_local());
+        assert_eq!(copy.type_consistency(), qos.type_consistency());
+        assert_eq!(copy.writer_batching(), qos.writer_batching());
+        assert_eq!(copy.psmx_instances(), qos.psmx_instances());
+        assert_eq!(copy.data_representation(), qos.data_representation());
+        assert_eq!(copy.property(), qos.property());
+        assert_eq!(copy.entity_factory(), None);
+    }
+
+    #[test]
+    fn test_qos_from_ffi_empty() {
+        let qos = QoS::from_ffi(&QoS::new().inner);
+        assert_eq!(qos.user_data(), None);
+        assert_eq!(qos.reliability(), None);
+        assert_eq!(qos.partition(), None);
+        assert_eq!(qos.property(), None);
+    }
 }
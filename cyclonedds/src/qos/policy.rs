@@ -14,7 +14,7 @@
 //! [Cyclone DDS documentation]: https://cyclonedds.io/docs
 
 use crate::Duration;
-use crate::internal::traits::AsFfi;
+use crate::internal::traits::{AsFfi, FromFfi};
 
 /// Attaches arbitrary application-specific data to an entity.
 ///
@@ -35,6 +35,15 @@ impl AsFfi for UserData {
     }
 }
 
+impl FromFfi for UserData {
+    type Source = Vec<u8>;
+
+    #[inline]
+    fn from_ffi(value: Self::Source) -> Option<Self> {
+        Some(Self { value })
+    }
+}
+
 /// Attaches arbitrary application-specific data to a topic.
 ///
 /// Propagated during discovery alongside the topic description, allowing
@@ -54,6 +63,15 @@ impl AsFfi for TopicData {
     }
 }
 
+impl FromFfi for TopicData {
+    type Source = Vec<u8>;
+
+    #[inline]
+    fn from_ffi(value: Self::Source) -> Option<Self> {
+        Some(Self { value })
+    }
+}
+
 /// Attaches arbitrary application-specific data to a publisher or subscriber.
 ///
 /// Propagated during discovery, allowing applications to embed metadata at
@@ -73,6 +91,15 @@ impl AsFfi for GroupData {
     }
 }
 
+impl FromFfi for GroupData {
+    type Source = Vec<u8>;
+
+    #[inline]
+    fn from_ffi(value: Self::Source) -> Option<Self> {
+        Some(Self { value })
+    }
+}
+
 /// Controls whether samples are stored for late-joining readers.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Durability {
@@ -105,6 +132,29 @@ impl AsFfi for Durability {
     }
 }
 
+impl FromFfi for Durability {
+    type Source = cyclonedds_sys::dds_durability_kind_t;
+
+    #[inline]
+    fn from_ffi(kind: Self::Source) -> Option<Self> {
+        match kind {
+            cyclonedds_sys::dds_durability_kind_DDS_DURABILITY_VOLATILE => {
+                Some(Durability::Volatile)
+            }
+            cyclonedds_sys::dds_durability_kind_DDS_DURABILITY_TRANSIENT_LOCAL => {
+                Some(Durability::TransientLocal)
+            }
+            cyclonedds_sys::dds_durability_kind_DDS_DURABILITY_TRANSIENT => {
+                Some(Durability::Transient)
+            }
+            cyclonedds_sys::dds_durability_kind_DDS_DURABILITY_PERSISTENT => {
+                Some(Durability::Persistent)
+            }
+            _ => None,
+        }
+    }
+}
+
 /// Configures the history and resource limits of the durability service.
 ///
 /// Only relevant when [`Durability`] is [`Transient`](Durability::Transient) or
@@ -147,6 +197,39 @@ impl AsFfi for DurabilityService {
     }
 }
 
+impl FromFfi for DurabilityService {
+    type Source = (
+        cyclonedds_sys::dds_duration_t,
+        cyclonedds_sys::dds_history_kind_t,
+        i32,
+        i32,
+        i32,
+        i32,
+    );
+
+    #[inline]
+    fn from_ffi(
+        (
+            service_cleanup_delay,
+            history_kind,
+            history_depth,
+            max_samples,
+            max_instances,
+            max_samples_per_instance,
+        ): Self::Source,
+    ) -> Option<Self> {
+        Some(Self {
+            service_cleanup_delay: Duration::from_nanos(service_cleanup_delay),
+            history: History::from_ffi((history_kind, history_depth))?,
+            resource_limits: ResourceLimits::from_ffi((
+                max_samples,
+                max_instances,
+                max_samples_per_instance,
+            ))?,
+        })
+    }
+}
+
 /// Controls the scope and ordering of sample presentation to subscribers.
 ///
 /// The access scope determines the boundary within which `coherent_access` and
@@ -215,6 +298,39 @@ impl AsFfi for Presentation {
     }
 }
 
+impl FromFfi for Presentation {
+    type Source = (
+        cyclonedds_sys::dds_presentation_access_scope_kind,
+        bool,
+        bool,
+    );
+
+    #[inline]
+    fn from_ffi((access_scope, coherent_access, ordered_access): Self::Source) -> Option<Self> {
+        match access_scope {
+            cyclonedds_sys::dds_presentation_access_scope_kind_DDS_PRESENTATION_INSTANCE => {
+                Some(Presentation::Instance {
+                    coherent_access,
+                    ordered_access,
+                })
+            }
+            cyclonedds_sys::dds_presentation_access_scope_kind_DDS_PRESENTATION_TOPIC => {
+                Some(Presentation::Topic {
+                    coherent_access,
+                    ordered_access,
+                })
+            }
+            cyclonedds_sys::dds_presentation_access_scope_kind_DDS_PRESENTATION_GROUP => {
+                Some(Presentation::Group {
+                    coherent_access,
+                    ordered_access,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
 /// The maximum time between successive writes for a given instance.
 ///
 /// Writers and readers negotiate a compatible deadline. If a writer does not
@@ -238,6 +354,17 @@ impl AsFfi for Deadline {
     }
 }
 
+impl FromFfi for Deadline {
+    type Source = cyclonedds_sys::dds_duration_t;
+
+    #[inline]
+    fn from_ffi(period: Self::Source) -> Option<Self> {
+        Some(Self {
+            period: Duration::from_nanos(period),
+        })
+    }
+}
+
 /// The acceptable delay between writing and delivering a sample.
 ///
 /// NOTE: this does not enforce any timing guarantees but is rather a
@@ -258,6 +385,17 @@ impl AsFfi for LatencyBudget {
     }
 }
 
+impl FromFfi for LatencyBudget {
+    type Source = cyclonedds_sys::dds_duration_t;
+
+    #[inline]
+    fn from_ffi(duration: Self::Source) -> Option<Self> {
+        Some(Self {
+            duration: Duration::from_nanos(duration),
+        })
+    }
+}
+
 /// Controls whether ownership of an instance is shared or exclusive among
 /// writers.
 ///
@@ -295,6 +433,23 @@ impl AsFfi for Ownership {
     }
 }
 
+impl FromFfi for Ownership {
+    type Source = (cyclonedds_sys::dds_ownership_kind_t, Option<i32>);
+
+    #[inline]
+    fn from_ffi((kind, strength): Self::Source) -> Option<Self> {
+        match kind {
+            cyclonedds_sys::dds_ownership_kind_DDS_OWNERSHIP_SHARED => Some(Ownership::Shared),
+            cyclonedds_sys::dds_ownership_kind_DDS_OWNERSHIP_EXCLUSIVE => {
+                Some(Ownership::Exclusive {
+                    strength: strength.unwrap_or_default(),
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
 /// Controls how the system determines whether a writer is still active.
 ///
 /// Readers use the liveliness policy to detect when a matched writer has
@@ -347,6 +502,30 @@ impl AsFfi for Liveliness {
     }
 }
 
+impl FromFfi for Liveliness {
+    type Source = (
+        cyclonedds_sys::dds_liveliness_kind_t,
+        cyclonedds_sys::dds_duration_t,
+    );
+
+    #[inline]
+    fn from_ffi((kind, lease_duration): Self::Source) -> Option<Self> {
+        let lease_duration = Duration::from_nanos(lease_duration);
+        match kind {
+            cyclonedds_sys::dds_liveliness_kind_DDS_LIVELINESS_AUTOMATIC => {
+                Some(Liveliness::Automatic { lease_duration })
+            }
+            cyclonedds_sys::dds_liveliness_kind_DDS_LIVELINESS_MANUAL_BY_PARTICIPANT => {
+                Some(Liveliness::ManualByParticipant { lease_duration })
+            }
+            cyclonedds_sys::dds_liveliness_kind_DDS_LIVELINESS_MANUAL_BY_TOPIC => {
+                Some(Liveliness::ManualByTopic { lease_duration })
+            }
+            _ => None,
+        }
+    }
+}
+
 /// The minimum time between sample deliveries to a reader for a given instance.
 ///
 /// Samples arriving faster than the minimum separation are dropped. Useful for
@@ -367,6 +546,17 @@ impl AsFfi for TimeBasedFilter {
     }
 }
 
+impl FromFfi for TimeBasedFilter {
+    type Source = cyclonedds_sys::dds_duration_t;
+
+    #[inline]
+    fn from_ffi(minimum_separation: Self::Source) -> Option<Self> {
+        Some(Self {
+            minimum_separation: Duration::from_nanos(minimum_separation),
+        })
+    }
+}
+
 /// Restricts communication to named logical partitions within a domain.
 ///
 /// A writer and reader only match if they share at least one partition name.
@@ -397,6 +587,20 @@ impl AsFfi for Partition {
     }
 }
 
+impl FromFfi for Partition {
+    type Source = Vec<std::ffi::CString>;
+
+    #[inline]
+    fn from_ffi(partitions: Self::Source) -> Option<Self> {
+        Some(Self {
+            partitions: partitions
+                .iter()
+                .map(|partition| partition.to_string_lossy().into_owned())
+                .collect(),
+        })
+    }
+}
+
 /// The delivery guarantee for samples.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Reliability {
@@ -432,6 +636,28 @@ impl AsFfi for Reliability {
     }
 }
 
+impl FromFfi for Reliability {
+    type Source = (
+        cyclonedds_sys::dds_reliability_kind_t,
+        cyclonedds_sys::dds_duration_t,
+    );
+
+    #[inline]
+    fn from_ffi((kind, max_blocking_time): Self::Source) -> Option<Self> {
+        match kind {
+            cyclonedds_sys::dds_reliability_kind_DDS_RELIABILITY_BEST_EFFORT => {
+                Some(Reliability::BestEffort)
+            }
+            cyclonedds_sys::dds_reliability_kind_DDS_RELIABILITY_RELIABLE => {
+                Some(Reliability::Reliable {
+                    max_blocking_time: Duration::from_nanos(max_blocking_time),
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
 /// A hint to the transport layer about the relative send priority of this
 /// entity.
 ///
@@ -452,6 +678,15 @@ impl AsFfi for TransportPriority {
     }
 }
 
+impl FromFfi for TransportPriority {
+    type Source = i32;
+
+    #[inline]
+    fn from_ffi(priority: Self::Source) -> Option<Self> {
+        Some(Self { priority })
+    }
+}
+
 /// The maximum duration a sample remains valid after being written.
 ///
 /// Samples that have not been delivered within their lifespan are silently
@@ -471,6 +706,17 @@ impl AsFfi for Lifespan {
     }
 }
 
+impl FromFfi for Lifespan {
+    type Source = cyclonedds_sys::dds_duration_t;
+
+    #[inline]
+    fn from_ffi(duration: Self::Source) -> Option<Self> {
+        Some(Self {
+            duration: Duration::from_nanos(duration),
+        })
+    }
+}
+
 /// Controls the order in which samples are delivered to a reader when multiple
 /// writers produce samples for the same instance.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -496,6 +742,23 @@ impl AsFfi for DestinationOrder {
     }
 }
 
+impl FromFfi for DestinationOrder {
+    type Source = cyclonedds_sys::dds_destination_order_kind_t;
+
+    #[inline]
+    fn from_ffi(kind: Self::Source) -> Option<Self> {
+        match kind {
+            cyclonedds_sys::dds_destination_order_kind_DDS_DESTINATIONORDER_BY_RECEPTION_TIMESTAMP => {
+                Some(DestinationOrder::ByReceptionTimestamp)
+            }
+            cyclonedds_sys::dds_destination_order_kind_DDS_DESTINATIONORDER_BY_SOURCE_TIMESTAMP => {
+                Some(DestinationOrder::BySourceTimestamp)
+            }
+            _ => None,
+        }
+    }
+}
+
 /// Controls how many samples are stored per instance.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum History {
@@ -523,6 +786,21 @@ impl AsFfi for History {
     }
 }
 
+impl FromFfi for History {
+    type Source = (cyclonedds_sys::dds_history_kind_t, i32);
+
+    #[inline]
+    fn from_ffi((kind, depth): Self::Source) -> Option<Self> {
+        match kind {
+            cyclonedds_sys::dds_history_kind_DDS_HISTORY_KEEP_ALL => Some(History::KeepAll),
+            cyclonedds_sys::dds_history_kind_DDS_HISTORY_KEEP_LAST => {
+                Some(History::KeepLast { depth })
+            }
+            _ => None,
+        }
+    }
+}
+
 /// Caps on the number of instances, samples, and samples per instance.
 ///
 /// When a limit is reached, incoming samples are rejected and the
@@ -548,6 +826,11 @@ pub enum ResourceLimit {
 }
 
 impl ResourceLimit {
+    #[must_use]
+    fn from_ffi(limit: i32) -> Self {
+        u32::try_from(limit).map_or(ResourceLimit::Unlimited, ResourceLimit::Limited)
+    }
+
     #[must_use]
     fn as_ffi(self) -> i32 {
         /// This is an invalid value on the Cyclone C side and will defer the
@@ -576,10 +859,29 @@ impl AsFfi for ResourceLimits {
     }
 }
 
+impl FromFfi for ResourceLimits {
+    type Source = (i32, i32, i32);
+
+    #[inline]
+    fn from_ffi(
+        (max_samples, max_instances, max_samples_per_instance): Self::Source,
+    ) -> Option<Self> {
+        Some(Self {
+            max_samples: ResourceLimit::from_ffi(max_samples),
+            max_instances: ResourceLimit::from_ffi(max_instances),
+            max_samples_per_instance: ResourceLimit::from_ffi(max_samples_per_instance),
+        })
+    }
+}
+
 /// Controls whether child entities are automatically enabled on creation.
 ///
 /// When `autoenable_created_entities` is `false`, entities must be explicitly
 /// enabled before they can communicate.
+///
+/// NOTE: Cyclone DDS does not support delayed enabling, so entities are enabled
+/// as soon as they are created regardless of this policy. Listeners that must
+/// see the first data should be passed when the entity is built instead.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct EntityFactory {
     /// If `true`, entities are enabled immediately on creation.
@@ -615,6 +917,17 @@ impl AsFfi for WriterDataLifecycle {
     }
 }
 
+impl FromFfi for WriterDataLifecycle {
+    type Source = bool;
+
+    #[inline]
+    fn from_ffi(autodispose_unregistered_instances: Self::Source) -> Option<Self> {
+        Some(Self {
+            autodispose_unregistered_instances,
+        })
+    }
+}
+
 /// Controls how the reader handles stale instance data after writers disappear.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct ReaderDataLifecycle {
@@ -641,66 +954,438 @@ impl AsFfi for ReaderDataLifecycle {
     }
 }
 
-// TODO validate the following QoS
-// ///
-// pub enum IgnoreLocal {
-//     ///
-//     Nothing,
-//     ///
-//     Participant,
-//     ///
-//     Process,
-// }
-
-// ///
-// pub enum TypeConsistency {
-//     ///
-//     DisallowTypeCoercion {
-//         ///
-//         force_type_validation: bool,
-//     },
-//     ///
-//     AllowTypeCoercion {
-//         ///
-//         ignore_sequence_bounds: bool,
-//         ///
-//         ignore_string_bounds: bool,
-//         ///
-//         ignore_member_names: bool,
-//         ///
-//         prevent_type_widening: bool,
-//         ///
-//         force_type_validation: bool,
-//     },
-// }
-
-// ///
-// pub struct WriterBatching {
-//     ///
-//     pub batch_updates: bool,
-// }
-
-// ///
-// pub struct PsmxInstances {
-//     ///
-//     pub instances: Vec<String>,
-// }
-
-// ///
-// pub enum DataRepresentationKind {
-//     ///
-//     Xcdr1,
-//     ///
-//     Xml,
-//     ///
-//     Xcdr2,
-// }
-
-// ///
-// pub struct DataRepresentation {
-//     ///
-//     pub representations: std::collections::HashSet<DataRepresentationKind>,
-// }
+impl FromFfi for ReaderDataLifecycle {
+    type Source = (
+        cyclonedds_sys::dds_duration_t,
+        cyclonedds_sys::dds_duration_t,
+    );
+
+    #[inline]
+    fn from_ffi(
+        (autopurge_nowriter_samples_delay, autopurge_disposed_samples_delay): Self::Source,
+    ) -> Option<Self> {
+        Some(Self {
+            autopurge_nowriter_samples_delay: Duration::from_nanos(
+                autopurge_nowriter_samples_delay,
+            ),
+            autopurge_disposed_samples_delay: Duration::from_nanos(
+                autopurge_disposed_samples_delay,
+            ),
+        })
+    }
+}
+
+/// Controls whether an entity ignores data published by entities local to it.
+///
+/// This is a Cyclone DDS extension. It allows, for example, a reader to avoid
+/// receiving the samples of writers within the same participant or process.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IgnoreLocal {
+    /// Local data is not ignored.
+    Nothing,
+    /// Data published by entities within the same participant is ignored.
+    Participant,
+    /// Data published by entities within the same process is ignored.
+    Process,
+}
+
+impl AsFfi for IgnoreLocal {
+    type Target<'a> = cyclonedds_sys::dds_ignorelocal_kind_t;
+
+    #[inline]
+    fn as_ffi(&self) -> Self::Target<'_> {
+        match self {
+            IgnoreLocal::Nothing => cyclonedds_sys::dds_ignorelocal_kind_DDS_IGNORELOCAL_NONE,
+            IgnoreLocal::Participant => {
+                cyclonedds_sys::dds_ignorelocal_kind_DDS_IGNORELOCAL_PARTICIPANT
+            }
+            IgnoreLocal::Process => cyclonedds_sys::dds_ignorelocal_kind_DDS_IGNORELOCAL_PROCESS,
+        }
+    }
+}
+
+impl FromFfi for IgnoreLocal {
+    type Source = cyclonedds_sys::dds_ignorelocal_kind_t;
+
+    #[inline]
+    fn from_ffi(kind: Self::Source) -> Option<Self> {
+        match kind {
+            cyclonedds_sys::dds_ignorelocal_kind_DDS_IGNORELOCAL_NONE => Some(IgnoreLocal::Nothing),
+            cyclonedds_sys::dds_ignorelocal_kind_DDS_IGNORELOCAL_PARTICIPANT => {
+                Some(IgnoreLocal::Participant)
+            }
+            cyclonedds_sys::dds_ignorelocal_kind_DDS_IGNORELOCAL_PROCESS => {
+                Some(IgnoreLocal::Process)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Controls how strictly the types of matching readers and writers must
+/// agree.
+///
+/// Defined by the DDS-XTypes specification. Only takes effect when Cyclone is
+/// built with type discovery enabled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TypeConsistency {
+    /// The reader and writer types must be identical.
+    DisallowTypeCoercion {
+        /// If `true`, matching requires the type information of the remote
+        /// entity to be available.
+        force_type_validation: bool,
+    },
+    /// The reader and writer types need only be assignable to one another.
+    AllowTypeCoercion {
+        /// If `true`, bounds on sequences are ignored when checking
+        /// assignability.
+        ignore_sequence_bounds: bool,
+        /// If `true`, bounds on strings are ignored when checking
+        /// assignability.
+        ignore_string_bounds: bool,
+        /// If `true`, member names are ignored and only member IDs are
+        /// compared.
+        ignore_member_names: bool,
+        /// If `true`, a reader type may not be wider than the writer type.
+        prevent_type_widening: bool,
+        /// If `true`, matching requires the type information of the remote
+        /// entity to be available.
+        force_type_validation: bool,
+    },
+}
+
+impl AsFfi for TypeConsistency {
+    type Target<'a> = (
+        cyclonedds_sys::dds_type_consistency_kind_t,
+        bool,
+        bool,
+        bool,
+        bool,
+        bool,
+    );
+
+    #[inline]
+    fn as_ffi(&self) -> Self::Target<'_> {
+        match *self {
+            TypeConsistency::DisallowTypeCoercion {
+                force_type_validation,
+            } => (
+                cyclonedds_sys::dds_type_consistency_kind_DDS_TYPE_CONSISTENCY_DISALLOW_TYPE_COERCION,
+                false,
+                false,
+                false,
+                false,
+                force_type_validation,
+            ),
+            TypeConsistency::AllowTypeCoercion {
+                ignore_sequence_bounds,
+                ignore_string_bounds,
+                ignore_member_names,
+                prevent_type_widening,
+                force_type_validation,
+            } => (
+                cyclonedds_sys::dds_type_consistency_kind_DDS_TYPE_CONSISTENCY_ALLOW_TYPE_COERCION,
+                ignore_sequence_bounds,
+                ignore_string_bounds,
+                ignore_member_names,
+                prevent_type_widening,
+                force_type_validation,
+            ),
+        }
+    }
+}
+
+impl FromFfi for TypeConsistency {
+    type Source = (
+        cyclonedds_sys::dds_type_consistency_kind_t,
+        bool,
+        bool,
+        bool,
+        bool,
+        bool,
+    );
+
+    #[inline]
+    fn from_ffi(
+        (
+            kind,
+            ignore_sequence_bounds,
+            ignore_string_bounds,
+            ignore_member_names,
+            prevent_type_widening,
+            force_type_validation,
+        ): Self::Source,
+    ) -> Option<Self> {
+        match kind {
+            cyclonedds_sys::dds_type_consistency_kind_DDS_TYPE_CONSISTENCY_DISALLOW_TYPE_COERCION => {
+                Some(TypeConsistency::DisallowTypeCoercion {
+                    force_type_validation,
+                })
+            }
+            cyclonedds_sys::dds_type_consistency_kind_DDS_TYPE_CONSISTENCY_ALLOW_TYPE_COERCION => {
+                Some(TypeConsistency::AllowTypeCoercion {
+                    ignore_sequence_bounds,
+                    ignore_string_bounds,
+                    ignore_member_names,
+                    prevent_type_widening,
+                    force_type_validation,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Controls whether a writer may batch updates before sending them.
+///
+/// This is a Cyclone DDS extension. When enabled, samples are queued until the
+/// writer is flushed, trading latency for throughput.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WriterBatching {
+    /// If `true`, samples are batched until an explicit flush.
+    pub batch_updates: bool,
+}
+
+impl AsFfi for WriterBatching {
+    type Target<'a> = bool;
+
+    #[inline]
+    fn as_ffi(&self) -> Self::Target<'_> {
+        self.batch_updates
+    }
+}
+
+impl FromFfi for WriterBatching {
+    type Source = bool;
+
+    #[inline]
+    fn from_ffi(batch_updates: Self::Source) -> Option<Self> {
+        Some(Self { batch_updates })
+    }
+}
+
+/// Restricts the PSMX (publish-subscribe message exchange) instances an entity
+/// may use.
+///
+/// This is a Cyclone DDS extension. Only the named shared-memory transport
+/// instances configured for the domain are considered for the entity.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PsmxInstances {
+    /// The names of the PSMX instances.
+    pub instances: Vec<String>,
+}
+
+impl AsFfi for PsmxInstances {
+    type Target<'a> = Vec<std::ffi::CString>;
+
+    #[inline]
+    fn as_ffi(&self) -> Self::Target<'_> {
+        self.instances
+            .iter()
+            .map(|instance| {
+                std::ffi::CString::new(instance.as_str()).unwrap_or_else(|err| {
+                    panic!(
+                        "unable to safely create std::ffi::CString from PSMX instance name: \
+                         {instance:?}: {err}"
+                    )
+                })
+            })
+            .collect()
+    }
+}
+
+impl FromFfi for PsmxInstances {
+    type Source = Vec<std::ffi::CString>;
+
+    #[inline]
+    fn from_ffi(instances: Self::Source) -> Option<Self> {
+        Some(Self {
+            instances: instances
+                .iter()
+                .map(|instance| instance.to_string_lossy().into_owned())
+                .collect(),
+        })
+    }
+}
+
+/// A serialized representation of sample data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DataRepresentationKind {
+    /// The classic (version 1) extended CDR encoding.
+    Xcdr1,
+    /// The XML representation.
+    Xml,
+    /// The version 2 extended CDR encoding.
+    Xcdr2,
+}
+
+impl AsFfi for DataRepresentationKind {
+    type Target<'a> = cyclonedds_sys::dds_data_representation_id_t;
+
+    #[inline]
+    fn as_ffi(&self) -> Self::Target<'_> {
+        match self {
+            DataRepresentationKind::Xcdr1 => cyclonedds_sys::DATA_REPRESENTATION_XCDR1,
+            DataRepresentationKind::Xml => cyclonedds_sys::DATA_REPRESENTATION_XML,
+            DataRepresentationKind::Xcdr2 => cyclonedds_sys::DATA_REPRESENTATION_XCDR2,
+        }
+    }
+}
+
+/// Declares the data representations an entity supports.
+///
+/// A writer uses the first representation in the list while a reader accepts
+/// any of the listed representations.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DataRepresentation {
+    /// The supported representations in order of preference.
+    pub representations: Vec<DataRepresentationKind>,
+}
+
+impl AsFfi for DataRepresentation {
+    type Target<'a> = Vec<cyclonedds_sys::dds_data_representation_id_t>;
+
+    #[inline]
+    fn as_ffi(&self) -> Self::Target<'_> {
+        self.representations
+            .iter()
+            .map(DataRepresentationKind::as_ffi)
+            .collect()
+    }
+}
+
+impl FromFfi for DataRepresentation {
+    type Source = Vec<cyclonedds_sys::dds_data_representation_id_t>;
+
+    #[inline]
+    fn from_ffi(representations: Self::Source) -> Option<Self> {
+        Some(Self {
+            representations: representations
+                .into_iter()
+                .map(|representation| match representation {
+                    cyclonedds_sys::DATA_REPRESENTATION_XCDR1 => {
+                        Some(DataRepresentationKind::Xcdr1)
+                    }
+                    cyclonedds_sys::DATA_REPRESENTATION_XML => Some(DataRepresentationKind::Xml),
+                    cyclonedds_sys::DATA_REPRESENTATION_XCDR2 => {
+                        Some(DataRepresentationKind::Xcdr2)
+                    }
+                    _ => None,
+                })
+                .collect::<Option<_>>()?,
+        })
+    }
+}
+
+/// Attaches a set of named properties to an entity.
+///
+/// Properties are used by Cyclone to configure plugins such as DDS Security
+/// and PSMX, and may be used by applications to tag entities. Properties
+/// marked for propagation are sent to remote participants during discovery.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Property {
+    /// The properties with string values.
+    pub value: Vec<StringProperty>,
+    /// The properties with binary values.
+    pub binary_value: Vec<BinaryProperty>,
+}
+
+/// A named property with a string value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StringProperty {
+    /// The name of the property.
+    pub name: String,
+    /// The value of the property.
+    pub value: String,
+    /// If `true`, the property is propagated during discovery.
+    pub propagate: bool,
+}
+
+/// A named property with a binary value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BinaryProperty {
+    /// The name of the property.
+    pub name: String,
+    /// The raw byte value of the property.
+    pub value: Vec<u8>,
+    /// If `true`, the property is propagated during discovery.
+    pub propagate: bool,
+}
+
+impl AsFfi for Property {
+    type Target<'a> = (
+        Vec<(std::ffi::CString, std::ffi::CString, bool)>,
+        Vec<(std::ffi::CString, &'a [u8], bool)>,
+    );
+
+    #[inline]
+    fn as_ffi(&self) -> Self::Target<'_> {
+        fn property_name(name: &str) -> std::ffi::CString {
+            std::ffi::CString::new(name).unwrap_or_else(|err| {
+                panic!(
+                    "unable to safely create std::ffi::CString from property name: {name:?}: {err}"
+                )
+            })
+        }
+
+        let value = self
+            .value
+            .iter()
+            .map(|property| {
+                let value = std::ffi::CString::new(property.value.as_str()).unwrap_or_else(|err| {
+                    panic!(
+                        "unable to safely create std::ffi::CString from property value: {:?}: \
+                         {err}",
+                        property.value
+                    )
+                });
+                (property_name(&property.name), value, property.propagate)
+            })
+            .collect();
+        let binary_value = self
+            .binary_value
+            .iter()
+            .map(|property| {
+                (
+                    property_name(&property.name),
+                    property.value.as_slice(),
+                    property.propagate,
+                )
+            })
+            .collect();
+
+        (value, binary_value)
+    }
+}
+
+impl FromFfi for Property {
+    type Source = (
+        Vec<(std::ffi::CString, std::ffi::CString, bool)>,
+        Vec<(std::ffi::CString, Vec<u8>, bool)>,
+    );
+
+    #[inline]
+    fn from_ffi((value, binary_value): Self::Source) -> Option<Self> {
+        Some(Self {
+            value: value
+                .into_iter()
+                .map(|(name, value, propagate)| StringProperty {
+                    name: name.to_string_lossy().into_owned(),
+                    value: value.to_string_lossy().into_owned(),
+                    propagate,
+                })
+                .collect(),
+            binary_value: binary_value
+                .into_iter()
+                .map(|(name, value, propagate)| BinaryProperty {
+                    name: name.to_string_lossy().into_owned(),
+                    value,
+                    propagate,
+                })
+                .collect(),
+        })
+    }
+}
 
 /// Assigns a human-readable name to an entity.
 ///
@@ -725,3 +1410,14 @@ impl AsFfi for EntityName {
         })
     }
 }
+
+impl FromFfi for EntityName {
+    type Source = std::ffi::CString;
+
+    #[inline]
+    fn from_ffi(name: Self::Source) -> Option<Self> {
+        Some(Self {
+            name: name.to_string_lossy().into_owned(),
+        })
+    }
+}
@@ -200,7 +200,7 @@ where
             .map(|listener| listener.as_ffi())
             .transpose()
             .and_then(|listener| {
-                Ok(Reader {
+                let reader = Reader {
                     inner: ffi::dds_create_reader(
                         self.subscriber
                             .map_or(ffi::dds_get_participant(self.topic.inner)?, |subscriber| {
@@ -211,7 +211,9 @@ where
                         listener.as_ref(),
                     )?,
                     phantom_topic: std::marker::PhantomData,
-                })
+                };
+                ffi::dds_register_undeserializable_reader::<T>(reader.inner)?;
+                Ok(reader)
             })
     }
 }
@@ -405,6 +407,49 @@ where
         ffi::dds_peek(self.inner)
     }
 
+    /// Removes all available samples from the reader cache and returns them
+    /// CDR-encoded, including the 4-byte encapsulation header.
+    ///
+    /// The encoding can be passed to
+    /// [`Writer::write_serialized`](crate::Writer::write_serialized) as-is.
+    /// For key-only samples, such as those produced by disposing of an
+    /// instance, only the key fields are encoded and
+    /// [`valid_data`](crate::sample::SerializedSample::valid_data) is `false`.
+    ///
+    /// A received sample that cannot be encoded again (which a sample written
+    /// with [`Writer::write`](crate::Writer::write) never is) is skipped, and
+    /// with the `log` feature a warning is logged for it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the reader fails to take samples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cyclonedds::{Domain, Participant, Topic, Writer, Reader};
+    /// # let domain = Domain::default();
+    /// # let participant = Participant::new(&domain)?;
+    /// # #[derive(
+    /// #     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+    /// # )]
+    /// # struct Data {
+    /// #     x: i32,
+    /// # }
+    /// let topic = Topic::<Data>::new(&participant, "Example")?;
+    /// let reader = Reader::new(&topic)?;
+    /// let writer = Writer::new(&topic)?;
+    ///
+    /// writer.write(&Data { x: 42 })?;
+    /// let samples = reader.take_serialized()?;
+    /// assert!(samples[0].valid_data);
+    /// assert_eq!(samples[0].serialized[4..], 42i32.to_ne_bytes());
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn take_serialized(&self) -> Result<Vec<crate::sample::SerializedSample>> {
+        ffi::dds_peek_read_take_serialized::<T, ffi::read_operation::Take>(self.inner)
+    }
+
     /// Returns the instance handles of all writers currently matched with
     /// this reader.
     ///
@@ -448,6 +493,432 @@ where
         Ok(matched)
     }
 
+    /// Returns the details of a writer matched with this reader.
+    ///
+    /// `handle` is one of the handles returned by
+    /// [`matched_publications`](Reader::matched_publications).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BadParameter`](crate::Error::BadParameter) if the reader is
+    /// invalid or `handle` does not identify a writer matched with this
+    /// reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cyclonedds::{Domain, Participant, Topic, Writer, Reader};
+    /// # let domain = Domain::default();
+    /// # let participant = Participant::new(&domain)?;
+    /// # #[derive(
+    /// #     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+    /// # )]
+    /// # struct Data {
+    /// #     x: i32,
+    /// # }
+    /// let topic = Topic::<Data>::new(&participant, "Example")?;
+    /// let reader = Reader::new(&topic)?;
+    /// let writer = Writer::new(&topic)?;
+    ///
+    /// for handle in reader.matched_publications()? {
+    ///     let publication = reader.matched_publication_data(handle)?;
+    ///     println!("matched {:?} on {}", publication.key, publication.topic_name);
+    /// }
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn matched_publication_data(
+        &self,
+        handle: crate::entity::InstanceHandle,
+    ) -> Result<crate::builtin::PublicationBuiltinTopicData> {
+        ffi::dds_get_matched_publication_data(self.inner, handle.inner)
+    }
+
+    /// Returns the name of the [`Topic`](crate::Topic) this reader is
+    /// associated with.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the topic name could not be
+    /// retrieved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cyclonedds::{Domain, Participant, Topic, Reader};
+    /// # let domain = Domain::default();
+    /// # let participant = Participant::new(&domain)?;
+    /// # #[derive(
+    /// #     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+    /// # )]
+    /// # struct Data {
+    /// #     x: i32,
+    /// # }
+    /// let topic = Topic::<Data>::new(&participant, "Example")?;
+    /// let reader = Reader::new(&topic)?;
+    ///
+    /// assert_eq!(reader.topic_name()?, "Example");
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn topic_name(&self) -> Result<String> {
+        ffi::dds_get_topic(self.inner).and_then(ffi::dds_get_name)
+    }
+
+    /// Returns the name of the type of the [`Topic`](crate::Topic) this
+    /// reader is associated with.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the type name could not be
+    /// retrieved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cyclonedds::{Domain, Participant, Topic, Reader};
+    /// # let domain = Domain::default();
+    /// # let participant = Participant::new(&domain)?;
+    /// # #[derive(
+    /// #     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+    /// # )]
+    /// # struct Data {
+    /// #     x: i32,
+    /// # }
+    /// let topic = Topic::<Data>::new(&participant, "Example")?;
+    /// let reader = Reader::new(&topic)?;
+    ///
+    /// println!("type name: {}", reader.type_name()?);
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn type_name(&self) -> Result<String> {
+        ffi::dds_get_topic(self.inner).and_then(ffi::dds_get_type_name)
+    }
+
+    /// Returns the [`SubscriptionMatched`](crate::status::SubscriptionMatched)
+    /// status, which counts the writers this reader has matched with.
+    ///
+    /// Retrieving the status resets the `delta` of its counters and clears the
+    /// corresponding [`Status`](crate::Status) flag.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the status could not be
+    /// retrieved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cyclonedds::{Domain, Participant, Topic, Reader, Writer};
+    /// # let domain = Domain::default();
+    /// # let participant = Participant::new(&domain)?;
+    /// # #[derive(
+    /// #     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+    /// # )]
+    /// # struct Data {
+    /// #     x: i32,
+    /// # }
+    /// use cyclonedds::entity::Entity;
+    ///
+    /// let topic = Topic::<Data>::new(&participant, "Example")?;
+    /// let reader = Reader::new(&topic)?;
+    /// let writer = Writer::new(&topic)?;
+    ///
+    /// let status = reader.subscription_matched_status()?;
+    /// assert_eq!(status.current.count, 1);
+    /// assert_eq!(status.last_publication_handle, writer.instance_handle()?);
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn subscription_matched_status(&self) -> Result<crate::status::SubscriptionMatched> {
+        ffi::dds_get_subscription_matched_status(self.inner)
+    }
+
+    /// Returns the [`LivelinessChanged`](crate::status::LivelinessChanged)
+    /// status, which counts the matched writers that are alive and not alive.
+    ///
+    /// Retrieving the status resets the `delta` of its counters and clears the
+    /// corresponding [`Status`](crate::Status) flag.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the status could not be
+    /// retrieved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cyclonedds::{Domain, Participant, Topic, Reader};
+    /// # let domain = Domain::default();
+    /// # let participant = Participant::new(&domain)?;
+    /// # #[derive(
+    /// #     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+    /// # )]
+    /// # struct Data {
+    /// #     x: i32,
+    /// # }
+    /// let topic = Topic::<Data>::new(&participant, "Example")?;
+    /// let reader = Reader::new(&topic)?;
+    ///
+    /// let status = reader.liveliness_changed_status()?;
+    /// println!("alive writers: {}", status.alive.count);
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn liveliness_changed_status(&self) -> Result<crate::status::LivelinessChanged> {
+        ffi::dds_get_liveliness_changed_status(self.inner)
+    }
+
+    /// Returns the [`SampleLost`](crate::status::SampleLost) status, which
+    /// counts the samples that were lost before reaching this reader.
+    ///
+    /// Retrieving the status resets the `delta` of its counters and clears the
+    /// corresponding [`Status`](crate::Status) flag.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the status could not be
+    /// retrieved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cyclonedds::{Domain, Participant, Topic, Reader};
+    /// # let domain = Domain::default();
+    /// # let participant = Participant::new(&domain)?;
+    /// # #[derive(
+    /// #     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+    /// # )]
+    /// # struct Data {
+    /// #     x: i32,
+    /// # }
+    /// let topic = Topic::<Data>::new(&participant, "Example")?;
+    /// let reader = Reader::new(&topic)?;
+    ///
+    /// assert_eq!(reader.sample_lost_status()?.total.count, 0);
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn sample_lost_status(&self) -> Result<crate::status::SampleLost> {
+        ffi::dds_get_sample_lost_status(self.inner)
+    }
+
+    /// Returns the [`SampleRejected`](crate::status::SampleRejected) status,
+    /// which counts the samples this reader rejected because of its
+    /// [`ResourceLimits`](crate::qos::policy::ResourceLimits).
+    ///
+    /// Retrieving the status resets the `delta` of its counters and clears the
+    /// corresponding [`Status`](crate::Status) flag.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the status could not be
+    /// retrieved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cyclonedds::{Domain, Participant, Topic, Reader};
+    /// # let domain = Domain::default();
+    /// # let participant = Participant::new(&domain)?;
+    /// # #[derive(
+    /// #     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+    /// # )]
+    /// # struct Data {
+    /// #     x: i32,
+    /// # }
+    /// let topic = Topic::<Data>::new(&participant, "Example")?;
+    /// let reader = Reader::new(&topic)?;
+    ///
+    /// assert_eq!(reader.sample_rejected_status()?.total.count, 0);
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn sample_rejected_status(&self) -> Result<crate::status::SampleRejected> {
+        ffi::dds_get_sample_rejected_status(self.inner)
+    }
+
+    /// Returns the
+    /// [`SampleUndeserializable`](crate::status::SampleUndeserializable)
+    /// status, which counts the incoming samples of the type that were dropped
+    /// because they could not be decoded into `T`.
+    ///
+    /// This is a per-type counter rather than a per-reader one: Cyclone
+    /// decodes an incoming sample once per type in the domain before it knows
+    /// which topic and readers it is for, so a sample that fails to decode is
+    /// counted once for `T` (and for types with the same
+    /// [`dds_type_name`](crate::Topicable::dds_type_name)), whichever topic it
+    /// was written on. Every reader of the type sees the same `count`, and
+    /// retrieving the status only resets the `delta` for this reader.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the status could not be
+    /// retrieved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cyclonedds::{Domain, Participant, Topic, Reader};
+    /// # let domain = Domain::default();
+    /// # let participant = Participant::new(&domain)?;
+    /// # #[derive(
+    /// #     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+    /// # )]
+    /// # struct Data {
+    /// #     x: i32,
+    /// # }
+    /// let topic = Topic::<Data>::new(&participant, "Example")?;
+    /// let reader = Reader::new(&topic)?;
+    ///
+    /// assert_eq!(reader.type_undeserializable_status()?.total.count, 0);
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn type_undeserializable_status(&self) -> Result<crate::status::SampleUndeserializable> {
+        ffi::dds_get_type_undeserializable_status::<T>(self.inner)
+    }
+
+    /// Sets a `callback` that receives the raw CDR bytes (including the
+    /// encapsulation header) and the decoding error of every incoming sample
+    /// of the type that could not be decoded into `T`.
+    ///
+    /// Such samples are always dropped, counted in
+    /// [`type_undeserializable_status`](Reader::type_undeserializable_status)
+    /// and logged. The callback lets an application keep the bytes, for
+    /// example to diagnose a type mismatch with a remote writer. The callback
+    /// belongs to this reader and replaces any callback previously set on it,
+    /// but like the status it is per type: it is invoked for samples of `T`
+    /// that failed to decode on any topic in the domain. It runs on a Cyclone
+    /// thread so it should return quickly.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the callback could not be set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cyclonedds::{Domain, Participant, Topic, Reader};
+    /// # let domain = Domain::default();
+    /// # let participant = Participant::new(&domain)?;
+    /// # #[derive(
+    /// #     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+    /// # )]
+    /// # struct Data {
+    /// #     x: i32,
+    /// # }
+    /// let topic = Topic::<Data>::new(&participant, "Example")?;
+    /// let reader = Reader::new(&topic)?;
+    ///
+    /// reader.set_type_undeserializable_callback(Box::new(|serialized, error| {
+    ///     eprintln!("undecodable sample ({} bytes): {error}", serialized.len());
+    /// }))?;
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn set_type_undeserializable_callback(
+        &self,
+        callback: crate::status::UndeserializableCallback,
+    ) -> Result<()> {
+        ffi::dds_set_type_undeserializable_callback::<T>(self.inner, Some(callback))
+    }
+
+    /// Removes the callback set with
+    /// [`set_type_undeserializable_callback`](Reader::set_type_undeserializable_callback).
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the callback could not be unset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cyclonedds::{Domain, Participant, Topic, Reader};
+    /// # let domain = Domain::default();
+    /// # let participant = Participant::new(&domain)?;
+    /// # #[derive(
+    /// #     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+    /// # )]
+    /// # struct Data {
+    /// #     x: i32,
+    /// # }
+    /// let topic = Topic::<Data>::new(&participant, "Example")?;
+    /// let reader = Reader::new(&topic)?;
+    ///
+    /// reader.set_type_undeserializable_callback(Box::new(|_, _| {}))?;
+    /// reader.unset_type_undeserializable_callback()?;
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn unset_type_undeserializable_callback(&self) -> Result<()> {
+        ffi::dds_set_type_undeserializable_callback::<T>(self.inner, None)
+    }
+
+    /// Returns the [`RequestedDeadlineMissed`](crate::status::RequestedDeadlineMissed)
+    /// status, which counts the instances that were not updated within this
+    /// reader's [`Deadline`](crate::qos::policy::Deadline).
+    ///
+    /// Retrieving the status resets the `delta` of its counters and clears the
+    /// corresponding [`Status`](crate::Status) flag.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the status could not be
+    /// retrieved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cyclonedds::{Domain, Participant, Topic, Reader};
+    /// # let domain = Domain::default();
+    /// # let participant = Participant::new(&domain)?;
+    /// # #[derive(
+    /// #     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+    /// # )]
+    /// # struct Data {
+    /// #     x: i32,
+    /// # }
+    /// let topic = Topic::<Data>::new(&participant, "Example")?;
+    /// let reader = Reader::new(&topic)?;
+    ///
+    /// assert_eq!(reader.requested_deadline_missed_status()?.total.count, 0);
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn requested_deadline_missed_status(
+        &self,
+    ) -> Result<crate::status::RequestedDeadlineMissed> {
+        ffi::dds_get_requested_deadline_missed_status(self.inner)
+    }
+
+    /// Returns the
+    /// [`RequestedIncompatibleQoS`](crate::status::RequestedIncompatibleQoS)
+    /// status, which counts the writers that offered a [`QoS`](crate::QoS)
+    /// incompatible with what this reader requested.
+    ///
+    /// Retrieving the status resets the `delta` of its counters and clears the
+    /// corresponding [`Status`](crate::Status) flag.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the status could not be
+    /// retrieved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cyclonedds::{Domain, Participant, Topic, Reader};
+    /// # let domain = Domain::default();
+    /// # let participant = Participant::new(&domain)?;
+    /// # #[derive(
+    /// #     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+    /// # )]
+    /// # struct Data {
+    /// #     x: i32,
+    /// # }
+    /// let topic = Topic::<Data>::new(&participant, "Example")?;
+    /// let reader = Reader::new(&topic)?;
+    ///
+    /// assert_eq!(reader.requested_incompatible_qos_status()?.total.count, 0);
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn requested_incompatible_qos_status(
+        &self,
+    ) -> Result<crate::status::RequestedIncompatibleQoS> {
+        ffi::dds_get_requested_incompatible_qos_status(self.inner)
+    }
+
     /// Blocks until all historical data available from matched writers with
     /// [`TransientLocal`](crate::qos::policy::Durability::TransientLocal) or
     /// higher durability has been received, or until `timeout` elapses.
@@ -598,7 +1069,8 @@ where
     T: crate::Topicable,
 {
     fn drop(&mut self) {
-        let result = ffi::dds_delete(self.inner);
+        let result = ffi::dds_unregister_undeserializable_reader::<T>(self.inner)
+            .and(ffi::dds_delete(self.inner));
         debug_assert!(
             result.is_ok(),
             "unable to delete {self:?}: failed with {result:?}"
@@ -737,6 +1209,25 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn test_reader_topic_and_type_name() {
+        let domain_id = crate::tests::domain::unique_id();
+        let domain = crate::Domain::new(domain_id).unwrap();
+        let topic_name = crate::tests::topic::unique_name();
+        let participant = crate::Participant::new(&domain).unwrap();
+        let topic = Topic::<crate::tests::topic::Data>::new(&participant, &topic_name).unwrap();
+
+        let mut reader = Reader::new(&topic).unwrap();
+        assert_eq!(reader.topic_name().unwrap(), topic_name);
+        assert!(!reader.type_name().unwrap().is_empty());
+
+        let reader_id = reader.inner;
+        reader.inner = 0;
+        assert_eq!(reader.topic_name().unwrap_err(), crate::Error::BadParameter);
+        assert_eq!(reader.type_name().unwrap_err(), crate::Error::BadParameter);
+        reader.inner = reader_id;
+    }
+
     #[test]
     fn test_reader_matched_publications() {
         let domain_id = crate::tests::domain::unique_id();
@@ -759,6 +1250,36 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn test_reader_matched_publication_data() {
+        let domain_id = crate::tests::domain::unique_id();
+        let domain = crate::Domain::new(domain_id).unwrap();
+        let topic_name = crate::tests::topic::unique_name();
+        let participant = crate::Participant::new(&domain).unwrap();
+        let topic = Topic::<crate::tests::topic::Data>::new(&participant, &topic_name).unwrap();
+        let qos = crate::QoS::new().with_reliability(crate::qos::policy::Reliability::Reliable {
+            max_blocking_time: crate::Duration::from_millis(10),
+        });
+
+        let reader = Reader::new(&topic).unwrap();
+        let writer = crate::Writer::builder(&topic)
+            .with_qos(&qos)
+            .build()
+            .unwrap();
+
+        let handle = writer.instance_handle().unwrap();
+        let publication = reader.matched_publication_data(handle).unwrap();
+        assert_eq!(publication.topic_name, topic_name);
+        assert!(!publication.type_name.is_empty());
+        assert_eq!(publication.qos.reliability(), qos.reliability());
+        assert_eq!(publication.user_data(), None);
+
+        let result = reader
+            .matched_publication_data(reader.instance_handle().unwrap())
+            .unwrap_err();
+        assert_eq!(result, crate::Error::BadParameter);
+    }
+
     #[test]
     fn test_reader_matched_publications_on_invalid_reader() {
         let domain_id = crate::tests::domain::unique_id();
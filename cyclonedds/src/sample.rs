@@ -23,11 +23,11 @@ where
 {
     Sample {
         sample: Box<T>,
-        materialized_key: std::cell::OnceCell<Box<T::Key>>,
+        materialized_key: std::sync::OnceLock<Box<T::Key>>,
     },
     Key {
         key: Box<T::Key>,
-        materialized_sample: std::cell::OnceCell<Box<T>>,
+        materialized_sample: std::sync::OnceLock<Box<T>>,
     },
 }
 
@@ -38,14 +38,14 @@ where
     pub fn new_sample(sample: T) -> Self {
         Self::Sample {
             sample: Box::new(sample),
-            materialized_key: std::cell::OnceCell::new(),
+            materialized_key: std::sync::OnceLock::new(),
         }
     }
 
     pub fn new_key(key: T::Key) -> Self {
         Self::Key {
             key: Box::new(key),
-            materialized_sample: std::cell::OnceCell::new(),
+            materialized_sample: std::sync::OnceLock::new(),
         }
     }
 
@@ -595,6 +595,21 @@ impl From<&cyclonedds_sys::dds_sample_info> for Info {
     }
 }
 
+/// A received sample in its serialized form, as returned by
+/// [`Reader::take_serialized`](crate::Reader::take_serialized).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SerializedSample {
+    /// CDR encoding of the sample, including the 4-byte encapsulation header.
+    pub serialized: Vec<u8>,
+    /// Metadata associated with the sample.
+    pub info: Info,
+    /// `false` if only the key fields are encoded in
+    /// [`serialized`](Self::serialized), such as for a sample produced by
+    /// disposing of an instance. Key-only encodings are not accepted by
+    /// [`Writer::write_serialized`](crate::Writer::write_serialized).
+    pub valid_data: bool,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
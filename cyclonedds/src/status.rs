@@ -4,6 +4,13 @@
 //! specification and carries event-specific detail such as counts and instance
 //! handles. See the [`listener`](crate::listener) module for how to register
 //! callbacks that receive these types.
+//!
+//! The current value of each status can also be polled, for example from a
+//! [`WaitSet`](crate::WaitSet) loop or a health check, through the
+//! corresponding getter such as
+//! [`Reader::subscription_matched_status`](crate::Reader::subscription_matched_status),
+//! [`Writer::publication_matched_status`](crate::Writer::publication_matched_status),
+//! or [`Topic::inconsistent_topic_status`](crate::Topic::inconsistent_topic_status).
 
 pub(crate) mod bitflags {
     bitflags::bitflags! {
@@ -298,6 +305,23 @@ pub struct SampleLost {
     pub total: Counter,
 }
 
+/// Returned by
+/// [`Reader::type_undeserializable_status`](crate::Reader::type_undeserializable_status)
+/// to count incoming samples that could not be decoded into the reader's type,
+/// typically because the remote writer uses a different version of the type.
+/// The count is kept per type, across all topics of the type in the domain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SampleUndeserializable {
+    /// Running count of samples of the type that failed to deserialize.
+    pub total: Counter,
+}
+
+/// Callback set with
+/// [`Reader::set_type_undeserializable_callback`](crate::Reader::set_type_undeserializable_callback)
+/// that receives the raw CDR bytes of an incoming sample that could not be
+/// decoded, along with the decoding error.
+pub type UndeserializableCallback = Box<dyn Fn(&[u8], &dyn std::error::Error) + Send + Sync>;
+
 /// Delivered to the
 /// [`with_sample_rejected`](crate::listener::ReaderListener::with_sample_rejected)
 /// callback when an incoming sample is rejected due to [`resource
@@ -670,6 +694,56 @@ mod tests {
         assert_eq!(result, QoSPolicyId::DataRepresentation);
     }
 
+    #[test]
+    fn test_status_getters() -> crate::Result<()> {
+        let domain_id = crate::tests::domain::unique_id();
+        let domain = crate::Domain::new(domain_id)?;
+        let participant = crate::Participant::new(&domain)?;
+        let topic_name = crate::tests::topic::unique_name();
+        let topic = crate::Topic::<crate::tests::topic::Data>::new(&participant, &topic_name)?;
+        let reader = crate::Reader::new(&topic)?;
+        let writer = crate::Writer::new(&topic)?;
+
+        let status = writer.publication_matched_status()?;
+        assert_eq!(status.total, Counter { count: 1, delta: 1 });
+        assert_eq!(status.current, Counter { count: 1, delta: 1 });
+        let status = reader.subscription_matched_status()?;
+        assert_eq!(status.total, Counter { count: 1, delta: 1 });
+        assert_eq!(status.current, Counter { count: 1, delta: 1 });
+
+        // Retrieving a status resets its deltas.
+        let status = writer.publication_matched_status()?;
+        assert_eq!(status.current, Counter { count: 1, delta: 0 });
+        let status = reader.subscription_matched_status()?;
+        assert_eq!(status.current, Counter { count: 1, delta: 0 });
+
+        let second_reader = crate::Reader::new(&topic)?;
+        assert_eq!(
+            writer.publication_matched_status()?.current,
+            Counter { count: 2, delta: 1 }
+        );
+        drop(second_reader);
+        assert_eq!(
+            writer.publication_matched_status()?.current,
+            Counter {
+                count: 1,
+                delta: -1
+            }
+        );
+
+        assert_eq!(topic.inconsistent_topic_status()?.total.count, 0);
+        assert_eq!(writer.liveliness_lost_status()?.total.count, 0);
+        assert_eq!(writer.offered_deadline_missed_status()?.total.count, 0);
+        assert_eq!(writer.offered_incompatible_qos_status()?.total.count, 0);
+        assert_eq!(reader.sample_lost_status()?.total.count, 0);
+        assert_eq!(reader.sample_rejected_status()?.total.count, 0);
+        assert_eq!(reader.requested_deadline_missed_status()?.total.count, 0);
+        assert_eq!(reader.requested_incompatible_qos_status()?.total.count, 0);
+        assert_eq!(reader.liveliness_changed_status()?.not_alive.count, 0);
+
+        Ok(())
+    }
+
     #[test]
     #[should_panic = "internal error: entered unreachable code: unsupported value"]
     fn test_qos_policy_id_conversion_out_of_range() {
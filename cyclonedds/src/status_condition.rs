@@ -0,0 +1,367 @@
+use crate::entity::Entity;
+use crate::{Result, Status};
+
+use crate::internal::ffi;
+
+/// A condition that triggers when a communication status of an entity
+/// changes, for use with a [`WaitSet`](crate::WaitSet).
+///
+/// Every entity has exactly one status condition. Its *enabled statuses*
+/// select which status changes trigger the condition. After a
+/// [`wait`](crate::WaitSet::wait) returns, [`triggered`](StatusCondition::triggered)
+/// reports which of the enabled statuses fired, so that a single attachment
+/// can tell, for example, a [`PublicationMatched`](Status::PublicationMatched)
+/// event apart from an [`OfferedDeadlineMissed`](Status::OfferedDeadlineMissed)
+/// event on the same writer.
+///
+/// In Cyclone DDS the status condition is the entity itself: the enabled
+/// statuses are the entity's [status mask](Entity::status_mask) and dropping
+/// the `StatusCondition` does not delete anything.
+///
+/// # Examples
+///
+/// ```
+/// use cyclonedds::{Duration, Reader, Status, StatusCondition, Topic, WaitSet, Writer};
+/// # use cyclonedds::{Domain, Participant};
+/// # #[derive(
+/// #     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+/// # )]
+/// # struct Data {
+/// #     x: i32,
+/// # }
+/// # let domain = Domain::default();
+/// # let participant = Participant::new(&domain)?;
+/// let topic = Topic::<Data>::new(&participant, "Example")?;
+/// let writer = Writer::new(&topic)?;
+///
+/// let condition = StatusCondition::new(&writer)?;
+/// condition.set_enabled_statuses(Status::PublicationMatched | Status::OfferedDeadlineMissed)?;
+///
+/// let mut waitset = WaitSet::<()>::new(&participant)?;
+/// waitset.attach(&condition, None)?;
+///
+/// let _reader = Reader::new(&topic)?;
+/// waitset.wait(Duration::from_secs(5))?;
+///
+/// let fired = condition.take_triggered()?;
+/// assert!(fired.contains(Status::PublicationMatched));
+/// assert!(!fired.contains(Status::OfferedDeadlineMissed));
+/// # Ok::<_, cyclonedds::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct StatusCondition<'entity> {
+    pub(crate) inner: cyclonedds_sys::dds_entity_t,
+    phantom: std::marker::PhantomData<&'entity (dyn Entity + Sync)>,
+}
+
+impl<'e> StatusCondition<'e> {
+    /// Returns the status condition of `entity`.
+    ///
+    /// The enabled statuses are initially those of the entity's current
+    /// [status mask](Entity::status_mask).
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if `entity` is not a valid entity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cyclonedds::{Domain, Participant};
+    /// # let domain = Domain::default();
+    /// # let participant = Participant::new(&domain)?;
+    /// use cyclonedds::StatusCondition;
+    ///
+    /// let condition = StatusCondition::new(&participant)?;
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn new(entity: &'e (dyn Entity + Sync)) -> Result<Self> {
+        let inner = entity.id().inner;
+        // Validate the handle up front so that errors surface here rather
+        // than on first use.
+        ffi::dds_get_status_mask(inner)?;
+        Ok(Self {
+            inner,
+            phantom: std::marker::PhantomData,
+        })
+    }
+
+    /// Returns the statuses that trigger this condition.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an [`Error`](crate::Error) if the enabled statuses could not be retrieved.
+    ///
+    /// - Returns [`BadParameter`](crate::Error::BadParameter) if the retrieved bits do not
+    ///   correspond to a valid [`Status`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cyclonedds::{Domain, Participant, Topic, Writer};
+    /// # #[derive(
+    /// #     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+    /// # )]
+    /// # struct Data {
+    /// #     x: i32,
+    /// # }
+    /// # let domain = Domain::default();
+    /// # let participant = Participant::new(&domain)?;
+    /// # let topic = Topic::<Data>::new(&participant, "Example")?;
+    /// # let writer = Writer::new(&topic)?;
+    /// use cyclonedds::{Status, StatusCondition};
+    ///
+    /// let condition = StatusCondition::new(&writer)?;
+    /// assert!(condition.enabled_statuses()?.contains(Status::PublicationMatched));
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn enabled_statuses(&self) -> Result<Status> {
+        let mask = ffi::dds_get_status_mask(self.inner)?;
+        Status::from_bits(mask).ok_or(crate::Error::BadParameter)
+    }
+
+    /// Sets the statuses that trigger this condition.
+    ///
+    /// Statuses outside of `statuses` no longer wake an attached
+    /// [`WaitSet`](crate::WaitSet) and are not reported by
+    /// [`triggered`](StatusCondition::triggered).
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the enabled statuses could not be
+    /// set (e.g. `statuses` contains entries that do not apply to the entity
+    /// type).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cyclonedds::{Domain, Participant, Topic, Writer};
+    /// # #[derive(
+    /// #     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+    /// # )]
+    /// # struct Data {
+    /// #     x: i32,
+    /// # }
+    /// # let domain = Domain::default();
+    /// # let participant = Participant::new(&domain)?;
+    /// # let topic = Topic::<Data>::new(&participant, "Example")?;
+    /// # let writer = Writer::new(&topic)?;
+    /// use cyclonedds::{Status, StatusCondition};
+    ///
+    /// let condition = StatusCondition::new(&writer)?;
+    /// condition.set_enabled_statuses(Status::OfferedDeadlineMissed)?;
+    /// assert_eq!(condition.enabled_statuses()?, Status::OfferedDeadlineMissed);
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn set_enabled_statuses(&self, statuses: Status) -> Result<()> {
+        ffi::dds_set_status_mask(self.inner, statuses.bits())
+    }
+
+    /// Returns the enabled statuses that have changed without clearing them.
+    ///
+    /// A non-empty result means the condition is triggered. The flags remain
+    /// set, so an attached [`WaitSet`](crate::WaitSet) keeps waking until they
+    /// are cleared with [`take_triggered`](StatusCondition::take_triggered) or
+    /// by retrieving the corresponding status (e.g.
+    /// [`Writer::publication_matched_status`](crate::Writer::publication_matched_status)).
+    ///
+    /// # Errors
+    ///
+    /// - Returns an [`Error`](crate::Error) if the status flags could not be read.
+    ///
+    /// - Returns [`BadParameter`](crate::Error::BadParameter) if the retrieved bits do not
+    ///   correspond to a valid [`Status`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cyclonedds::{Domain, Participant, Reader, Topic, Writer};
+    /// # #[derive(
+    /// #     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+    /// # )]
+    /// # struct Data {
+    /// #     x: i32,
+    /// # }
+    /// # let domain = Domain::default();
+    /// # let participant = Participant::new(&domain)?;
+    /// # let topic = Topic::<Data>::new(&participant, "Example")?;
+    /// use cyclonedds::{Status, StatusCondition};
+    ///
+    /// let reader = Reader::new(&topic)?;
+    /// let condition = StatusCondition::new(&reader)?;
+    /// condition.set_enabled_statuses(Status::SubscriptionMatched)?;
+    ///
+    /// let writer = Writer::new(&topic)?;
+    /// assert_eq!(condition.triggered()?, Status::SubscriptionMatched);
+    /// // The flag is preserved.
+    /// assert_eq!(condition.triggered()?, Status::SubscriptionMatched);
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn triggered(&self) -> Result<Status> {
+        let mask = ffi::dds_get_status_mask(self.inner)?;
+        let status = ffi::dds_read_status(self.inner, mask)?;
+        Status::from_bits(status).ok_or(crate::Error::BadParameter)
+    }
+
+    /// Returns the enabled statuses that have changed and clears them.
+    ///
+    /// Unlike [`triggered`](StatusCondition::triggered), this resets the
+    /// returned flags so that an attached [`WaitSet`](crate::WaitSet) only
+    /// wakes again on a new status change.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an [`Error`](crate::Error) if the status flags could not be taken.
+    ///
+    /// - Returns [`BadParameter`](crate::Error::BadParameter) if the retrieved bits do not
+    ///   correspond to a valid [`Status`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cyclonedds::{Domain, Participant, Reader, Topic, Writer};
+    /// # #[derive(
+    /// #     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+    /// # )]
+    /// # struct Data {
+    /// #     x: i32,
+    /// # }
+    /// # let domain = Domain::default();
+    /// # let participant = Participant::new(&domain)?;
+    /// # let topic = Topic::<Data>::new(&participant, "Example")?;
+    /// use cyclonedds::{Status, StatusCondition};
+    ///
+    /// let reader = Reader::new(&topic)?;
+    /// let condition = StatusCondition::new(&reader)?;
+    /// condition.set_enabled_statuses(Status::SubscriptionMatched)?;
+    ///
+    /// let writer = Writer::new(&topic)?;
+    /// assert_eq!(condition.take_triggered()?, Status::SubscriptionMatched);
+    /// // The flag has been cleared.
+    /// assert!(condition.take_triggered()?.is_empty());
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn take_triggered(&self) -> Result<Status> {
+        let mask = ffi::dds_get_status_mask(self.inner)?;
+        let status = ffi::dds_take_status(self.inner, mask)?;
+        Status::from_bits(status).ok_or(crate::Error::BadParameter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_condition_create() {
+        let domain_id = crate::tests::domain::unique_id();
+        let domain = crate::Domain::new(domain_id).unwrap();
+        let participant = crate::Participant::new(&domain).unwrap();
+
+        let condition = StatusCondition::new(&participant).unwrap();
+        assert_eq!(condition.id(), participant.id());
+    }
+
+    #[test]
+    fn test_status_condition_with_invalid_entity() {
+        let domain_id = crate::tests::domain::unique_id();
+        let domain = crate::Domain::new(domain_id).unwrap();
+        let mut participant = crate::Participant::new(&domain).unwrap();
+        let participant_id = participant.inner;
+        participant.inner = 0;
+        let result = StatusCondition::new(&participant);
+        participant.inner = participant_id;
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_status_condition_enabled_statuses() {
+        let domain_id = crate::tests::domain::unique_id();
+        let domain = crate::Domain::new(domain_id).unwrap();
+        let participant = crate::Participant::new(&domain).unwrap();
+        let topic_name = crate::tests::topic::unique_name();
+        let topic =
+            crate::Topic::<crate::tests::topic::Data>::new(&participant, &topic_name).unwrap();
+        let writer = crate::Writer::new(&topic).unwrap();
+        let condition = StatusCondition::new(&writer).unwrap();
+
+        assert_eq!(
+            condition.enabled_statuses().unwrap(),
+            writer.status_mask().unwrap()
+        );
+
+        condition
+            .set_enabled_statuses(Status::OfferedDeadlineMissed)
+            .unwrap();
+        assert_eq!(
+            condition.enabled_statuses().unwrap(),
+            Status::OfferedDeadlineMissed
+        );
+        assert_eq!(writer.status_mask().unwrap(), Status::OfferedDeadlineMissed);
+    }
+
+    #[test]
+    fn test_status_condition_disabled_status_does_not_trigger() {
+        let domain_id = crate::tests::domain::unique_id();
+        let domain = crate::Domain::new(domain_id).unwrap();
+        let participant = crate::Participant::new(&domain).unwrap();
+        let topic_name = crate::tests::topic::unique_name();
+        let topic =
+            crate::Topic::<crate::tests::topic::Data>::new(&participant, &topic_name).unwrap();
+        let writer = crate::Writer::new(&topic).unwrap();
+        let condition = StatusCondition::new(&writer).unwrap();
+        condition
+            .set_enabled_statuses(Status::OfferedDeadlineMissed)
+            .unwrap();
+
+        let mut waitset = crate::WaitSet::<()>::new(&participant).unwrap();
+        waitset.attach(&condition, None).unwrap();
+
+        let _reader = crate::Reader::new(&topic).unwrap();
+        let result = waitset.wait(crate::Duration::from_millis(50)).unwrap_err();
+        assert_eq!(result, crate::Error::Timeout);
+        assert!(condition.triggered().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_status_condition_distinguishes_statuses_after_wait() {
+        let domain_id = crate::tests::domain::unique_id();
+        let domain = crate::Domain::new(domain_id).unwrap();
+        let participant = crate::Participant::new(&domain).unwrap();
+        let topic_name = crate::tests::topic::unique_name();
+        let topic =
+            crate::Topic::<crate::tests::topic::Data>::new(&participant, &topic_name).unwrap();
+        let qos = crate::QoS::new().with_deadline(crate::qos::policy::Deadline {
+            period: crate::Duration::from_millis(10),
+        });
+        let writer = crate::Writer::builder(&topic)
+            .with_qos(&qos)
+            .build()
+            .unwrap();
+        let condition = StatusCondition::new(&writer).unwrap();
+        condition
+            .set_enabled_statuses(Status::PublicationMatched | Status::OfferedDeadlineMissed)
+            .unwrap();
+
+        let blob = "writer";
+        let mut waitset = crate::WaitSet::new(&participant).unwrap();
+        waitset.attach(&condition, Some(&blob)).unwrap();
+
+        let _reader = crate::Reader::new(&topic).unwrap();
+        let actual = waitset.wait(crate::Duration::from_secs(5)).unwrap();
+        assert_eq!(actual, vec![&blob]);
+        assert_eq!(
+            condition.take_triggered().unwrap(),
+            Status::PublicationMatched
+        );
+
+        writer.write(&crate::tests::topic::Data::default()).unwrap();
+        let actual = waitset.wait(crate::Duration::from_secs(5)).unwrap();
+        assert_eq!(actual, vec![&blob]);
+        assert_eq!(
+            condition.take_triggered().unwrap(),
+            Status::OfferedDeadlineMissed
+        );
+    }
+}
@@ -225,6 +225,60 @@ impl<'d, 'p> Subscriber<'d, 'p> {
         ffi::dds_notify_readers(self.inner)
     }
 
+    /// Begins access to a coherent set of changes on all readers belonging to
+    /// this subscriber.
+    ///
+    /// While the returned [`GroupAccess`] is alive, reading from the readers of
+    /// this subscriber observes the received coherent sets consistently across
+    /// those readers. Access ends when the guard is dropped or
+    /// [`GroupAccess::end`] is called.
+    ///
+    /// This only has an effect if the subscriber's
+    /// [`Presentation`](crate::qos::policy::Presentation) policy uses the
+    /// `Group` access scope with `coherent_access` or `ordered_access` enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the access could not be started.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cyclonedds::qos::policy::Presentation;
+    /// use cyclonedds::{QoS, Reader, Subscriber, Topic};
+    /// # use cyclonedds::{Domain, Participant};
+    /// # let domain = Domain::default();
+    /// # let participant = Participant::new(&domain)?;
+    /// # #[derive(
+    /// #     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+    /// # )]
+    /// # struct Data {
+    /// #     x: i32,
+    /// # }
+    /// let qos = QoS::new().with_presentation(Presentation::Group {
+    ///     coherent_access: true,
+    ///     ordered_access: false,
+    /// });
+    /// let subscriber = Subscriber::builder(&participant).with_qos(&qos).build()?;
+    ///
+    /// let topic01 = Topic::<Data>::new(&participant, "MyTopic01")?;
+    /// let topic02 = Topic::<Data>::new(&participant, "MyTopic02")?;
+    /// let reader01 = Reader::builder(&topic01).with_subscriber(&subscriber).build()?;
+    /// let reader02 = Reader::builder(&topic02).with_subscriber(&subscriber).build()?;
+    ///
+    /// let access = subscriber.begin_access()?;
+    /// let samples01 = reader01.take()?;
+    /// let samples02 = reader02.take()?;
+    /// access.end()?;
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn begin_access(&self) -> Result<GroupAccess<'_, 'd, 'p>> {
+        // NOTE: Cyclone exposes begin/end access on subscribers through the
+        // coherent set functions.
+        ffi::dds_begin_coherent(self.inner)?;
+        Ok(GroupAccess { subscriber: self })
+    }
+
     pub(crate) const fn from_existing(
         inner: cyclonedds_sys::dds_entity_t,
     ) -> std::mem::ManuallyDrop<Self> {
@@ -313,6 +367,40 @@ impl<'d, 'p> Subscriber<'d, 'p> {
     }
 }
 
+/// Access to the coherent sets received by a [`Subscriber`] (created via
+/// [`Subscriber::begin_access`]).
+///
+/// Access ends when this guard is dropped. Use [`end`](GroupAccess::end) to
+/// end it explicitly and observe any error.
+#[derive(Debug)]
+#[must_use = "the access ends immediately if the guard is not held"]
+pub struct GroupAccess<'subscriber, 'domain, 'participant> {
+    subscriber: &'subscriber Subscriber<'domain, 'participant>,
+}
+
+impl GroupAccess<'_, '_, '_> {
+    /// Ends the access to the subscriber's readers.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the access could not be ended.
+    pub fn end(self) -> Result<()> {
+        let this = std::mem::ManuallyDrop::new(self);
+        ffi::dds_end_coherent(this.subscriber.inner)
+    }
+}
+
+impl Drop for GroupAccess<'_, '_, '_> {
+    fn drop(&mut self) {
+        let result = ffi::dds_end_coherent(self.subscriber.inner);
+        debug_assert!(
+            result.is_ok(),
+            "unable to end access on {:?}: failed with {result:?}",
+            self.subscriber
+        );
+    }
+}
+
 impl Drop for Subscriber<'_, '_> {
     fn drop(&mut self) {
         let result = ffi::dds_delete(self.inner);
@@ -426,4 +514,41 @@ mod tests {
         assert_eq!(result, crate::Error::BadParameter);
         subscriber.inner = subscriber_id;
     }
+
+    #[test]
+    fn test_subscriber_begin_end_access() {
+        let domain_id = crate::tests::domain::unique_id();
+        let domain = crate::Domain::new(domain_id).unwrap();
+        let participant = Participant::new(&domain).unwrap();
+        let qos = crate::QoS::new().with_presentation(crate::qos::policy::Presentation::Group {
+            coherent_access: true,
+            ordered_access: false,
+        });
+        let subscriber = Subscriber::builder(&participant)
+            .with_qos(&qos)
+            .build()
+            .unwrap();
+
+        let access = subscriber.begin_access().unwrap();
+        access.end().unwrap();
+
+        {
+            let _access = subscriber.begin_access().unwrap();
+        }
+        let _access = subscriber.begin_access().unwrap();
+    }
+
+    #[test]
+    fn test_subscriber_begin_access_on_invalid_subscriber() {
+        let domain_id = crate::tests::domain::unique_id();
+        let domain = crate::Domain::new(domain_id).unwrap();
+        let participant = Participant::new(&domain).unwrap();
+        let mut subscriber = Subscriber::new(&participant).unwrap();
+        let subscriber_id = subscriber.inner;
+        subscriber.inner = 0;
+
+        let result = subscriber.begin_access().unwrap_err();
+        assert_eq!(result, crate::Error::BadParameter);
+        subscriber.inner = subscriber_id;
+    }
 }
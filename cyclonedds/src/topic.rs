@@ -257,6 +257,39 @@ where
         TopicBuilder::new(participant, topic_name)
     }
 
+    /// Returns the [`InconsistentTopic`](crate::status::InconsistentTopic)
+    /// status, which counts the remote topics discovered with the same name but
+    /// an inconsistent type.
+    ///
+    /// Retrieving the status resets the `delta` of its counters and clears the
+    /// corresponding [`Status`](crate::Status) flag.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the status could not be
+    /// retrieved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cyclonedds::{Domain, Participant, Topic};
+    /// # let domain = Domain::default();
+    /// # let participant = Participant::new(&domain)?;
+    /// # #[derive(
+    /// #     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+    /// # )]
+    /// # struct Data {
+    /// #     x: i32,
+    /// # }
+    /// let topic = Topic::<Data>::new(&participant, "Example")?;
+    ///
+    /// assert_eq!(topic.inconsistent_topic_status()?.total.count, 0);
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn inconsistent_topic_status(&self) -> Result<crate::status::InconsistentTopic> {
+        ffi::dds_get_inconsistent_topic_status(self.inner)
+    }
+
     pub(crate) const fn from_existing(
         inner: cyclonedds_sys::dds_entity_t,
     ) -> std::mem::ManuallyDrop<Self> {
@@ -15,6 +15,19 @@
 /// gets generated which contains just the fields marked as a key fields. To
 /// access this type see the [`Key` type alias](crate::topicable::Key).
 ///
+/// # Thread safety
+///
+/// Cyclone DDS hands samples to its own threads (e.g. for delivery to local
+/// readers, retransmits, and listener callbacks) independently of the thread
+/// that wrote or read them, so both the type and its key must be
+/// [`Send`] + [`Sync`] and the type must be `'static`.
+///
+/// These bounds were added after the 0.0.4 release and are a breaking change:
+/// a type holding e.g. an [`Rc`](std::rc::Rc), a
+/// [`RefCell`](std::cell::RefCell) or a non-`'static` reference can no longer
+/// be a topic payload and has to switch to its thread-safe, owned
+/// counterpart.
+///
 /// # Examples
 ///
 /// ```
@@ -53,7 +66,13 @@
 /// }
 /// ```
 pub trait Topicable:
-    serde::ser::Serialize + serde::de::DeserializeOwned + std::clone::Clone + std::fmt::Debug
+    serde::ser::Serialize
+    + serde::de::DeserializeOwned
+    + std::clone::Clone
+    + std::fmt::Debug
+    + Send
+    + Sync
+    + 'static
 {
     /// The key type that uniquely identifies an instance of this topic.
     ///
@@ -70,6 +89,8 @@ pub trait Topicable:
         + std::fmt::Debug
         + std::cmp::PartialEq
         + std::hash::Hash
+        + Send
+        + Sync
         + crate::cdr_bounds::CdrBounds;
 
     /// Whether this type has a meaningful key.
@@ -90,6 +111,30 @@ pub trait Topicable:
     /// MD5 otherwise. Set this to `true` to force MD5 unconditionally.
     const FORCE_MD5_KEYHASH: bool = false;
 
+    /// Encodes samples in XCDR2, with [`Option`] struct members as DDS-XTypes
+    /// optional members.
+    ///
+    /// When `true`, samples are encoded the way Cyclone encodes final IDL types
+    /// in XCDR2, and every [`Option`] that is a member of a struct within the
+    /// sample is encoded the way an IDL `@optional` member is: a presence flag
+    /// followed by the value when present. This is what lets a Rust type
+    /// interoperate with an IDL type that uses `@optional`, as long as the
+    /// endpoints use the [`Xcdr2`](crate::qos::policy::DataRepresentationKind::Xcdr2)
+    /// data representation. An [`Option`] anywhere else (e.g. in a
+    /// `Vec<Option<T>>`) then fails to serialize, as does a field skipped with
+    /// `#[serde(skip_serializing_if = "...")]`.
+    ///
+    /// Whether a sequence is preceded by the DHEADER that XCDR2 puts before
+    /// sequences of non-primitive elements is decided from the Rust type of its
+    /// elements, so sequences of enums and fixed-size arrays of non-primitive
+    /// elements are not encoded the way IDL encodes them.
+    ///
+    /// The [`derive`](cyclonedds_macros::Topicable) macro sets this when the
+    /// type is annotated with `#[dds(optional_members)]`. The key is always
+    /// encoded in XCDR1, with an [`Option`] as a `u32` discriminant followed by
+    /// the value when present.
+    const OPTIONAL_MEMBERS: bool = false;
+
     /// Constructs a default instance of `Self` from a key.
     ///
     /// Used to materialize a full sample from a key-only notification. Fields
@@ -292,7 +292,9 @@ where
     /// # Errors
     ///
     /// Returns an [`Error`](crate::Error) if the writers fails to write the
-    /// sample.
+    /// sample, or [`Error::Serialization`](crate::Error::Serialization) if the
+    /// sample cannot be CDR-encoded (e.g. because it exceeds its bounds), in
+    /// which case no reader receives it.
     ///
     /// # Examples
     ///
@@ -314,6 +316,7 @@ where
     /// writer.write(&Data { x: 1, y: 2 })?;
     /// # Ok::<_, cyclonedds::Error>(())
     /// ```
+    #[track_caller]
     pub fn write(&self, sample: &T) -> Result<()> {
         ffi::dds_write(self.inner, sample)
     }
@@ -350,10 +353,54 @@ where
     /// writer.write_with_timestamp(&Data::default(), Time::from_secs(1))?;
     /// # Ok::<_, cyclonedds::Error>(())
     /// ```
+    #[track_caller]
     pub fn write_with_timestamp(&self, sample: &T, timestamp: crate::Time) -> Result<()> {
         ffi::dds_write_with_timestamp(self.inner, sample, timestamp.inner)
     }
 
+    /// Writes a sample that is already CDR-encoded, including the 4-byte
+    /// encapsulation header.
+    ///
+    /// This lets bridges and recorders forward payloads obtained from
+    /// [`Reader::take_serialized`](crate::Reader::take_serialized) without
+    /// handling `T` themselves. The payload is still checked by decoding it as
+    /// a `T` but is then sent unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::BadParameter`](crate::Error::BadParameter) if
+    /// `serialized` is not a valid encoding of a `T`, or another
+    /// [`Error`](crate::Error) if the writer fails to write the sample.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cyclonedds::{Domain, Participant, Topic, Writer, Reader};
+    /// # let domain = Domain::default();
+    /// # let participant = Participant::new(&domain)?;
+    /// # #[derive(
+    /// #     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+    /// # )]
+    /// # struct Data {
+    /// #     x: i32,
+    /// # }
+    /// let topic = Topic::<Data>::new(&participant, "MyTopic")?;
+    /// let reader = Reader::new(&topic)?;
+    /// let writer = Writer::new(&topic)?;
+    ///
+    /// // A little-endian CDR header followed by `x`.
+    /// writer.write_serialized(&[0x00, 0x01, 0x00, 0x00, 0x2a, 0x00, 0x00, 0x00])?;
+    /// let samples = reader.take()?;
+    /// assert_eq!(samples[0].sample().map(|sample| sample.x), Some(42));
+    ///
+    /// // Too short to hold `x`.
+    /// assert!(writer.write_serialized(&[0x00, 0x01, 0x00, 0x00, 0x2a]).is_err());
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn write_serialized(&self, serialized: &[u8]) -> Result<()> {
+        ffi::dds_write_serialized::<T>(self.inner, serialized)
+    }
+
     /// Flushes batched samples to the network.
     ///
     /// Only relevant when write batching is enabled in the domain
@@ -470,6 +517,245 @@ where
         })
     }
 
+    /// Returns the details of a reader matched with this writer.
+    ///
+    /// `handle` is one of the handles returned by
+    /// [`matched_subscriptions`](Writer::matched_subscriptions).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BadParameter`](crate::Error::BadParameter) if the writer is
+    /// invalid or `handle` does not identify a reader matched with this
+    /// writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cyclonedds::{Domain, Participant, Topic, Writer, Reader};
+    /// # let domain = Domain::default();
+    /// # let participant = Participant::new(&domain)?;
+    /// # #[derive(
+    /// #     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+    /// # )]
+    /// # struct Data {
+    /// #     x: i32,
+    /// # }
+    /// let topic = Topic::<Data>::new(&participant, "MyTopic")?;
+    /// let writer = Writer::new(&topic)?;
+    /// let reader = Reader::new(&topic)?;
+    ///
+    /// for handle in writer.matched_subscriptions()? {
+    ///     let subscription = writer.matched_subscription_data(handle)?;
+    ///     assert_eq!(subscription.topic_name, "MyTopic");
+    /// }
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn matched_subscription_data(
+        &self,
+        handle: crate::entity::InstanceHandle,
+    ) -> Result<crate::builtin::SubscriptionBuiltinTopicData> {
+        ffi::dds_get_matched_subscription_data(self.inner, handle.inner)
+    }
+
+    /// Returns the name of the [`Topic`](crate::Topic) this writer is
+    /// associated with.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the topic name could not be
+    /// retrieved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cyclonedds::{Domain, Participant, Topic, Writer};
+    /// # let domain = Domain::default();
+    /// # let participant = Participant::new(&domain)?;
+    /// # #[derive(
+    /// #     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+    /// # )]
+    /// # struct Data {
+    /// #     x: i32,
+    /// # }
+    /// let topic = Topic::<Data>::new(&participant, "Example")?;
+    /// let writer = Writer::new(&topic)?;
+    ///
+    /// assert_eq!(writer.topic_name()?, "Example");
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn topic_name(&self) -> Result<String> {
+        ffi::dds_get_topic(self.inner).and_then(ffi::dds_get_name)
+    }
+
+    /// Returns the name of the type of the [`Topic`](crate::Topic) this
+    /// writer is associated with.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the type name could not be
+    /// retrieved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cyclonedds::{Domain, Participant, Topic, Writer};
+    /// # let domain = Domain::default();
+    /// # let participant = Participant::new(&domain)?;
+    /// # #[derive(
+    /// #     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+    /// # )]
+    /// # struct Data {
+    /// #     x: i32,
+    /// # }
+    /// let topic = Topic::<Data>::new(&participant, "Example")?;
+    /// let writer = Writer::new(&topic)?;
+    ///
+    /// println!("type name: {}", writer.type_name()?);
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn type_name(&self) -> Result<String> {
+        ffi::dds_get_topic(self.inner).and_then(ffi::dds_get_type_name)
+    }
+
+    /// Returns the [`PublicationMatched`](crate::status::PublicationMatched)
+    /// status, which counts the readers this writer has matched with.
+    ///
+    /// Retrieving the status resets the `delta` of its counters and clears the
+    /// corresponding [`Status`](crate::Status) flag.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the status could not be
+    /// retrieved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cyclonedds::{Domain, Participant, Topic, Reader, Writer};
+    /// # let domain = Domain::default();
+    /// # let participant = Participant::new(&domain)?;
+    /// # #[derive(
+    /// #     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+    /// # )]
+    /// # struct Data {
+    /// #     x: i32,
+    /// # }
+    /// use cyclonedds::entity::Entity;
+    ///
+    /// let topic = Topic::<Data>::new(&participant, "Example")?;
+    /// let writer = Writer::new(&topic)?;
+    /// let reader = Reader::new(&topic)?;
+    ///
+    /// let status = writer.publication_matched_status()?;
+    /// assert_eq!(status.current.count, 1);
+    /// assert_eq!(status.last_subscription_handle, reader.instance_handle()?);
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn publication_matched_status(&self) -> Result<crate::status::PublicationMatched> {
+        ffi::dds_get_publication_matched_status(self.inner)
+    }
+
+    /// Returns the [`LivelinessLost`](crate::status::LivelinessLost) status,
+    /// which counts how often this writer failed to assert its liveliness.
+    ///
+    /// Retrieving the status resets the `delta` of its counters and clears the
+    /// corresponding [`Status`](crate::Status) flag.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the status could not be
+    /// retrieved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cyclonedds::{Domain, Participant, Topic, Writer};
+    /// # let domain = Domain::default();
+    /// # let participant = Participant::new(&domain)?;
+    /// # #[derive(
+    /// #     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+    /// # )]
+    /// # struct Data {
+    /// #     x: i32,
+    /// # }
+    /// let topic = Topic::<Data>::new(&participant, "Example")?;
+    /// let writer = Writer::new(&topic)?;
+    ///
+    /// assert_eq!(writer.liveliness_lost_status()?.total.count, 0);
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn liveliness_lost_status(&self) -> Result<crate::status::LivelinessLost> {
+        ffi::dds_get_liveliness_lost_status(self.inner)
+    }
+
+    /// Returns the [`OfferedDeadlineMissed`](crate::status::OfferedDeadlineMissed)
+    /// status, which counts the instances this writer failed to update within
+    /// its [`Deadline`](crate::qos::policy::Deadline).
+    ///
+    /// Retrieving the status resets the `delta` of its counters and clears the
+    /// corresponding [`Status`](crate::Status) flag.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the status could not be
+    /// retrieved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cyclonedds::{Domain, Participant, Topic, Writer};
+    /// # let domain = Domain::default();
+    /// # let participant = Participant::new(&domain)?;
+    /// # #[derive(
+    /// #     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+    /// # )]
+    /// # struct Data {
+    /// #     x: i32,
+    /// # }
+    /// let topic = Topic::<Data>::new(&participant, "Example")?;
+    /// let writer = Writer::new(&topic)?;
+    ///
+    /// assert_eq!(writer.offered_deadline_missed_status()?.total.count, 0);
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn offered_deadline_missed_status(&self) -> Result<crate::status::OfferedDeadlineMissed> {
+        ffi::dds_get_offered_deadline_missed_status(self.inner)
+    }
+
+    /// Returns the [`OfferedIncompatibleQoS`](crate::status::OfferedIncompatibleQoS)
+    /// status, which counts the readers that requested a [`QoS`](crate::QoS)
+    /// this writer does not offer.
+    ///
+    /// Retrieving the status resets the `delta` of its counters and clears the
+    /// corresponding [`Status`](crate::Status) flag.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](crate::Error) if the status could not be
+    /// retrieved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cyclonedds::{Domain, Participant, Topic, Writer};
+    /// # let domain = Domain::default();
+    /// # let participant = Participant::new(&domain)?;
+    /// # #[derive(
+    /// #     cyclonedds::Topicable, serde::Serialize, serde::Deserialize, Clone, Debug, Default,
+    /// # )]
+    /// # struct Data {
+    /// #     x: i32,
+    /// # }
+    /// let topic = Topic::<Data>::new(&participant, "Example")?;
+    /// let writer = Writer::new(&topic)?;
+    ///
+    /// assert_eq!(writer.offered_incompatible_qos_status()?.total.count, 0);
+    /// # Ok::<_, cyclonedds::Error>(())
+    /// ```
+    pub fn offered_incompatible_qos_status(&self) -> Result<crate::status::OfferedIncompatibleQoS> {
+        ffi::dds_get_offered_incompatible_qos_status(self.inner)
+    }
+
     /// Registers an instance identified by `key` with this writer.
     ///
     /// Registration is optional but allows for the pre-allocation of resources
@@ -502,6 +788,7 @@ where
     /// let handle = writer.register_instance(&Key::<Data> { x: 1, y: 2 })?;
     /// # Ok::<_, cyclonedds::Error>(())
     /// ```
+    #[track_caller]
     pub fn register_instance(&self, key: &T::Key) -> Result<crate::entity::InstanceHandle> {
         ffi::dds_register_instance::<T>(self.inner, key)
             .map(|inner| crate::entity::InstanceHandle { inner })
@@ -538,6 +825,7 @@ where
     /// writer.unregister_instance(&Key::<Data> { x: 1, y: 2 })?;
     /// # Ok::<_, cyclonedds::Error>(())
     /// ```
+    #[track_caller]
     pub fn unregister_instance(&self, key: &T::Key) -> Result<()> {
         ffi::dds_unregister_instance::<T>(self.inner, key)
     }
@@ -607,6 +895,7 @@ where
     /// writer.unregister_instance_with_timestamp(&Key::<Data> { x: 1, y: 2 }, Time::from_secs(1))?;
     /// # Ok::<_, cyclonedds::Error>(())
     /// ```
+    #[track_caller]
     pub fn unregister_instance_with_timestamp(
         &self,
         key: &T::Key,
@@ -723,6 +1012,7 @@ where
     /// writer.write_dispose(&Data { x: 1, y: 2 })?;
     /// # Ok::<_, cyclonedds::Error>(())
     /// ```
+    #[track_caller]
     pub fn write_dispose(&self, data: &T) -> Result<()> {
         ffi::dds_write_dispose(self.inner, data)
     }
@@ -757,6 +1047,7 @@ where
     /// writer.write_dispose_with_timestamp(&Data::default(), Time::from_secs(1))?;
     /// # Ok::<_, cyclonedds::Error>(())
     /// ```
+    #[track_caller]
     pub fn write_dispose_with_timestamp(&self, data: &T, timestamp: crate::Time) -> Result<()> {
         ffi::dds_write_dispose_with_timestamp(self.inner, data, timestamp.inner)
     }
@@ -792,6 +1083,7 @@ where
     /// writer.dispose(&Key::<Data> { x: 1, y: 2 })?;
     /// # Ok::<_, cyclonedds::Error>(())
     /// ```
+    #[track_caller]
     pub fn dispose(&self, key: &T::Key) -> Result<()> {
         ffi::dds_dispose::<T>(self.inner, key)
     }
@@ -825,6 +1117,7 @@ where
     /// writer.dispose_with_timestamp(&Key::<Data> { x: 1, y: 2 }, Time::from_secs(1))?;
     /// # Ok::<_, cyclonedds::Error>(())
     /// ```
+    #[track_caller]
     pub fn dispose_with_timestamp(&self, key: &T::Key, timestamp: crate::Time) -> Result<()> {
         ffi::dds_dispose_with_timestamp::<T>(self.inner, key, timestamp.inner)
     }
@@ -1111,6 +1404,60 @@ mod tests {
         writer.write(&crate::tests::topic::Data::default()).unwrap();
     }
 
+    #[test]
+    fn test_writer_write_unserializable() {
+        use crate::entity::Entity;
+
+        #[derive(Debug, Clone, serde::Deserialize)]
+        struct Unserializable;
+
+        impl serde::Serialize for Unserializable {
+            fn serialize<S: serde::Serializer>(&self, _: S) -> Result<S::Ok, S::Error> {
+                Err(serde::ser::Error::custom("unserializable"))
+            }
+        }
+
+        impl Topicable for Unserializable {
+            type Key = ();
+
+            fn from_key((): &Self::Key) -> Self {
+                Self
+            }
+
+            fn as_key(&self) -> Self::Key {}
+        }
+
+        let domain_id = crate::tests::domain::unique_id();
+        let domain = crate::Domain::new(domain_id).unwrap();
+        let topic_name = crate::tests::topic::unique_name();
+        let participant = crate::Participant::new(&domain).unwrap();
+        let topic = Topic::<Unserializable>::new(&participant, &topic_name).unwrap();
+        let reader = crate::Reader::new(&topic).unwrap();
+        let mut writer = Writer::new(&topic).unwrap();
+
+        // The write fails even though the only reader is in the same process
+        // and would never need the encoding.
+        let line = line!() + 1;
+        let result = writer.write(&Unserializable).unwrap_err();
+        assert_eq!(result, crate::Error::Serialization);
+        assert!(reader.take().unwrap().is_empty());
+        let context = crate::ErrorContext::last().unwrap();
+        assert_eq!(context.operation(), "write");
+        assert_eq!(context.entity(), writer.id());
+        assert_eq!(context.topic_name(), Some(topic_name.as_str()));
+        assert_eq!(context.location().file(), file!());
+        assert_eq!(context.location().line(), line);
+        let source = std::error::Error::source(&context).unwrap();
+        assert!(source.to_string().contains("unserializable"));
+
+        // A failed serialization does not leak into a later, unrelated failure.
+        let writer_id = writer.inner;
+        writer.inner = 0;
+        let result = writer.write(&Unserializable).unwrap_err();
+        assert_eq!(result, crate::Error::BadParameter);
+        writer.inner = writer_id;
+    }
+
     #[test]
     fn test_writer_write_with_timestamp() {
         let domain_id = crate::tests::domain::unique_id();
@@ -1374,6 +1721,25 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn test_writer_topic_and_type_name() {
+        let domain_id = crate::tests::domain::unique_id();
+        let domain = crate::Domain::new(domain_id).unwrap();
+        let topic_name = crate::tests::topic::unique_name();
+        let participant = crate::Participant::new(&domain).unwrap();
+        let topic = Topic::<crate::tests::topic::Data>::new(&participant, &topic_name).unwrap();
+
+        let mut writer = Writer::new(&topic).unwrap();
+        assert_eq!(writer.topic_name().unwrap(), topic_name);
+        assert!(!writer.type_name().unwrap().is_empty());
+
+        let writer_id = writer.inner;
+        writer.inner = 0;
+        assert_eq!(writer.topic_name().unwrap_err(), crate::Error::BadParameter);
+        assert_eq!(writer.type_name().unwrap_err(), crate::Error::BadParameter);
+        writer.inner = writer_id;
+    }
+
     #[test]
     fn test_writer_matched_subscriptions() {
         use crate::entity::Entity;
@@ -1392,6 +1758,41 @@ mod tests {
         assert_eq!(matched, vec![reader.instance_handle().unwrap()]);
     }
 
+    #[test]
+    fn test_writer_matched_subscription_data() {
+        use crate::entity::Entity;
+
+        let domain_id = crate::tests::domain::unique_id();
+        let domain = crate::Domain::new(domain_id).unwrap();
+        let topic_name = crate::tests::topic::unique_name();
+        let participant = crate::Participant::new(&domain).unwrap();
+        let topic = Topic::<crate::tests::topic::Data>::new(&participant, &topic_name).unwrap();
+        let qos = crate::QoS::new().with_user_data(crate::qos::policy::UserData {
+            value: b"reader".to_vec(),
+        });
+
+        let writer = Writer::new(&topic).unwrap();
+        let reader = crate::Reader::builder(&topic)
+            .with_qos(&qos)
+            .build()
+            .unwrap();
+
+        let handle = reader.instance_handle().unwrap();
+        let subscription = writer.matched_subscription_data(handle).unwrap();
+        assert_eq!(subscription.topic_name, topic_name);
+        assert!(!subscription.type_name.is_empty());
+        assert_eq!(subscription.user_data(), Some(b"reader".as_slice()));
+        assert_eq!(
+            subscription.participant_instance_handle,
+            participant.instance_handle().unwrap()
+        );
+
+        let result = writer
+            .matched_subscription_data(writer.instance_handle().unwrap())
+            .unwrap_err();
+        assert_eq!(result, crate::Error::BadParameter);
+    }
+
     #[test]
     fn test_writer_lookup_instance() {
         let domain_id = crate::tests::domain::unique_id();
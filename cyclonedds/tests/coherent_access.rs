@@ -0,0 +1,211 @@
+//! Integration tests for coherent sets and group access.
+
+// NOTE: active lint levels are defined in the top-level workspace `Cargo.toml`.
+// These `allow`s for tests exists for lints that significantly reduce test
+// readability or ergonomics.
+#![cfg_attr(test, allow(clippy::indexing_slicing))]
+
+use cyclonedds as dds;
+
+mod common;
+
+/// Polls `condition` until it returns `true` or a generous timeout elapses.
+fn wait_until(mut condition: impl FnMut() -> dds::Result<bool>) -> dds::Result<bool> {
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+    while std::time::Instant::now() < deadline {
+        if condition()? {
+            return Ok(true);
+        }
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    }
+    Ok(false)
+}
+
+/// Returns the configuration for a domain that is externally matched with
+/// `external_domain_id` over the loopback interface.
+fn config(external_domain_id: u32) -> String {
+    format!(
+        "<Domain>
+          <General>
+            <Interfaces>
+              <NetworkInterface address='127.0.0.1' />
+            </Interfaces>
+          </General>
+          <Discovery>
+            <ExternalDomainId>
+              {external_domain_id}
+            </ExternalDomainId>
+          </Discovery>
+        </Domain>"
+    )
+}
+
+/// Verify that samples written as part of a coherent set spanning two topics
+/// are not visible to readers until the set ends, at which point all of them
+/// become visible together.
+#[test]
+fn coherent_set_across_topics() -> dds::Result<()> {
+    let domain_id_01 = common::domain::unique_id();
+    let domain_id_02 = common::domain::unique_id();
+    let config = config(domain_id_02);
+    let topic_name_01 = common::topic::unique_name();
+    let topic_name_02 = common::topic::unique_name();
+
+    let qos = dds::QoS::new()
+        .with_presentation(dds::qos::policy::Presentation::Group {
+            coherent_access: true,
+            ordered_access: false,
+        })
+        .with_reliability(dds::qos::policy::Reliability::Reliable {
+            max_blocking_time: dds::Duration::from_millis(100),
+        })
+        .with_history(dds::qos::policy::History::KeepAll);
+
+    let domain = dds::Domain::new_with_xml_config(domain_id_01, &config)?;
+    let participant = dds::Participant::new(&domain)?;
+    let subscriber = dds::Subscriber::builder(&participant)
+        .with_qos(&qos)
+        .build()?;
+    let topic_01 = dds::Topic::<common::topic::Data>::new(&participant, &topic_name_01)?;
+    let topic_02 = dds::Topic::<common::topic::Data>::new(&participant, &topic_name_02)?;
+    let reader_01 = dds::Reader::builder(&topic_01)
+        .with_qos(&qos)
+        .with_subscriber(&subscriber)
+        .build()?;
+    let reader_02 = dds::Reader::builder(&topic_02)
+        .with_qos(&qos)
+        .with_subscriber(&subscriber)
+        .build()?;
+
+    let domain = dds::Domain::new_with_xml_config(domain_id_02, &config)?;
+    let participant = dds::Participant::new(&domain)?;
+    let publisher = dds::Publisher::builder(&participant)
+        .with_qos(&qos)
+        .build()?;
+    let topic_01 = dds::Topic::<common::topic::Data>::new(&participant, &topic_name_01)?;
+    let topic_02 = dds::Topic::<common::topic::Data>::new(&participant, &topic_name_02)?;
+    let writer_01 = dds::Writer::builder(&topic_01)
+        .with_qos(&qos)
+        .with_publisher(&publisher)
+        .build()?;
+    let writer_02 = dds::Writer::builder(&topic_02)
+        .with_qos(&qos)
+        .with_publisher(&publisher)
+        .build()?;
+
+    assert!(wait_until(|| {
+        Ok(!writer_01.matched_subscriptions()?.is_empty()
+            && !writer_02.matched_subscriptions()?.is_empty())
+    })?);
+
+    let sample_01 = common::topic::Data {
+        x: 1,
+        y: 2,
+        message: format!("message on {topic_name_01}"),
+    };
+    let sample_02 = common::topic::Data {
+        x: 3,
+        y: 4,
+        message: format!("message on {topic_name_02}"),
+    };
+
+    let coherent_set = publisher.begin_coherent()?;
+    writer_01.write(&sample_01)?;
+    writer_02.write(&sample_02)?;
+
+    // The set is still open so neither sample may be visible.
+    std::thread::sleep(std::time::Duration::from_millis(200));
+    assert!(reader_01.peek()?.is_empty());
+    assert!(reader_02.peek()?.is_empty());
+
+    coherent_set.end()?;
+
+    assert!(wait_until(|| {
+        let access = subscriber.begin_access()?;
+        let complete = !reader_01.peek()?.is_empty() && !reader_02.peek()?.is_empty();
+        access.end()?;
+        Ok(complete)
+    })?);
+
+    let access = subscriber.begin_access()?;
+    let samples_01 = reader_01.take()?;
+    let samples_02 = reader_02.take()?;
+    access.end()?;
+
+    assert_eq!(samples_01.len(), 1);
+    assert_eq!(*samples_01[0], sample_01);
+    assert_eq!(samples_02.len(), 1);
+    assert_eq!(*samples_02[0], sample_02);
+
+    Ok(())
+}
+
+/// Verify that a coherent set on a single topic ended by dropping its guard
+/// delivers all of its samples at once.
+#[test]
+fn coherent_set_ended_on_drop() -> dds::Result<()> {
+    let domain_id_01 = common::domain::unique_id();
+    let domain_id_02 = common::domain::unique_id();
+    let config = config(domain_id_02);
+    let topic_name = common::topic::unique_name();
+
+    let qos = dds::QoS::new()
+        .with_presentation(dds::qos::policy::Presentation::Topic {
+            coherent_access: true,
+            ordered_access: false,
+        })
+        .with_reliability(dds::qos::policy::Reliability::Reliable {
+            max_blocking_time: dds::Duration::from_millis(100),
+        })
+        .with_history(dds::qos::policy::History::KeepAll);
+
+    let domain = dds::Domain::new_with_xml_config(domain_id_01, &config)?;
+    let participant = dds::Participant::new(&domain)?;
+    let subscriber = dds::Subscriber::builder(&participant)
+        .with_qos(&qos)
+        .build()?;
+    let topic = dds::Topic::<common::topic::Data>::new(&participant, &topic_name)?;
+    let reader = dds::Reader::builder(&topic)
+        .with_qos(&qos)
+        .with_subscriber(&subscriber)
+        .build()?;
+
+    let domain = dds::Domain::new_with_xml_config(domain_id_02, &config)?;
+    let participant = dds::Participant::new(&domain)?;
+    let publisher = dds::Publisher::builder(&participant)
+        .with_qos(&qos)
+        .build()?;
+    let topic = dds::Topic::<common::topic::Data>::new(&participant, &topic_name)?;
+    let writer = dds::Writer::builder(&topic)
+        .with_qos(&qos)
+        .with_publisher(&publisher)
+        .build()?;
+
+    assert!(wait_until(|| {
+        let matched = writer.matched_subscriptions()?;
+        Ok(!matched.is_empty())
+    })?);
+
+    {
+        let _coherent_set = publisher.begin_coherent()?;
+        for x in 0..10 {
+            writer.write(&common::topic::Data {
+                x,
+                ..Default::default()
+            })?;
+        }
+
+        std::thread::sleep(std::time::Duration::from_millis(200));
+        assert!(reader.peek()?.is_empty());
+    }
+
+    assert!(wait_until(|| Ok(!reader.peek()?.is_empty()))?);
+
+    let samples = reader.take()?;
+    assert_eq!(samples.len(), 10);
+    for (x, sample) in (0..10).zip(samples.iter()) {
+        assert_eq!(sample.x, x);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,131 @@
+//! Integration tests for runtime-typed readers and writers.
+
+// NOTE: active lint levels are defined in the top-level workspace `Cargo.toml`.
+// These `allow`s for tests exists for lints that significantly reduce test
+// readability or ergonomics.
+#![cfg_attr(test, allow(clippy::indexing_slicing))]
+
+use cyclonedds as dds;
+use cyclonedds::Topicable;
+use cyclonedds::dynamic::{
+    DynamicData, DynamicReader, DynamicStruct, DynamicTopic, DynamicType, DynamicWriter, StructType,
+};
+
+mod common;
+
+/// The runtime description of [`common::topic::Data`].
+fn data_type() -> StructType {
+    StructType::new(common::topic::Data::dds_type_name().as_ref())
+        .with_key_member("x", DynamicType::UInt32)
+        .with_key_member("y", DynamicType::Int32)
+        .with_member("message", DynamicType::String { bound: None })
+}
+
+fn data_sample(data: &common::topic::Data) -> DynamicData {
+    DynamicData::from(
+        DynamicStruct::new()
+            .with_field("x", data.x)
+            .with_field("y", data.y)
+            .with_field("message", data.message.as_str()),
+    )
+}
+
+/// Verify that samples written by a dynamic writer are received unchanged by a
+/// dynamic reader, including the key-only sample produced by a dispose.
+#[test]
+fn dynamic_write_dynamic_read() -> dds::Result<()> {
+    let domain_id = common::domain::unique_id();
+    let topic_name = common::topic::unique_name();
+
+    let qos = dds::QoS::new().with_history(dds::qos::policy::History::KeepAll);
+    let domain = dds::Domain::new(domain_id)?;
+    let participant = dds::Participant::new(&domain)?;
+    let topic = DynamicTopic::builder(&participant, &topic_name, data_type())
+        .with_qos(&qos)
+        .build()?;
+    let reader = DynamicReader::new(&topic)?;
+    let writer = DynamicWriter::new(&topic)?;
+
+    let sample = data_sample(&common::topic::Data {
+        x: 1,
+        y: -2,
+        message: "hello".into(),
+    });
+    writer.write(&sample)?;
+    writer.dispose(&sample)?;
+
+    let samples = reader.take()?;
+    assert_eq!(samples.len(), 2);
+    assert_eq!(samples[0].sample(), Some(&sample));
+    assert_eq!(
+        samples[1].key(),
+        Some(&DynamicData::from(
+            DynamicStruct::new()
+                .with_field("x", 1u32)
+                .with_field("y", -2i32)
+        ))
+    );
+
+    // A sample that doesn't match the type is rejected.
+    let mismatched = DynamicData::from(DynamicStruct::new().with_field("x", 1u32));
+    assert_eq!(writer.write(&mismatched), Err(dds::Error::BadParameter));
+
+    Ok(())
+}
+
+/// Verify that dynamic and typed endpoints of the same type interoperate in
+/// both directions.
+#[test]
+fn dynamic_typed_interop() -> dds::Result<()> {
+    let domain_id = common::domain::unique_id();
+    let topic_name = common::topic::unique_name();
+
+    let domain = dds::Domain::new(domain_id)?;
+
+    let typed_participant = dds::Participant::new(&domain)?;
+    let typed_topic = dds::Topic::<common::topic::Data>::new(&typed_participant, &topic_name)?;
+    let typed_reader = dds::Reader::new(&typed_topic)?;
+    let typed_writer = dds::Writer::new(&typed_topic)?;
+
+    let dynamic_participant = dds::Participant::new(&domain)?;
+    let dynamic_topic = DynamicTopic::new(&dynamic_participant, &topic_name, data_type())?;
+    let dynamic_reader = DynamicReader::new(&dynamic_topic)?;
+    let dynamic_writer = DynamicWriter::new(&dynamic_topic)?;
+
+    assert_eq!(dynamic_writer.type_name()?, typed_writer.type_name()?);
+
+    let from_typed = common::topic::Data {
+        x: 1,
+        y: 2,
+        message: "from typed".into(),
+    };
+    typed_writer.write(&from_typed)?;
+
+    let from_dynamic = common::topic::Data {
+        x: 3,
+        y: 4,
+        message: "from dynamic".into(),
+    };
+    dynamic_writer.write(&data_sample(&from_dynamic))?;
+
+    let mut typed_samples: Vec<_> = typed_reader
+        .take()?
+        .into_iter()
+        .filter_map(dds::sample::SampleOrKey::into_sample)
+        .collect();
+    typed_samples.sort_by_key(|sample| sample.x);
+    assert_eq!(typed_samples, [from_typed.clone(), from_dynamic.clone()]);
+
+    let mut dynamic_samples: Vec<_> = dynamic_reader
+        .take()?
+        .into_iter()
+        .filter_map(dds::dynamic::DynamicSample::into_sample)
+        .collect();
+    dynamic_samples.sort_by_key(|sample| sample.field("x").and_then(DynamicData::as_u64));
+    assert_eq!(
+        dynamic_samples,
+        [data_sample(&from_typed), data_sample(&from_dynamic)]
+    );
+
+    Ok(())
+}
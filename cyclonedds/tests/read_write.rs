@@ -164,6 +164,103 @@ fn read_write_same_type_shape_different_sertype() -> dds::Result<()> {
     Ok(())
 }
 
+/// Verify that samples which cannot be decoded into the reader's type are
+/// counted per type and handed to the readers' callbacks rather than silently
+/// dropped, and that each reader keeps its own delta and callback.
+#[test]
+fn read_write_undeserializable_sample() -> dds::Result<()> {
+    #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Default)]
+    struct Narrow {
+        x: u32,
+    }
+
+    impl dds::Topicable for Narrow {
+        type Key = ();
+
+        fn from_key((): &Self::Key) -> Self {
+            Self::default()
+        }
+
+        fn as_key(&self) -> Self::Key {}
+
+        fn dds_type_name() -> impl AsRef<str> {
+            "Data"
+        }
+    }
+
+    #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Default)]
+    struct Wide {
+        x: u32,
+        y: u64,
+        z: u64,
+    }
+
+    impl dds::Topicable for Wide {
+        type Key = ();
+
+        fn from_key((): &Self::Key) -> Self {
+            Self::default()
+        }
+
+        fn as_key(&self) -> Self::Key {}
+
+        fn dds_type_name() -> impl AsRef<str> {
+            "Data"
+        }
+    }
+
+    let domain_id = common::domain::unique_id();
+    let topic_name = common::topic::unique_name();
+    let domain = dds::Domain::new(domain_id)?;
+    let participant = dds::Participant::new(&domain)?;
+    let topic01 = dds::Topic::<Narrow>::new(&participant, &topic_name)?;
+    let topic02 = dds::Topic::<Wide>::new(&participant, &topic_name)?;
+    let other_topic = dds::Topic::<Wide>::new(&participant, &common::topic::unique_name())?;
+
+    let writer = dds::Writer::new(&topic01)?;
+    let reader = dds::Reader::new(&topic02)?;
+    let other_reader = dds::Reader::new(&topic02)?;
+    let other_topic_reader = dds::Reader::new(&other_topic)?;
+
+    let callback_bytes = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    reader.set_type_undeserializable_callback(Box::new({
+        let callback_bytes = std::sync::Arc::clone(&callback_bytes);
+        move |serialized, _| {
+            callback_bytes
+                .lock()
+                .expect("callback bytes lock poisoned")
+                .extend_from_slice(serialized);
+        }
+    }))?;
+
+    writer.write(&Narrow { x: 1234 })?;
+
+    assert!(reader.take()?.is_empty());
+    let status = reader.type_undeserializable_status()?;
+    assert_eq!(status.total.count, 1);
+    assert_eq!(status.total.delta, 1);
+    let status = reader.type_undeserializable_status()?;
+    assert_eq!(status.total.count, 1);
+    assert_eq!(status.total.delta, 0);
+
+    assert!(other_reader.take()?.is_empty());
+    let status = other_reader.type_undeserializable_status()?;
+    assert_eq!(status.total.count, 1);
+    assert_eq!(status.total.delta, 1);
+
+    // The count is kept per type, so a reader of another topic of the same
+    // type counts the sample too.
+    assert!(other_topic_reader.take()?.is_empty());
+    let status = other_topic_reader.type_undeserializable_status()?;
+    assert_eq!(status.total.count, 1);
+    assert_eq!(status.total.delta, 1);
+
+    let serialized = callback_bytes.lock().expect("callback bytes lock poisoned");
+    assert_eq!(serialized[4..8], 1234_u32.to_ne_bytes());
+
+    Ok(())
+}
+
 /// Verify single-sample and multi-sample read/write scenarios across two
 /// externally matched domains.
 #[test]
@@ -396,3 +493,145 @@ fn read_write_generate_keyhash_unbounded_keyed_data() -> dds::Result<()> {
 
     Ok(())
 }
+
+/// Verify that serialized samples taken from one topic can be forwarded to
+/// another topic of the same type without being decoded by the application.
+#[test]
+fn read_write_serialized_forwarding() -> dds::Result<()> {
+    let domain_id = common::domain::unique_id();
+    let source_topic_name = common::topic::unique_name();
+    let target_topic_name = common::topic::unique_name();
+
+    let qos = dds::QoS::new().with_history(dds::qos::policy::History::KeepAll);
+    let domain = dds::Domain::new(domain_id)?;
+    let participant = dds::Participant::builder(&domain).with_qos(&qos).build()?;
+
+    let source_topic = dds::Topic::<common::topic::Data>::new(&participant, &source_topic_name)?;
+    let source_reader = dds::Reader::new(&source_topic)?;
+    let source_writer = dds::Writer::new(&source_topic)?;
+
+    let target_topic = dds::Topic::<common::topic::Data>::new(&participant, &target_topic_name)?;
+    let target_reader = dds::Reader::new(&target_topic)?;
+    let target_writer = dds::Writer::new(&target_topic)?;
+
+    let sample_01 = common::topic::Data {
+        x: 1,
+        y: -1,
+        message: "first".to_string(),
+    };
+    let sample_02 = common::topic::Data {
+        x: 2,
+        y: -2,
+        message: "🐋鯨❤️".to_string(),
+    };
+    source_writer.write(&sample_01)?;
+    source_writer.write(&sample_02)?;
+
+    let serialized = source_reader.take_serialized()?;
+    assert_eq!(serialized.len(), 2);
+    assert!(source_reader.take_serialized()?.is_empty());
+
+    for sample in &serialized {
+        assert!(sample.valid_data);
+        target_writer.write_serialized(&sample.serialized)?;
+    }
+
+    let samples = target_reader.read()?;
+    assert_eq!(samples.len(), 2);
+    assert_eq!(*samples[0], sample_01);
+    assert_eq!(*samples[1], sample_02);
+
+    // The payloads are forwarded unchanged.
+    let forwarded = target_reader.take_serialized()?;
+    assert_eq!(forwarded.len(), 2);
+    assert_eq!(forwarded[0].serialized, serialized[0].serialized);
+    assert_eq!(forwarded[1].serialized, serialized[1].serialized);
+
+    // Disposing of an instance yields a key-only sample.
+    source_writer.dispose(&(sample_01.x, sample_01.y))?;
+    let disposed = source_reader.take_serialized()?;
+    assert_eq!(disposed.len(), 1);
+    assert!(!disposed[0].valid_data);
+
+    // Payloads that can't be decoded as the topic type are rejected.
+    assert_eq!(
+        target_writer.write_serialized(&[0x00, 0x01, 0x00, 0x00, 0x01]),
+        Err(dds::Error::BadParameter)
+    );
+    assert_eq!(
+        target_writer.write_serialized(&[]),
+        Err(dds::Error::BadParameter)
+    );
+    assert!(target_reader.take()?.is_empty());
+
+    Ok(())
+}
+
+/// Verify that a sample that cannot be encoded again is skipped when taking
+/// serialized samples, without losing the samples around it.
+#[test]
+fn read_write_serialized_skips_unencodable_samples() -> dds::Result<()> {
+    use dds::Topicable;
+
+    /// A reading that decodes from any value but only encodes if it is not
+    /// negative.
+    #[derive(Topicable, serde::Deserialize, Clone, Debug, Default, PartialEq)]
+    struct Reading {
+        #[dds(key)]
+        id: u32,
+        value: i32,
+    }
+
+    impl serde::Serialize for Reading {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            use serde::ser::SerializeStruct;
+
+            if self.value < 0 {
+                return Err(serde::ser::Error::custom("negative reading"));
+            }
+            let mut reading = serializer.serialize_struct("Reading", 2)?;
+            reading.serialize_field("id", &self.id)?;
+            reading.serialize_field("value", &self.value)?;
+            reading.end()
+        }
+    }
+
+    let domain_id = common::domain::unique_id();
+    let topic_name = common::topic::unique_name();
+    let domain = dds::Domain::new(domain_id)?;
+    let participant = dds::Participant::new(&domain)?;
+    let topic = dds::Topic::<Reading>::new(&participant, &topic_name)?;
+    let reader = dds::Reader::new(&topic)?;
+    let writer = dds::Writer::new(&topic)?;
+
+    writer.write(&Reading { id: 1, value: 1 })?;
+    // A negative reading can't be written, but it can be decoded.
+    assert_eq!(
+        writer.write(&Reading { id: 2, value: -2 }),
+        Err(dds::Error::Serialization)
+    );
+    writer.write_serialized(&[
+        0x00, 0x01, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0xfe, 0xff, 0xff, 0xff,
+    ])?;
+    writer.write(&Reading { id: 3, value: 3 })?;
+
+    let samples = reader.take_serialized()?;
+    assert_eq!(samples.len(), 2);
+    assert_eq!(
+        samples[0].serialized,
+        [
+            0x00, 0x01, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00
+        ]
+    );
+    assert_eq!(
+        samples[1].serialized,
+        [
+            0x00, 0x01, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00
+        ]
+    );
+
+    // The skipped sample was taken along with the others.
+    assert!(reader.take()?.is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,206 @@
+//! Compile-time `Send`/`Sync` assertions and multi-threaded usage tests.
+
+// NOTE: active lint levels are defined in the top-level workspace `Cargo.toml`.
+// These `allow`s for tests exists for lints that significantly reduce test
+// readability or ergonomics.
+#![cfg_attr(test, allow(clippy::indexing_slicing))]
+
+use cyclonedds as dds;
+
+mod common;
+
+/// Fails to compile unless `T` is both [`Send`] and [`Sync`].
+const fn assert_send_sync<T: Send + Sync + ?Sized>() {}
+
+const _: () = {
+    type Data = common::topic::Data;
+
+    assert_send_sync::<dds::Domain>();
+    assert_send_sync::<dds::Participant<'static>>();
+    assert_send_sync::<dds::Publisher<'static, 'static>>();
+    assert_send_sync::<dds::Subscriber<'static, 'static>>();
+    assert_send_sync::<dds::Topic<'static, 'static, Data>>();
+    assert_send_sync::<dds::Writer<'static, 'static, 'static, Data>>();
+    assert_send_sync::<dds::Reader<'static, 'static, 'static, Data>>();
+    assert_send_sync::<dds::ReadCondition<'static, 'static, 'static, 'static, Data>>();
+    assert_send_sync::<dds::GuardCondition<'static>>();
+    assert_send_sync::<dds::StatusCondition<'static>>();
+    assert_send_sync::<dds::WaitSet<'static, 'static, 'static, ()>>();
+    assert_send_sync::<dds::CoherentSet<'static, 'static, 'static>>();
+    assert_send_sync::<dds::GroupAccess<'static, 'static, 'static>>();
+    assert_send_sync::<dds::QoS>();
+    assert_send_sync::<dds::Listener>();
+    assert_send_sync::<dds::ReaderListener<Data>>();
+    assert_send_sync::<dds::WriterListener<Data>>();
+    assert_send_sync::<dds::sample::SampleOrKey<Data>>();
+    assert_send_sync::<
+        dds::builtin::BuiltinReader<'static, 'static, dds::builtin::ParticipantBuiltinTopicData>,
+    >();
+    assert_send_sync::<dds::owned::OwnedParticipant>();
+    assert_send_sync::<dds::owned::OwnedTopic<Data>>();
+    assert_send_sync::<dds::owned::OwnedWriter<Data>>();
+    assert_send_sync::<dds::owned::OwnedReader<Data>>();
+    assert_send_sync::<dds::dynamic::DynamicTopic<'static, 'static>>();
+    assert_send_sync::<dds::dynamic::DynamicWriter<'static, 'static, 'static>>();
+    assert_send_sync::<dds::dynamic::DynamicReader<'static, 'static, 'static>>();
+    assert_send_sync::<dds::dynamic::DynamicSample>();
+};
+
+/// Number of threads used by the stress tests.
+const THREADS: u32 = 8;
+/// Number of samples written by each thread in the stress tests.
+const SAMPLES_PER_THREAD: u32 = 250;
+
+/// Verify that a single writer shared between many threads delivers every
+/// sample written concurrently.
+#[test]
+fn concurrent_writes_from_many_threads() -> dds::Result<()> {
+    let domain_id = common::domain::unique_id();
+    let topic_name = common::topic::unique_name();
+    let qos = dds::QoS::new()
+        .with_reliability(dds::qos::policy::Reliability::Reliable {
+            max_blocking_time: dds::Duration::from_secs(1),
+        })
+        .with_history(dds::qos::policy::History::KeepAll);
+
+    let domain = dds::Domain::new(domain_id)?;
+    let participant = dds::Participant::new(&domain)?;
+    let topic = dds::Topic::<common::topic::Data>::builder(&participant, &topic_name)
+        .with_qos(&qos)
+        .build()?;
+    let reader = dds::Reader::builder(&topic).with_qos(&qos).build()?;
+    let writer = dds::Writer::builder(&topic).with_qos(&qos).build()?;
+
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..THREADS)
+            .map(|thread| {
+                let writer = &writer;
+                scope.spawn(move || {
+                    for x in 0..SAMPLES_PER_THREAD {
+                        writer.write(&common::topic::Data {
+                            x,
+                            y: thread.cast_signed(),
+                            message: format!("sample {x} from thread {thread}"),
+                        })?;
+                    }
+                    Ok::<_, dds::Error>(())
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .try_for_each(|handle| handle.join().expect("writer thread panicked"))
+    })?;
+
+    let samples = reader.take()?;
+    assert_eq!(samples.len(), (THREADS * SAMPLES_PER_THREAD) as usize);
+
+    let mut received: Vec<_> = samples.iter().map(|sample| (sample.y, sample.x)).collect();
+    received.sort_unstable();
+    let expected: Vec<_> = (0..THREADS)
+        .flat_map(|thread| (0..SAMPLES_PER_THREAD).map(move |x| (thread.cast_signed(), x)))
+        .collect();
+    assert_eq!(received, expected);
+
+    Ok(())
+}
+
+/// Verify that many threads can concurrently take from a reader that is being
+/// written to from other threads without losing or duplicating samples.
+#[test]
+fn concurrent_writes_and_takes() -> dds::Result<()> {
+    let domain_id = common::domain::unique_id();
+    let topic_name = common::topic::unique_name();
+    let qos = dds::QoS::new()
+        .with_reliability(dds::qos::policy::Reliability::Reliable {
+            max_blocking_time: dds::Duration::from_secs(1),
+        })
+        .with_history(dds::qos::policy::History::KeepAll);
+
+    let domain = dds::Domain::new(domain_id)?;
+    let participant = dds::Participant::new(&domain)?;
+    let topic = dds::Topic::<common::topic::Data>::builder(&participant, &topic_name)
+        .with_qos(&qos)
+        .build()?;
+    let reader = dds::Reader::builder(&topic).with_qos(&qos).build()?;
+    let writer = dds::Writer::builder(&topic).with_qos(&qos).build()?;
+
+    let total = (THREADS * SAMPLES_PER_THREAD) as usize;
+    let taken = std::sync::Mutex::new(Vec::with_capacity(total));
+
+    std::thread::scope(|scope| {
+        let writers: Vec<_> = (0..THREADS)
+            .map(|thread| {
+                let writer = &writer;
+                scope.spawn(move || {
+                    for x in 0..SAMPLES_PER_THREAD {
+                        writer.write(&common::topic::Data {
+                            x,
+                            y: thread.cast_signed(),
+                            ..Default::default()
+                        })?;
+                    }
+                    Ok::<_, dds::Error>(())
+                })
+            })
+            .collect();
+
+        let takers: Vec<_> = (0..THREADS)
+            .map(|_| {
+                let (reader, taken) = (&reader, &taken);
+                scope.spawn(move || {
+                    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(10);
+                    while std::time::Instant::now() < deadline {
+                        let samples = reader.take()?;
+                        let mut taken = taken.lock().expect("taken samples lock poisoned");
+                        taken.extend(samples.iter().map(|sample| (sample.y, sample.x)));
+                        if taken.len() >= total {
+                            break;
+                        }
+                    }
+                    Ok::<_, dds::Error>(())
+                })
+            })
+            .collect();
+
+        writers
+            .into_iter()
+            .chain(takers)
+            .try_for_each(|handle| handle.join().expect("thread panicked"))
+    })?;
+
+    let mut taken = taken.into_inner().expect("taken samples lock poisoned");
+    taken.sort_unstable();
+    let expected: Vec<_> = (0..THREADS)
+        .flat_map(|thread| (0..SAMPLES_PER_THREAD).map(move |x| (thread.cast_signed(), x)))
+        .collect();
+    assert_eq!(taken, expected);
+
+    Ok(())
+}
+
+/// Verify that a guard condition set from another thread wakes a waitset.
+#[test]
+fn guard_condition_triggered_from_another_thread() -> dds::Result<()> {
+    let domain_id = common::domain::unique_id();
+    let domain = dds::Domain::new(domain_id)?;
+    let participant = dds::Participant::new(&domain)?;
+    let guard_condition = dds::GuardCondition::new(&participant)?;
+    let blob = 7;
+    let mut waitset = dds::WaitSet::<u32>::new(&participant)?;
+    waitset.attach(&guard_condition, Some(&blob))?;
+
+    std::thread::scope(|scope| {
+        let setter = scope.spawn(|| {
+            std::thread::sleep(std::time::Duration::from_millis(50));
+            guard_condition.set(true)
+        });
+
+        let triggered = waitset.wait(dds::Duration::from_secs(5))?;
+        assert_eq!(triggered, vec![&blob]);
+        setter.join().expect("setter thread panicked")
+    })?;
+
+    assert!(guard_condition.read()?);
+    Ok(())
+}
@@ -86,3 +86,290 @@ fn test_topicable_with_invalid_topic_name() -> dds::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_topicable_with_bounded_key_fields() -> dds::Result<()> {
+    use dds::cdr_bounds::{CdrBounds, CdrSize};
+
+    #[derive(
+        CdrBounds, Debug, Clone, serde::Serialize, serde::Deserialize, Default, PartialEq, Hash,
+    )]
+    struct Position {
+        x: i16,
+        y: i16,
+    }
+
+    #[derive(Topicable, Debug, Clone, serde::Serialize, serde::Deserialize, Default, PartialEq)]
+    struct Data {
+        #[dds(key, bound = 7)]
+        name: String,
+        #[dds(key)]
+        position: Position,
+        #[dds(bound = 2)]
+        readings: Vec<f32>,
+    }
+
+    // 4 byte length + 7 characters + null terminator + 2 * 2 bytes.
+    assert_eq!(
+        dds::Key::<Data>::max_serialized_cdr_size(),
+        CdrSize::Bounded(16)
+    );
+    assert_eq!(dds::Key::<Data>::alignment(), 4);
+
+    let domain = dds::Domain::default();
+    let participant = dds::Participant::new(&domain)?;
+    let topic = dds::Topic::<Data>::new(&participant, "data")?;
+    let reader = dds::Reader::new(&topic)?;
+    let writer = dds::Writer::new(&topic)?;
+
+    let sample = Data {
+        name: "sensor".to_string(),
+        position: Position { x: 1, y: 2 },
+        readings: vec![0.5, 1.5],
+    };
+    writer.write(&sample)?;
+
+    let samples = reader.read()?;
+    assert_eq!(*samples[0], sample);
+
+    // The bounds of key fields are enforced on write.
+    let sample = Data {
+        name: "sensor-7".to_string(),
+        ..sample
+    };
+    assert_eq!(writer.write(&sample), Err(dds::Error::Serialization));
+    let context = dds::ErrorContext::last().expect("the write failed to serialize");
+    assert_eq!(context.operation(), "write");
+
+    Ok(())
+}
+
+#[test]
+fn test_topicable_with_mixed_alignment_key_fields() -> dds::Result<()> {
+    use dds::cdr_bounds::{CdrBounds, CdrSize};
+
+    #[derive(
+        CdrBounds, Debug, Clone, serde::Serialize, serde::Deserialize, Default, PartialEq, Hash,
+    )]
+    struct Stamp {
+        flags: u8,
+        nanos: u64,
+        source: u8,
+    }
+
+    #[derive(Topicable, Debug, Clone, serde::Serialize, serde::Deserialize, Default, PartialEq)]
+    struct Data {
+        #[dds(key)]
+        kind: u8,
+        #[dds(key)]
+        stamp: Stamp,
+        #[dds(key)]
+        channel: u16,
+        value: f64,
+    }
+
+    // 1 byte + 7 padding + 8 bytes + 1 byte + 7 trailing padding.
+    assert_eq!(Stamp::max_serialized_cdr_size(), CdrSize::Bounded(24));
+    assert_eq!(Stamp::alignment(), 8);
+    // 1 byte + 7 padding + 24 byte stamp + 2 bytes + 6 trailing padding.
+    assert_eq!(
+        dds::Key::<Data>::max_serialized_cdr_size(),
+        CdrSize::Bounded(40)
+    );
+    assert_eq!(dds::Key::<Data>::alignment(), 8);
+
+    let domain = dds::Domain::default();
+    let participant = dds::Participant::new(&domain)?;
+    let topic = dds::Topic::<Data>::new(&participant, "data")?;
+    let reader = dds::Reader::new(&topic)?;
+    let writer = dds::Writer::new(&topic)?;
+
+    // The keys only differ past the first 16 bytes of their encoding, so they
+    // are only told apart by an MD5 keyhash.
+    let first = Data {
+        kind: 1,
+        stamp: Stamp {
+            flags: 2,
+            nanos: 3,
+            source: 4,
+        },
+        channel: 5,
+        value: 0.5,
+    };
+    let second = Data {
+        channel: 6,
+        ..first.clone()
+    };
+    writer.write(&first)?;
+    writer.write(&second)?;
+
+    let samples = reader.take()?;
+    assert_eq!(samples.len(), 2);
+    assert!(samples.iter().any(|sample| **sample == first));
+    assert!(samples.iter().any(|sample| **sample == second));
+
+    Ok(())
+}
+
+#[test]
+fn test_topicable_with_bounded_types() -> dds::Result<()> {
+    use dds::bounded::{BoundedString, BoundedVec};
+    use dds::cdr_bounds::{CdrBounds, CdrSize};
+
+    #[derive(Topicable, Debug, Clone, serde::Serialize, serde::Deserialize, Default, PartialEq)]
+    struct Data {
+        #[dds(key)]
+        name: BoundedString<8>,
+        readings: BoundedVec<u32, 2>,
+    }
+
+    // 4 byte length + 8 characters + null terminator, padded to 4 bytes.
+    assert_eq!(
+        dds::Key::<Data>::max_serialized_cdr_size(),
+        CdrSize::Bounded(16)
+    );
+
+    let domain = dds::Domain::default();
+    let participant = dds::Participant::new(&domain)?;
+    let topic = dds::Topic::<Data>::new(&participant, "data")?;
+    let reader = dds::Reader::new(&topic)?;
+    let writer = dds::Writer::new(&topic)?;
+
+    let mut sample = Data {
+        name: "sensor".into(),
+        readings: vec![1, 2].into(),
+    };
+    writer.write(&sample)?;
+
+    let samples = reader.take()?;
+    assert_eq!(*samples[0], sample);
+
+    // The bounds are checked on write, even with only a reader in the same
+    // process, which never needs the sample encoded.
+    sample.readings.push(3);
+    assert_eq!(writer.write(&sample), Err(dds::Error::Serialization));
+    assert!(dds::ErrorContext::last().is_some());
+    assert!(reader.take()?.is_empty());
+
+    sample.readings.pop();
+    sample.name.push_str("-overflow");
+    assert_eq!(writer.write(&sample), Err(dds::Error::Serialization));
+    assert!(reader.take()?.is_empty());
+
+    // A sample within its bounds can still be written afterwards.
+    sample.name = "sensor-2".into();
+    writer.write(&sample)?;
+    let samples = reader.take()?;
+    assert_eq!(samples.len(), 1);
+    assert_eq!(*samples[0], sample);
+
+    Ok(())
+}
+
+#[test]
+fn test_topicable_with_newtype_key() -> dds::Result<()> {
+    use dds::cdr_bounds::{CdrBounds, CdrSize};
+
+    #[derive(
+        CdrBounds, Debug, Clone, serde::Serialize, serde::Deserialize, Default, PartialEq, Hash,
+    )]
+    struct Id([u8; 16]);
+
+    #[derive(Topicable, Debug, Clone, serde::Serialize, serde::Deserialize, Default, PartialEq)]
+    struct Data {
+        #[dds(key)]
+        id: Id,
+        #[dds(key)]
+        timeout: Option<std::time::Duration>,
+        value: u32,
+    }
+
+    assert_eq!(Id::max_serialized_cdr_size(), CdrSize::Bounded(16));
+    assert_eq!(
+        dds::Key::<Data>::max_serialized_cdr_size(),
+        CdrSize::Bounded(16 + 8 + 16)
+    );
+
+    let domain = dds::Domain::default();
+    let participant = dds::Participant::new(&domain)?;
+    let topic = dds::Topic::<Data>::new(&participant, "data")?;
+    let reader = dds::Reader::new(&topic)?;
+    let writer = dds::Writer::new(&topic)?;
+
+    let sample = Data {
+        id: Id([7; 16]),
+        timeout: Some(std::time::Duration::from_millis(1500)),
+        value: 42,
+    };
+    writer.write(&sample)?;
+
+    let samples = reader.read()?;
+    assert_eq!(*samples[0], sample);
+
+    Ok(())
+}
+
+#[test]
+fn test_topicable_with_optional_members() -> dds::Result<()> {
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Default, PartialEq)]
+    struct Position {
+        x: f64,
+        y: f64,
+        altitude: Option<f64>,
+    }
+
+    #[derive(Topicable, Debug, Clone, serde::Serialize, serde::Deserialize, Default, PartialEq)]
+    #[dds(optional_members)]
+    struct Data {
+        #[dds(key)]
+        id: u32,
+        label: Option<String>,
+        position: Position,
+        heading: Option<u16>,
+    }
+
+    #[derive(Topicable, Debug, Clone, serde::Serialize, serde::Deserialize, Default, PartialEq)]
+    #[dds(optional_members)]
+    struct Nested {
+        position: Position,
+    }
+
+    assert!(Data::OPTIONAL_MEMBERS);
+    assert!(Nested::OPTIONAL_MEMBERS);
+
+    let domain = dds::Domain::default();
+    let participant = dds::Participant::new(&domain)?;
+    let topic = dds::Topic::<Data>::new(&participant, "data")?;
+    let reader = dds::Reader::new(&topic)?;
+    let writer = dds::Writer::new(&topic)?;
+
+    let samples = [
+        Data {
+            id: 1,
+            label: Some("buoy".to_string()),
+            position: Position {
+                x: 1.0,
+                y: 2.0,
+                altitude: Some(3.0),
+            },
+            heading: Some(90),
+        },
+        Data {
+            id: 2,
+            ..Data::default()
+        },
+    ];
+    for sample in &samples {
+        writer.write(sample)?;
+    }
+
+    let mut received: Vec<_> = reader
+        .take()?
+        .iter()
+        .map(|sample| (**sample).clone())
+        .collect();
+    received.sort_by_key(|sample| sample.id);
+    assert_eq!(received, samples);
+
+    Ok(())
+}